@@ -1,10 +1,12 @@
 use std::cell::RefCell;
 use std::pin::pin;
+use std::{io, thread};
 use std::{pin::Pin, sync::Arc};
 
+use futures_channel::oneshot;
 use futures_util::future::{select, Either};
 use nyquest_interface::r#async::AsyncResponse;
-use nyquest_interface::Error as NyquestError;
+use nyquest_interface::{Error as NyquestError, HttpVersion, ProgressCallback, RedirectHop};
 
 mod handler;
 mod r#loop;
@@ -18,8 +20,9 @@ mod set;
 mod shared;
 
 use crate::curl_ng::easy::{AsRawEasyMut as _, Share};
+use crate::progress::ProgressReporter;
 use crate::r#async::handler::AsyncHandler;
-use crate::request::{create_easy, AsCallbackMut as _};
+use crate::request::{create_easy, AsCallbackMut as _, ResolveTarget, ResolvedHost};
 use crate::url::concat_url;
 
 type Easy = crate::request::BoxEasyHandle<handler::AsyncHandler>;
@@ -28,6 +31,7 @@ pub struct CurlMultiClientInner {
     options: nyquest_interface::client::ClientOptions,
     loop_manager: r#loop::LoopManager,
     share: Share,
+    cookie_file: Option<crate::cookie::CookieFile>,
 }
 #[derive(Clone)]
 pub struct CurlMultiClient {
@@ -38,6 +42,9 @@ pub struct CurlAsyncResponse {
     status: u16,
     content_length: Option<u64>,
     headers: Vec<(String, String)>,
+    url: Option<String>,
+    version: Option<HttpVersion>,
+    redirect_history: Vec<RedirectHop>,
     handle: r#loop::RequestHandle,
     max_response_buffer_size: Option<u64>,
 }
@@ -60,6 +67,22 @@ impl AsyncResponse for CurlAsyncResponse {
             .collect())
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        Ok(self.headers.clone())
+    }
+
+    fn url(&self) -> Option<String> {
+        self.url.clone()
+    }
+
+    fn version(&self) -> Option<HttpVersion> {
+        self.version
+    }
+
+    fn redirect_history(&self) -> Vec<RedirectHop> {
+        self.redirect_history.clone()
+    }
+
     async fn text(mut self: Pin<&mut Self>) -> nyquest_interface::Result<String> {
         let buf = self.as_mut().bytes().await?;
         #[cfg(feature = "charset")]
@@ -135,16 +158,62 @@ impl nyquest_interface::r#async::AsyncClient for CurlMultiClient {
         &self,
         req: nyquest_interface::r#async::Request,
     ) -> nyquest_interface::Result<Self::Response> {
+        self.request_inner(req, None).await
+    }
+
+    async fn request_with_progress(
+        &self,
+        req: nyquest_interface::r#async::Request,
+        progress: ProgressCallback,
+    ) -> nyquest_interface::Result<Self::Response> {
+        self.request_inner(req, Some(progress)).await
+    }
+
+    fn cookie_jar(&self) -> Option<Arc<dyn nyquest_interface::CookieJar>> {
+        self.inner
+            .options
+            .use_cookies
+            .then(|| Arc::new(crate::cookie::CurlCookieJar::new(self.inner.share.clone())) as _)
+    }
+
+    fn flush_cookies(&self) -> nyquest_interface::Result<()> {
+        match &self.inner.cookie_file {
+            Some(cookie_file) => cookie_file.save(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CurlMultiClient {
+    async fn request_inner(
+        &self,
+        req: nyquest_interface::r#async::Request,
+        progress: Option<ProgressCallback>,
+    ) -> nyquest_interface::Result<CurlAsyncResponse> {
+        let max_response_buffer_size = req
+            .options
+            .max_response_buffer_size
+            .or(self.inner.options.max_response_buffer_size);
         let (req, read_task_collection) = {
             let mut easy = create_easy(AsyncHandler::default(), &self.inner.share)?;
             let raw = easy.as_mut().as_raw_easy_mut().raw();
             easy.as_callback_mut().pause = Some(pause::EasyPause::new(raw));
+            if let Some(progress) = progress {
+                easy.as_callback_mut().progress = Some(ProgressReporter::new(progress));
+                easy.as_mut()
+                    .with_error_message(|e| e.as_raw_easy_mut().set_noprogress(false))?;
+            }
             // FIXME: properly concat base_url and url
             let url = concat_url(self.inner.options.base_url.as_deref(), &req.relative_uri);
+            let resolved = match ResolveTarget::of(&url, &self.inner.options) {
+                Some(target) => Some(resolve_in_thread(target).await?),
+                None => None,
+            };
             let req_ctx = easy.as_callback_mut().ctx.clone();
             let read_task_collection = RefCell::new(read_task::ReadTaskCollection::new(req_ctx));
             crate::request::populate_request(
                 &url,
+                resolved.as_ref(),
                 req,
                 &self.inner.options,
                 easy.as_mut(),
@@ -169,11 +238,23 @@ impl nyquest_interface::r#async::AsyncClient for CurlMultiClient {
             Either::Right((Err(e), _)) => return Err(e),
             Either::Right((Ok(_), _)) => unreachable!(),
         };
-        res.max_response_buffer_size = self.inner.options.max_response_buffer_size;
+        res.max_response_buffer_size = max_response_buffer_size;
         Ok(res)
     }
 }
 
+/// Calls the custom resolver on its own thread, as it blocks like getaddrinfo.
+async fn resolve_in_thread(target: ResolveTarget) -> nyquest_interface::Result<ResolvedHost> {
+    let (tx, rx) = oneshot::channel();
+    thread::Builder::new()
+        .name("nyquest-curl-resolver".into())
+        .spawn(move || {
+            let _ = tx.send(target.resolve());
+        })?;
+    rx.await
+        .map_err(|_| io::Error::other("custom resolver panicked"))?
+}
+
 impl nyquest_interface::r#async::AsyncBackend for crate::CurlBackend {
     type AsyncClient = CurlMultiClient;
 
@@ -181,11 +262,21 @@ impl nyquest_interface::r#async::AsyncBackend for crate::CurlBackend {
         &self,
         options: nyquest_interface::client::ClientOptions,
     ) -> Result<Self::AsyncClient, NyquestError> {
+        let options = crate::request::prepare_options(options)?;
+        let share = Share::new();
+        let cookie_file = match &options.cookie_file {
+            Some(path) if options.use_cookies => Some(crate::cookie::CookieFile::load(
+                share.clone(),
+                path.clone(),
+            )?),
+            _ => None,
+        };
         Ok(CurlMultiClient {
             inner: Arc::new(CurlMultiClientInner {
                 loop_manager: r#loop::LoopManager::new(),
                 options,
-                share: Share::new(),
+                share,
+                cookie_file,
             }),
         })
     }
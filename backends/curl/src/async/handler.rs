@@ -5,12 +5,14 @@ use curl::easy::WriteError;
 use super::pause::EasyPause;
 use super::shared::SharedRequestStates;
 use crate::curl_ng::easy::EasyCallback;
+use crate::progress::ProgressReporter;
 
 #[derive(Default)]
 pub(super) struct AsyncHandler {
     pub(super) ctx: Arc<SharedRequestStates>,
     // To be filled after Easy2 is constructed
     pub(super) pause: Option<EasyPause>,
+    pub(super) progress: Option<ProgressReporter>,
 }
 
 struct AsyncHandlerRef<'a> {
@@ -91,4 +93,11 @@ impl EasyCallback for AsyncHandler {
     fn seek(&mut self, _whence: std::io::SeekFrom) -> curl::easy::SeekResult {
         curl::easy::SeekResult::Fail
     }
+
+    fn xferinfo(&mut self, dltotal: u64, dlnow: u64, ultotal: u64, ulnow: u64) -> bool {
+        if let Some(progress) = &mut self.progress {
+            progress.report(dltotal, dlnow, ultotal, ulnow);
+        }
+        true
+    }
 }
@@ -398,6 +398,14 @@ fn run_loop(multl_waker_tx: oneshot::Sender<LoopManagerShared>) {
                                     .filter_map(|line| line.split_once(':'))
                                     .map(|(k, v)| (k.into(), v.trim_start().into()))
                                     .collect(),
+                                url: e.as_mut().as_raw_easy_mut().get_effective_url()?,
+                                version: e
+                                    .as_mut()
+                                    .as_raw_easy_mut()
+                                    .get_http_version()
+                                    .ok()
+                                    .and_then(crate::request::http_version_from_curl),
+                                redirect_history: std::mem::take(&mut state.state.redirect_history),
                                 handle: req_handle,
                                 max_response_buffer_size: None, // To be filled in client.request()
                             })
@@ -417,12 +425,16 @@ fn run_loop(multl_waker_tx: oneshot::Sender<LoopManagerShared>) {
                         continue;
                     };
                     let ctx = handle.as_callback_mut().ctx.clone();
-                    if let Err(e) =
-                        handle.with_error_message(|e| e.as_raw_easy_mut().unpause_recv())
-                    {
-                        let res = Err(e.into());
-                        ctx.state.lock().unwrap().result = RequestResult::Done { res, id };
-                        ctx.waker.wake();
+                    match handle.with_error_message(|e| e.as_raw_easy_mut().unpause_recv()) {
+                        Ok(()) => {}
+                        // The transfer has already completed, and the completion is yet to be
+                        // picked up from the multi handle
+                        Err(e) if e.code == curl_sys::CURLE_BAD_FUNCTION_ARGUMENT => {}
+                        Err(e) => {
+                            let res = Err(e.into());
+                            ctx.state.lock().unwrap().result = RequestResult::Done { res, id };
+                            ctx.waker.wake();
+                        }
                     }
                 }
                 LoopTask::UnpauseSendHandle(id) => {
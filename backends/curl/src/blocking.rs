@@ -4,7 +4,9 @@ use std::mem::ManuallyDrop;
 use std::sync::{Arc, Mutex};
 
 use nyquest_interface::blocking::Request;
-use nyquest_interface::{Error as NyquestError, Result as NyquestResult};
+use nyquest_interface::{
+    Error as NyquestError, HttpVersion, ProgressCallback, RedirectHop, Result as NyquestResult,
+};
 
 mod handler;
 mod multi_easy;
@@ -13,6 +15,7 @@ mod part_reader;
 mod set;
 
 use crate::curl_ng::easy::Share;
+use crate::request::ResolveTarget;
 use crate::url::concat_url;
 use multi_easy::MultiEasy;
 
@@ -21,6 +24,7 @@ pub struct CurlEasyClient {
     options: Arc<nyquest_interface::client::ClientOptions>,
     slot: Arc<MultiEasySlot>,
     share: Share,
+    cookie_file: Option<Arc<crate::cookie::CookieFile>>,
 }
 
 struct MultiEasySlot {
@@ -38,6 +42,9 @@ pub struct CurlBlockingResponse {
     status: u16,
     content_length: Option<u64>,
     headers: Vec<(String, String)>,
+    url: Option<String>,
+    version: Option<HttpVersion>,
+    redirect_history: Vec<RedirectHop>,
     handle: OwnedEasyHandleGuard,
     max_response_buffer_size: Option<u64>,
 }
@@ -76,14 +83,23 @@ impl<S: AsRef<MultiEasySlot>> Drop for EasyHandleGuard<S> {
 }
 
 impl CurlEasyClient {
-    pub fn new(options: nyquest_interface::client::ClientOptions) -> Self {
-        Self {
+    pub fn new(options: nyquest_interface::client::ClientOptions) -> NyquestResult<Self> {
+        let share = Share::new();
+        let cookie_file = match &options.cookie_file {
+            Some(path) if options.use_cookies => Some(Arc::new(crate::cookie::CookieFile::load(
+                share.clone(),
+                path.clone(),
+            )?)),
+            _ => None,
+        };
+        Ok(Self {
             options: Arc::new(options),
             slot: Arc::new(MultiEasySlot {
                 multi_easy: Mutex::new(None),
             }),
-            share: Share::new(),
-        }
+            share,
+            cookie_file,
+        })
     }
 
     fn get_or_create_handle(&self) -> NyquestResult<EasyHandleGuard<&Arc<MultiEasySlot>>> {
@@ -116,6 +132,7 @@ impl io::Read for CurlBlockingResponse {
             Ok(len) => Ok(len),
             Err(NyquestError::RequestTimeout) => Err(io::ErrorKind::TimedOut.into()),
             Err(NyquestError::Io(e)) => Err(e),
+            Err(NyquestError::Cancelled) => Err(io::Error::other(NyquestError::Cancelled)),
             Err(e) => unreachable!("Unexpected error: {}", e),
         }
     }
@@ -139,6 +156,22 @@ impl nyquest_interface::blocking::BlockingResponse for CurlBlockingResponse {
             .collect())
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        Ok(self.headers.clone())
+    }
+
+    fn url(&self) -> Option<String> {
+        self.url.clone()
+    }
+
+    fn version(&self) -> Option<HttpVersion> {
+        self.version
+    }
+
+    fn redirect_history(&self) -> Vec<RedirectHop> {
+        self.redirect_history.clone()
+    }
+
     fn text(&mut self) -> nyquest_interface::Result<String> {
         let buf = self.bytes()?;
         #[cfg(feature = "charset")]
@@ -177,11 +210,53 @@ impl nyquest_interface::blocking::BlockingClient for CurlEasyClient {
     type Response = CurlBlockingResponse;
 
     fn request(&self, req: Request) -> nyquest_interface::Result<Self::Response> {
+        self.request_inner(req, None)
+    }
+
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: ProgressCallback,
+    ) -> nyquest_interface::Result<Self::Response> {
+        self.request_inner(req, Some(progress))
+    }
+
+    fn cookie_jar(&self) -> Option<Arc<dyn nyquest_interface::CookieJar>> {
+        self.options
+            .use_cookies
+            .then(|| Arc::new(crate::cookie::CurlCookieJar::new(self.share.clone())) as _)
+    }
+
+    fn flush_cookies(&self) -> nyquest_interface::Result<()> {
+        match &self.cookie_file {
+            Some(cookie_file) => cookie_file.save(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CurlEasyClient {
+    fn request_inner(
+        &self,
+        req: Request,
+        progress: Option<ProgressCallback>,
+    ) -> nyquest_interface::Result<CurlBlockingResponse> {
         let mut handle_guard = self.get_or_create_handle()?;
         // FIXME: properly concat base_url and url
         let url = concat_url(self.options.base_url.as_deref(), &req.relative_uri);
+        let max_response_buffer_size = req
+            .options
+            .max_response_buffer_size
+            .or(self.options.max_response_buffer_size);
         let handle: &mut MultiEasy = handle_guard.handle_mut();
-        handle.populate_request(&url, req, &self.options)?;
+        handle.set_cancellation(req.cancellation.clone());
+        let resolved = ResolveTarget::of(&url, &self.options)
+            .map(ResolveTarget::resolve)
+            .transpose()?;
+        handle.populate_request(&url, resolved.as_ref(), req, &self.options)?;
+        if let Some(progress) = progress {
+            handle.set_progress(progress)?;
+        }
         handle.poll_until_response_headers()?;
         let mut headers_buf = handle.take_response_headers_buffer();
         let headers = headers_buf
@@ -194,8 +269,11 @@ impl nyquest_interface::blocking::BlockingClient for CurlEasyClient {
             status: handle.status()?,
             content_length: handle.content_length()?,
             headers,
+            url: handle.effective_url()?,
+            version: handle.http_version(),
+            redirect_history: handle.take_redirect_history(),
             handle: handle_guard.into_owned(),
-            max_response_buffer_size: self.options.max_response_buffer_size,
+            max_response_buffer_size,
         })
     }
 }
@@ -207,6 +285,7 @@ impl nyquest_interface::blocking::BlockingBackend for crate::CurlBackend {
         &self,
         options: nyquest_interface::client::ClientOptions,
     ) -> NyquestResult<Self::BlockingClient> {
-        Ok(CurlEasyClient::new(options))
+        let options = crate::request::prepare_options(options)?;
+        CurlEasyClient::new(options)
     }
 }
@@ -1,6 +1,6 @@
 use curl::easy::{ReadError, SeekResult, WriteError};
 
-use crate::{curl_ng::easy::EasyCallback, state::RequestState};
+use crate::{curl_ng::easy::EasyCallback, progress::ProgressReporter, state::RequestState};
 #[cfg(feature = "blocking-stream")]
 use nyquest_interface::blocking::BoxedStream;
 
@@ -9,6 +9,7 @@ pub struct BlockingHandler {
     pub(super) state: RequestState,
     #[cfg(feature = "blocking-stream")]
     body_stream: Option<BoxedStream>,
+    pub(super) progress: Option<ProgressReporter>,
 }
 
 impl BlockingHandler {
@@ -67,4 +68,11 @@ impl EasyCallback for BlockingHandler {
     fn seek(&mut self, _whence: std::io::SeekFrom) -> SeekResult {
         SeekResult::Fail
     }
+
+    fn xferinfo(&mut self, dltotal: u64, dlnow: u64, ultotal: u64, ulnow: u64) -> bool {
+        if let Some(progress) = &mut self.progress {
+            progress.report(dltotal, dlnow, ultotal, ulnow);
+        }
+        true
+    }
 }
@@ -3,13 +3,17 @@ use std::ops::ControlFlow;
 use std::pin::Pin;
 
 use nyquest_interface::blocking::Request;
-use nyquest_interface::{Error as NyquestError, Result as NyquestResult};
+use nyquest_interface::{
+    CancellationToken, Error as NyquestError, HttpVersion, ProgressCallback, RedirectHop,
+    Result as NyquestResult,
+};
 
 use crate::blocking::handler::BlockingHandler;
 use crate::curl_ng::easy::{AsRawEasyMut as _, Share};
 use crate::curl_ng::mime::MimePartContent;
 use crate::curl_ng::multi::{MultiWithSet, RawMulti};
-use crate::request::{create_easy, AsCallbackMut as _, BoxEasyHandle, EasyHandle};
+use crate::progress::ProgressReporter;
+use crate::request::{create_easy, AsCallbackMut as _, BoxEasyHandle, EasyHandle, ResolvedHost};
 use crate::state::RequestState;
 
 type Easy = EasyHandle<super::handler::BlockingHandler>;
@@ -18,15 +22,22 @@ type Multi = MultiWithSet<RawMulti, super::set::SingleMultiSet>;
 
 pub(crate) struct MultiEasy {
     multi: Multi,
+    cancellation: Option<CancellationToken>,
 }
 
+/// The longest time to wait in `multi_poll` before checking the cancellation token again.
+const CANCELLATION_POLL_INTERVAL_MS: u32 = 100;
+
 impl MultiEasy {
     pub fn new(share: &Share) -> NyquestResult<Self> {
         let mut multi = Multi::new(RawMulti::new(), Default::default());
         let easy = create_easy::<BlockingHandler>(Default::default(), share)?;
         multi.add(easy)?;
         multi.set_max_connects(5).expect("set max connects"); // Default of easy is 5
-        Ok(MultiEasy { multi })
+        Ok(MultiEasy {
+            multi,
+            cancellation: None,
+        })
     }
 
     fn with_detached_easy<T>(
@@ -70,15 +81,26 @@ impl MultiEasy {
     }
 
     pub fn reset_state(&mut self) -> NyquestResult<()> {
+        self.cancellation = None;
         self.with_detached_easy(reset_easy_state)
     }
 
+    pub fn set_cancellation(&mut self, cancellation: Option<CancellationToken>) {
+        self.cancellation = cancellation;
+    }
+
     fn poll_until(
         &mut self,
         mut cb: impl FnMut(&mut RequestState) -> NyquestResult<ControlFlow<()>>,
     ) -> NyquestResult<()> {
         loop {
-            let suggested_timeout = self.multi.get_timeout_ms()?.unwrap_or(1000);
+            let mut suggested_timeout = self.multi.get_timeout_ms()?.unwrap_or(1000);
+            if let Some(cancellation) = &self.cancellation {
+                if cancellation.is_cancelled() {
+                    return Err(NyquestError::Cancelled);
+                }
+                suggested_timeout = suggested_timeout.min(CANCELLATION_POLL_INTERVAL_MS);
+            }
             let multi_res = self.multi.wait(suggested_timeout);
             let multi_res = multi_res.and_then(|_| self.multi.perform())?;
             let mut res = ControlFlow::Continue(());
@@ -118,6 +140,7 @@ impl MultiEasy {
     pub fn populate_request(
         &mut self,
         url: &str,
+        resolved: Option<&ResolvedHost>,
         req: Request,
         options: &nyquest_interface::client::ClientOptions,
     ) -> NyquestResult<()> {
@@ -128,6 +151,7 @@ impl MultiEasy {
         self.with_detached_easy(|easy| {
             crate::request::populate_request(
                 url,
+                resolved,
                 req,
                 options,
                 easy,
@@ -159,6 +183,7 @@ impl MultiEasy {
     pub fn populate_request(
         &mut self,
         url: &str,
+        resolved: Option<&ResolvedHost>,
         req: Request,
         options: &nyquest_interface::client::ClientOptions,
     ) -> NyquestResult<()> {
@@ -167,6 +192,7 @@ impl MultiEasy {
 
             crate::request::populate_request(
                 url,
+                resolved,
                 req,
                 options,
                 easy,
@@ -179,6 +205,13 @@ impl MultiEasy {
         })
     }
 
+    pub fn set_progress(&mut self, progress: ProgressCallback) -> NyquestResult<()> {
+        let mut easy = self.easy_mut();
+        easy.as_callback_mut().progress = Some(ProgressReporter::new(progress));
+        easy.with_error_message(|easy| easy.as_raw_easy_mut().set_noprogress(false))?;
+        Ok(())
+    }
+
     pub fn status(&mut self) -> NyquestResult<u16> {
         self.easy_mut()
             .with_error_message(|easy| easy.as_raw_easy_mut().get_response_code())
@@ -191,6 +224,21 @@ impl MultiEasy {
             .map_err(|e| e.into())
     }
 
+    pub fn effective_url(&mut self) -> NyquestResult<Option<String>> {
+        self.easy_mut()
+            .with_error_message(|easy| easy.as_raw_easy_mut().get_effective_url())
+            .map_err(|e| e.into())
+    }
+
+    pub fn http_version(&mut self) -> Option<HttpVersion> {
+        // Unknown if the linked libcurl is too old to report it
+        let version = self
+            .easy_mut()
+            .with_error_message(|easy| easy.as_raw_easy_mut().get_http_version())
+            .ok()?;
+        crate::request::http_version_from_curl(version)
+    }
+
     pub fn poll_until_whole_response(
         &mut self,
         max_response_buffer_size: Option<u64>,
@@ -244,6 +292,10 @@ impl MultiEasy {
                 .response_headers_buffer,
         )
     }
+
+    pub fn take_redirect_history(&mut self) -> Vec<RedirectHop> {
+        std::mem::take(&mut self.easy_mut().as_callback_mut().state.redirect_history)
+    }
 }
 
 fn reset_easy_state(easy: Pin<&mut Easy>) -> Result<(), NyquestError> {
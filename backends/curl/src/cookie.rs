@@ -0,0 +1,109 @@
+//! Access to the cookies kept in the share handle of a client.
+
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nyquest_interface::{write_netscape_cookie_file, Cookie, CookieJar, Result as NyquestResult};
+
+use crate::curl_ng::easy::{AsRawEasyMut as _, OwnedEasyWithErrorBuf, RawEasy, Share, ShareHandle};
+
+type JarEasy = OwnedEasyWithErrorBuf<ShareHandle<RawEasy>>;
+
+/// A cookie jar backed by the cookies of a [`Share`], which are seen by all easy handles of the
+/// client.
+pub(crate) struct CurlCookieJar {
+    share: Share,
+}
+
+impl CurlCookieJar {
+    pub(crate) fn new(share: Share) -> Self {
+        Self { share }
+    }
+
+    fn with_easy<T>(
+        &self,
+        f: impl FnOnce(Pin<&mut RawEasy>) -> Result<T, crate::curl_ng::CurlCodeContext>,
+    ) -> NyquestResult<T> {
+        let mut easy: Pin<Box<JarEasy>> = Box::pin(OwnedEasyWithErrorBuf::new(
+            self.share.spawn_easy(RawEasy::new()),
+        ));
+        easy.as_mut().with_error_message(|e| e.init())?;
+        Ok(easy
+            .as_mut()
+            .with_error_message(|e| f(e.as_raw_easy_mut()))?)
+    }
+}
+
+impl CookieJar for CurlCookieJar {
+    fn cookies(&self) -> NyquestResult<Vec<Cookie>> {
+        let list = self.with_easy(|easy| easy.get_cookielist())?;
+        let now = SystemTime::now();
+        Ok(list
+            .iter()
+            .filter_map(|line| Cookie::from_netscape_line(&String::from_utf8_lossy(line)))
+            .filter(|cookie| !matches!(cookie.expires, Some(expires) if expires <= now))
+            .collect())
+    }
+
+    fn add(&self, cookie: Cookie) -> NyquestResult<()> {
+        self.with_easy(|easy| easy.set_cookielist(cookie.to_netscape_line()))
+    }
+
+    fn remove(&self, name: &str, domain: &str, path: &str) -> NyquestResult<()> {
+        // libcurl drops a cookie when it is replaced with an expired one
+        let matching: Vec<_> = self
+            .cookies()?
+            .into_iter()
+            .filter(|c| c.name == name && c.path == path && c.domain.eq_ignore_ascii_case(domain))
+            .collect();
+        for cookie in matching {
+            self.add(Cookie {
+                value: String::new(),
+                expires: Some(UNIX_EPOCH + Duration::from_secs(1)),
+                ..cookie
+            })?;
+        }
+        Ok(())
+    }
+
+    fn clear(&self) -> NyquestResult<()> {
+        self.with_easy(|easy| easy.set_cookielist("ALL"))
+    }
+}
+
+/// The cookie file of a client, loaded into the share handle when the client is created and saved
+/// when the client is dropped.
+pub(crate) struct CookieFile {
+    jar: CurlCookieJar,
+    path: PathBuf,
+}
+
+impl CookieFile {
+    /// Loads the cookies in `path`, if it exists. The path must be valid UTF-8.
+    pub(crate) fn load(share: Share, path: PathBuf) -> NyquestResult<Self> {
+        let jar = CurlCookieJar::new(share);
+        let path_str = path
+            .to_str()
+            .expect("cookie file path is checked to be UTF-8");
+        jar.with_easy(|mut easy| {
+            easy.as_mut().set_cookiefile(path_str)?;
+            easy.set_cookielist("RELOAD")
+        })?;
+        Ok(Self { jar, path })
+    }
+
+    /// Writes all cookies to the file, including session cookies.
+    pub(crate) fn save(&self) -> NyquestResult<()> {
+        // libcurl silently ignores errors writing CURLOPT_COOKIEJAR, so the file is written here
+        let list = self.jar.with_easy(|easy| easy.get_cookielist())?;
+        write_netscape_cookie_file(&self.path, list.iter().map(String::from_utf8_lossy))?;
+        Ok(())
+    }
+}
+
+impl Drop for CookieFile {
+    fn drop(&mut self) {
+        self.save().ok();
+    }
+}
@@ -20,8 +20,14 @@ pub trait EasyCallback {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, ReadError>;
     fn header(&mut self, data: &[u8]) -> bool;
     fn seek(&mut self, whence: SeekFrom) -> SeekResult;
+    /// Returns `false` to abort the transfer.
+    fn xferinfo(&mut self, dltotal: u64, dlnow: u64, ultotal: u64, ulnow: u64) -> bool;
 }
 
+// Not exported by curl-sys
+const CURLOPT_XFERINFOFUNCTION: curl_sys::CURLoption = curl_sys::CURLOPTTYPE_FUNCTIONPOINT + 219;
+const CURLOPT_XFERINFODATA: curl_sys::CURLoption = curl_sys::CURLOPT_PROGRESSDATA;
+
 pin_project! {
     pub struct EasyWithCallback<E, C> {
         #[pin]
@@ -81,6 +87,12 @@ impl<E: AsRawEasyMut, C: EasyCallback> EasyWithCallback<E, C> {
             raw.as_mut()
                 .setopt_ptr(curl_sys::CURLOPT_SEEKFUNCTION, seek_callback::<E, C> as _)
                 .with_easy_context("setopt SEEKFUNCTION")?;
+            raw.as_mut()
+                .setopt_ptr(CURLOPT_XFERINFODATA, self_ptr as _)
+                .with_easy_context("setopt XFERINFODATA")?;
+            raw.as_mut()
+                .setopt_ptr(CURLOPT_XFERINFOFUNCTION, xferinfo_callback::<E, C> as _)
+                .with_easy_context("setopt XFERINFOFUNCTION")?;
         }
         Ok(())
     }
@@ -177,3 +189,27 @@ fn seek_callback<E: AsRawEasyMut, C: EasyCallback>(
     })
     .unwrap_or(curl_sys::CURL_SEEKFUNC_FAIL)
 }
+
+fn xferinfo_callback<E: AsRawEasyMut, C: EasyCallback>(
+    userdata: *mut c_void,
+    dltotal: curl_sys::curl_off_t,
+    dlnow: curl_sys::curl_off_t,
+    ultotal: curl_sys::curl_off_t,
+    ulnow: curl_sys::curl_off_t,
+) -> c_int {
+    panic::catch_unwind(|| {
+        let this = unsafe { Pin::new_unchecked(&mut *(userdata as *mut EasyWithCallback<E, C>)) };
+        let res = this.project().callback.xferinfo(
+            dltotal as u64,
+            dlnow as u64,
+            ultotal as u64,
+            ulnow as u64,
+        );
+        if res {
+            0
+        } else {
+            1
+        }
+    })
+    .unwrap_or(1)
+}
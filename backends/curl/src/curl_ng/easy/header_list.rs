@@ -13,12 +13,17 @@ pin_project! {
         #[pin]
         easy: E,
         list: Option<CurlStringList>,
+        resolve_list: Option<CurlStringList>,
     }
 }
 
 impl<E> EasyWithHeaderList<E> {
     pub fn new(easy: E) -> Self {
-        EasyWithHeaderList { easy, list: None }
+        EasyWithHeaderList {
+            easy,
+            list: None,
+            resolve_list: None,
+        }
     }
 
     pub fn as_easy_mut(self: Pin<&mut Self>) -> Pin<&mut E> {
@@ -41,6 +46,23 @@ impl<E: AsRawEasyMut> EasyWithHeaderList<E> {
         *this.list = headers;
         Ok(())
     }
+
+    /// Sets the `host:port:addr[,addr]...` entries of `CURLOPT_RESOLVE`, which must outlive the
+    /// transfer.
+    pub fn set_resolve(
+        mut self: Pin<&mut Self>,
+        entries: Option<CurlStringList>,
+    ) -> Result<(), CurlCodeContext> {
+        let this = self.as_mut().project();
+        let raw = this.easy.as_raw_easy_mut();
+        let raw_list = entries.as_ref().map_or(null(), |l| l.raw());
+        unsafe {
+            raw.setopt_ptr(curl_sys::CURLOPT_RESOLVE, raw_list as *const _)
+                .with_easy_context("setopt CURLOPT_RESOLVE")?
+        }
+        *this.resolve_list = entries;
+        Ok(())
+    }
 }
 
 impl<E: AsRawEasyMut> AsRawEasyMut for EasyWithHeaderList<E> {
@@ -55,6 +77,7 @@ impl<E: AsRawEasyMut> AsRawEasyMut for EasyWithHeaderList<E> {
     fn reset(mut self: Pin<&mut Self>) -> Result<(), CurlCodeContext> {
         let this = self.as_mut().project();
         this.easy.reset()?;
-        self.set_headers(None)
+        self.as_mut().set_headers(None)?;
+        self.set_resolve(None)
     }
 }
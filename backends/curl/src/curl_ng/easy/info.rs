@@ -1,4 +1,4 @@
-use crate::curl_ng::{easy::RawEasy, CurlCodeContext, WithCurlCodeContext};
+use crate::curl_ng::{easy::RawEasy, CurlCodeContext, CurlStringList, WithCurlCodeContext};
 
 impl RawEasy {
     pub(super) unsafe fn getinfo_long(
@@ -25,6 +25,33 @@ impl RawEasy {
         }
     }
 
+    pub(super) unsafe fn getinfo_str(
+        &self,
+        info: curl_sys::CURLINFO,
+        context: &'static str,
+    ) -> Result<Option<String>, CurlCodeContext> {
+        unsafe {
+            let mut val: *const libc::c_char = std::ptr::null();
+            curl_sys::curl_easy_getinfo(self.raw(), info, &mut val).with_easy_context(context)?;
+            if val.is_null() {
+                return Ok(None);
+            }
+            Ok(Some(
+                std::ffi::CStr::from_ptr(val).to_string_lossy().into_owned(),
+            ))
+        }
+    }
+
+    /// Gets all known cookies in Netscape format.
+    pub fn get_cookielist(&self) -> Result<CurlStringList, CurlCodeContext> {
+        unsafe {
+            let mut list: *mut curl_sys::curl_slist = std::ptr::null_mut();
+            curl_sys::curl_easy_getinfo(self.raw(), curl_sys::CURLINFO_COOKIELIST, &mut list)
+                .with_easy_context("getinfo CURLINFO_COOKIELIST")?;
+            Ok(CurlStringList::from_raw(list))
+        }
+    }
+
     pub fn get_response_code(&self) -> Result<u16, CurlCodeContext> {
         let code = unsafe {
             self.getinfo_long(
@@ -35,6 +62,30 @@ impl RawEasy {
         Ok(code as u16)
     }
 
+    pub fn get_effective_url(&self) -> Result<Option<String>, CurlCodeContext> {
+        unsafe {
+            self.getinfo_str(
+                curl_sys::CURLINFO_EFFECTIVE_URL,
+                "getinfo CURLINFO_EFFECTIVE_URL",
+            )
+        }
+    }
+
+    /// Gets the HTTP version used by the last transfer as one of the `CURL_HTTP_VERSION_*`
+    /// values, available since libcurl 7.50.0.
+    pub fn get_http_version(&self) -> Result<libc::c_long, CurlCodeContext> {
+        const CURLINFO_HTTP_VERSION: curl_sys::CURLINFO = curl_sys::CURLINFO_LONG + 46;
+
+        unsafe { self.getinfo_long(CURLINFO_HTTP_VERSION, "getinfo CURLINFO_HTTP_VERSION") }
+    }
+
+    /// Gets the path of the default CA bundle file, available since libcurl 7.84.0.
+    pub fn get_default_ca_info(&self) -> Result<Option<String>, CurlCodeContext> {
+        const CURLINFO_CAINFO: curl_sys::CURLINFO = curl_sys::CURLINFO_STRING + 61;
+
+        unsafe { self.getinfo_str(CURLINFO_CAINFO, "getinfo CURLINFO_CAINFO") }
+    }
+
     pub fn get_content_length(&self) -> Result<Option<u64>, CurlCodeContext> {
         const CURLINFO_CONTENT_LENGTH_DOWNLOAD_T: curl_sys::CURLINFO = 0x600000 + 15;
 
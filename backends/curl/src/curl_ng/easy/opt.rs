@@ -30,6 +30,20 @@ impl RawEasy {
         unsafe { curl_sys::curl_easy_setopt(self.as_raw_easy_mut().raw(), opt, val) }
     }
 
+    pub(super) unsafe fn setopt_blob(
+        self: Pin<&mut Self>,
+        opt: curl_sys::CURLoption,
+        val: &[u8],
+    ) -> curl_sys::CURLcode {
+        // With CURL_BLOB_COPY, libcurl copies the data before returning
+        let blob = curl_sys::curl_blob {
+            data: val.as_ptr() as *mut _,
+            len: val.len(),
+            flags: curl_sys::CURL_BLOB_COPY,
+        };
+        unsafe { curl_sys::curl_easy_setopt(self.as_raw_easy_mut().raw(), opt, &blob) }
+    }
+
     pub(super) unsafe fn setopt_long(
         self: Pin<&mut Self>,
         opt: curl_sys::CURLoption,
@@ -73,6 +87,16 @@ impl RawEasy {
         }
     }
 
+    pub fn set_unix_socket_path<'s>(
+        self: Pin<&mut Self>,
+        path: impl Into<Cow<'s, str>>,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_str(curl_sys::CURLOPT_UNIX_SOCKET_PATH, path.into())
+                .with_easy_context("setopt CURLOPT_UNIX_SOCKET_PATH")
+        }
+    }
+
     pub fn set_http_proxy_tunnel(
         self: Pin<&mut Self>,
         tunnel: bool,
@@ -113,6 +137,17 @@ impl RawEasy {
         }
     }
 
+    /// Adds a cookie in Netscape or `Set-Cookie` format, or runs a command such as `ALL`.
+    pub fn set_cookielist<'s>(
+        self: Pin<&mut Self>,
+        cookie: impl Into<Cow<'s, str>>,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_str(curl_sys::CURLOPT_COOKIELIST, cookie.into())
+                .with_easy_context("setopt CURLOPT_COOKIELIST")
+        }
+    }
+
     pub fn set_timeout(
         self: Pin<&mut Self>,
         timeout: std::time::Duration,
@@ -134,6 +169,44 @@ impl RawEasy {
         }
     }
 
+    pub fn set_connect_timeout(
+        self: Pin<&mut Self>,
+        timeout: std::time::Duration,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            let ms = timeout.as_millis();
+            match c_long::try_from(ms) {
+                Ok(amt) => self.setopt_long(curl_sys::CURLOPT_CONNECTTIMEOUT_MS, amt),
+                Err(_) => {
+                    let amt = c_long::try_from(ms / 1000).map_err(|_| {
+                        curl_sys::CURLE_BAD_FUNCTION_ARGUMENT
+                            .with_easy_context("setopt CURLOPT_CONNECTTIMEOUT convert")
+                            .unwrap_err()
+                    })?;
+                    self.setopt_long(curl_sys::CURLOPT_CONNECTTIMEOUT, amt)
+                }
+            }
+            .with_easy_context("setopt CURLOPT_CONNECTTIMEOUT(_MS)")
+        }
+    }
+
+    pub fn set_low_speed_limit(
+        mut self: Pin<&mut Self>,
+        bytes_per_sec: u32,
+        time: std::time::Duration,
+    ) -> Result<(), CurlCodeContext> {
+        // CURLOPT_LOW_SPEED_TIME only takes whole seconds
+        let secs = time.as_secs() + u64::from(time.subsec_nanos() > 0);
+        let secs = c_long::try_from(secs.max(1)).unwrap_or(c_long::MAX);
+        unsafe {
+            self.as_mut()
+                .setopt_long(curl_sys::CURLOPT_LOW_SPEED_LIMIT, bytes_per_sec as c_long)
+                .with_easy_context("setopt CURLOPT_LOW_SPEED_LIMIT")?;
+            self.setopt_long(curl_sys::CURLOPT_LOW_SPEED_TIME, secs)
+                .with_easy_context("setopt CURLOPT_LOW_SPEED_TIME")
+        }
+    }
+
     pub fn set_ssl_verify_peer(self: Pin<&mut Self>, verify: bool) -> Result<(), CurlCodeContext> {
         unsafe {
             self.setopt_long(curl_sys::CURLOPT_SSL_VERIFYPEER, verify as c_long)
@@ -141,6 +214,86 @@ impl RawEasy {
         }
     }
 
+    pub fn set_ssl_version(self: Pin<&mut Self>, version: c_long) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_long(curl_sys::CURLOPT_SSLVERSION, version)
+                .with_easy_context("setopt CURLOPT_SSLVERSION")
+        }
+    }
+
+    pub fn set_http_version(self: Pin<&mut Self>, version: c_long) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_long(curl_sys::CURLOPT_HTTP_VERSION, version)
+                .with_easy_context("setopt CURLOPT_HTTP_VERSION")
+        }
+    }
+
+    pub fn set_ca_info_blob(self: Pin<&mut Self>, bundle: &[u8]) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_blob(curl_sys::CURLOPT_CAINFO_BLOB, bundle)
+                .with_easy_context("setopt CURLOPT_CAINFO_BLOB")
+        }
+    }
+
+    /// Stops loading root certificates from the default CA bundle file and directory.
+    pub fn clear_default_ca_locations(mut self: Pin<&mut Self>) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.as_mut()
+                .setopt_ptr(curl_sys::CURLOPT_CAINFO, null())
+                .with_easy_context("setopt CURLOPT_CAINFO null")?;
+            self.setopt_ptr(curl_sys::CURLOPT_CAPATH, null())
+                .with_easy_context("setopt CURLOPT_CAPATH null")
+        }
+    }
+
+    pub fn set_pinned_public_key<'s>(
+        self: Pin<&mut Self>,
+        pins: impl Into<Cow<'s, str>>,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_str(curl_sys::CURLOPT_PINNEDPUBLICKEY, pins.into())
+                .with_easy_context("setopt CURLOPT_PINNEDPUBLICKEY")
+        }
+    }
+
+    pub fn set_ssl_cert_blob(
+        mut self: Pin<&mut Self>,
+        cert: &[u8],
+        cert_type: &str,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.as_mut()
+                .setopt_str(curl_sys::CURLOPT_SSLCERTTYPE, cert_type.into())
+                .with_easy_context("setopt CURLOPT_SSLCERTTYPE")?;
+            self.setopt_blob(curl_sys::CURLOPT_SSLCERT_BLOB, cert)
+                .with_easy_context("setopt CURLOPT_SSLCERT_BLOB")
+        }
+    }
+
+    pub fn set_ssl_key_blob(
+        mut self: Pin<&mut Self>,
+        key: &[u8],
+        key_type: &str,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.as_mut()
+                .setopt_str(curl_sys::CURLOPT_SSLKEYTYPE, key_type.into())
+                .with_easy_context("setopt CURLOPT_SSLKEYTYPE")?;
+            self.setopt_blob(curl_sys::CURLOPT_SSLKEY_BLOB, key)
+                .with_easy_context("setopt CURLOPT_SSLKEY_BLOB")
+        }
+    }
+
+    pub fn set_key_password<'s>(
+        self: Pin<&mut Self>,
+        password: impl Into<Cow<'s, str>>,
+    ) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_str(curl_sys::CURLOPT_KEYPASSWD, password.into())
+                .with_easy_context("setopt CURLOPT_KEYPASSWD")
+        }
+    }
+
     pub fn _set_ssl_verify_host(self: Pin<&mut Self>, verify: bool) -> Result<(), CurlCodeContext> {
         unsafe {
             self.setopt_long(curl_sys::CURLOPT_SSL_VERIFYHOST, verify as c_long)
@@ -148,6 +301,13 @@ impl RawEasy {
         }
     }
 
+    pub fn set_noprogress(self: Pin<&mut Self>, noprogress: bool) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_long(curl_sys::CURLOPT_NOPROGRESS, noprogress as c_long)
+                .with_easy_context("setopt CURLOPT_NOPROGRESS")
+        }
+    }
+
     pub fn set_follow_location(self: Pin<&mut Self>, follow: bool) -> Result<(), CurlCodeContext> {
         unsafe {
             self.setopt_long(curl_sys::CURLOPT_FOLLOWLOCATION, follow as c_long)
@@ -155,6 +315,13 @@ impl RawEasy {
         }
     }
 
+    pub fn set_max_redirs(self: Pin<&mut Self>, max: u32) -> Result<(), CurlCodeContext> {
+        unsafe {
+            self.setopt_long(curl_sys::CURLOPT_MAXREDIRS, max as c_long)
+                .with_easy_context("setopt CURLOPT_MAXREDIRS")
+        }
+    }
+
     pub fn set_url<'s>(
         self: Pin<&mut Self>,
         url: impl Into<Cow<'s, str>>,
@@ -30,6 +30,15 @@ impl CurlStringList {
         }
     }
 
+    /// Takes ownership of a list allocated by libcurl.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be null or a list to be freed with `curl_slist_free_all`.
+    pub unsafe fn from_raw(raw: *mut curl_sys::curl_slist) -> Self {
+        Self { raw }
+    }
+
     pub fn raw(&self) -> *mut curl_sys::curl_slist {
         self.raw
     }
@@ -1,16 +1,48 @@
-use nyquest_interface::Error as NyquestError;
+use nyquest_interface::{Error as NyquestError, ErrorKind};
 
 use crate::curl_ng::{error_context::CurlMultiCodeContext, CurlErrorContext};
 
+fn error_kind(code: curl_sys::CURLcode) -> Option<ErrorKind> {
+    use curl_sys::*;
+
+    Some(match code {
+        CURLE_COULDNT_RESOLVE_HOST => ErrorKind::Dns,
+        CURLE_COULDNT_RESOLVE_PROXY => ErrorKind::Proxy,
+        CURLE_COULDNT_CONNECT => ErrorKind::Connect,
+        CURLE_SSL_CONNECT_ERROR
+        | CURLE_SSL_CIPHER
+        | CURLE_SSL_ENGINE_NOTFOUND
+        | CURLE_SSL_ENGINE_SETFAILED
+        | CURLE_SSL_ENGINE_INITFAILED
+        | CURLE_SSL_SHUTDOWN_FAILED
+        | CURLE_USE_SSL_FAILED => ErrorKind::Tls,
+        CURLE_PEER_FAILED_VERIFICATION
+        | CURLE_SSL_CERTPROBLEM
+        | CURLE_SSL_CACERT_BADFILE
+        | CURLE_SSL_CRL_BADFILE
+        | CURLE_SSL_ISSUER_ERROR
+        | CURLE_SSL_PINNEDPUBKEYNOTMATCH
+        | CURLE_SSL_INVALIDCERTSTATUS => ErrorKind::Certificate,
+        CURLE_RECV_ERROR | CURLE_GOT_NOTHING | CURLE_PARTIAL_FILE => ErrorKind::BodyRead,
+        CURLE_SEND_ERROR | CURLE_READ_ERROR | CURLE_SEND_FAIL_REWIND => ErrorKind::BodyWrite,
+        CURLE_BAD_CONTENT_ENCODING => ErrorKind::Decode,
+        _ => return None,
+    })
+}
+
 impl<'e> From<CurlErrorContext<'e>> for NyquestError {
     fn from(e: CurlErrorContext<'e>) -> Self {
-        if e.code == curl_sys::CURLE_OPERATION_TIMEDOUT {
-            return NyquestError::RequestTimeout;
+        match e.code {
+            curl_sys::CURLE_OPERATION_TIMEDOUT => return NyquestError::RequestTimeout,
+            curl_sys::CURLE_TOO_MANY_REDIRECTS => return NyquestError::TooManyRedirects,
+            curl_sys::CURLE_URL_MALFORMAT => return NyquestError::InvalidUrl,
+            _ => {}
+        }
+        let err = std::io::Error::other(format!("curl error:{}:{}:{}", e.context, e.code, e.msg));
+        match error_kind(e.code) {
+            Some(kind) => NyquestError::backend(kind, err),
+            None => NyquestError::Io(err),
         }
-        NyquestError::Io(std::io::Error::other(format!(
-            "curl error:{}:{}:{}",
-            e.context, e.code, e.msg
-        )))
     }
 }
 
@@ -11,8 +11,12 @@ mod r#async;
 #[cfg(feature = "blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
 mod blocking;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod cookie;
 mod curl_ng;
 mod error;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod progress;
 mod request;
 mod state;
 mod url;
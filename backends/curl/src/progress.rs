@@ -0,0 +1,31 @@
+use nyquest_interface::{Progress, ProgressCallback};
+
+/// Forwards the transfer info reported by libcurl to a progress callback, skipping unchanged
+/// values.
+pub(crate) struct ProgressReporter {
+    callback: ProgressCallback,
+    last: Option<Progress>,
+}
+
+impl ProgressReporter {
+    pub(crate) fn new(callback: ProgressCallback) -> Self {
+        Self {
+            callback,
+            last: None,
+        }
+    }
+
+    pub(crate) fn report(&mut self, dltotal: u64, dlnow: u64, ultotal: u64, ulnow: u64) {
+        // libcurl reports 0 for unknown sizes
+        let progress = Progress {
+            uploaded: ulnow,
+            upload_total: (ultotal > 0).then_some(ultotal),
+            downloaded: dlnow,
+            download_total: (dltotal > 0).then_some(dltotal),
+        };
+        if self.last != Some(progress) {
+            self.last = Some(progress);
+            (self.callback)(progress);
+        }
+    }
+}
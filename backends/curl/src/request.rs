@@ -1,8 +1,15 @@
+use std::io;
+use std::net::IpAddr;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
 use std::pin::Pin;
 
-use nyquest_interface::client::ProxyOptions;
-use nyquest_interface::{Body, Method, Request, Result as NyquestResult};
+use nyquest_interface::client::{
+    ClientIdentity, ClientOptions, DnsResolver, HttpVersionPreference, ProxyOptions, TlsVersion,
+};
+use nyquest_interface::{
+    Body, Error as NyquestError, ErrorKind, HttpVersion, Method, Request, Result as NyquestResult,
+};
 
 use crate::curl_ng::CurlCodeContext;
 use crate::{
@@ -34,8 +41,232 @@ pub fn create_easy<C: EasyCallback>(callback: C, share: &Share) -> NyquestResult
     Ok(easy)
 }
 
+/// Rejects client options that the linked libcurl is not able to honor, and merges the root
+/// certificates to trust into a single bundle.
+pub fn prepare_options(mut options: ClientOptions) -> NyquestResult<ClientOptions> {
+    let version = curl::Version::get();
+    // CURLOPT_SSLCERT_BLOB is added in 7.71.0
+    if options.client_identity.is_some()
+        && (!version.feature_ssl() || version.version_num() < 0x074700)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "client certificates require libcurl 7.71.0 or later with TLS support",
+        )
+        .into());
+    }
+    // CURLOPT_CAINFO_BLOB is added in 7.77.0
+    if (!options.root_certificates.is_empty() || options.only_custom_roots)
+        && (!version.feature_ssl() || version.version_num() < 0x074d00)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "custom root certificates require libcurl 7.77.0 or later with TLS support",
+        )
+        .into());
+    }
+    // sha256// pins in CURLOPT_PINNEDPUBLICKEY are supported with OpenSSL since 7.44.0
+    if !options.pinned_public_keys.is_empty()
+        && (!version.feature_ssl() || version.version_num() < 0x072c00)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "public key pinning requires libcurl 7.44.0 or later with TLS support",
+        )
+        .into());
+    }
+    if let Some(path) = &options.unix_socket {
+        // CURLOPT_UNIX_SOCKET_PATH is added in 7.40.0
+        if !version.feature_unix_domain_socket() || version.version_num() < 0x072800 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Unix domain sockets require libcurl 7.40.0 or later with Unix socket support",
+            )
+            .into());
+        }
+        if path.to_str().is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Unix domain socket path must be valid UTF-8",
+            )
+            .into());
+        }
+    }
+    if let Some(path) = options.cookie_file.as_ref().filter(|_| options.use_cookies) {
+        // CURLOPT_COOKIELIST "RELOAD" is added in 7.39.0
+        if version.version_num() < 0x072700 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cookie files require libcurl 7.39.0 or later",
+            )
+            .into());
+        }
+        if path.to_str().is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cookie file path must be valid UTF-8",
+            )
+            .into());
+        }
+    }
+    if !version.feature_http2() {
+        match options.http_version {
+            HttpVersionPreference::Http2PriorKnowledge => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "HTTP/2 requires libcurl built with HTTP/2 support",
+                )
+                .into());
+            }
+            // Falls back to HTTP/1.1 as if the server does not support HTTP/2
+            HttpVersionPreference::PreferHttp2 => {
+                options.http_version = HttpVersionPreference::Default;
+            }
+            _ => {}
+        }
+    }
+    if !options.root_certificates.is_empty() {
+        // CURLOPT_CAINFO_BLOB disables the fallback to the default roots of the TLS library, and
+        // replaces the default CA bundle file with some TLS backends, so the default bundle is
+        // included in the blob to be trusted alongside the custom roots. CURLOPT_CAPATH is kept.
+        let mut bundle = if options.only_custom_roots {
+            vec![]
+        } else {
+            default_ca_bundle()
+        };
+        for cert in &options.root_certificates {
+            bundle.extend_from_slice(cert);
+            bundle.push(b'\n');
+        }
+        options.root_certificates = vec![bundle];
+    }
+    Ok(options)
+}
+
+/// Reads the default CA bundle of libcurl, to be trusted along with custom roots.
+///
+/// Falls back to the bundle of the system found by openssl-probe if libcurl does not report one,
+/// e.g. before 7.84 or when relying on the defaults of OpenSSL. Empty if no bundle is found.
+fn default_ca_bundle() -> Vec<u8> {
+    let path = RawEasy::new()
+        .get_default_ca_info()
+        .ok()
+        .flatten()
+        .map(PathBuf::from);
+    #[cfg(all(unix, not(target_os = "macos"), feature = "ssl"))]
+    let path = path.or_else(|| openssl_probe::probe().cert_file);
+    let Some(mut bundle) = path.and_then(|path| std::fs::read(path).ok()) else {
+        return vec![];
+    };
+    bundle.push(b'\n');
+    bundle
+}
+
+/// Converts a `CURLINFO_HTTP_VERSION` value to the HTTP version of a response.
+pub fn http_version_from_curl(version: libc::c_long) -> Option<HttpVersion> {
+    Some(match version as libc::c_int {
+        curl_sys::CURL_HTTP_VERSION_1_0 => HttpVersion::Http10,
+        curl_sys::CURL_HTTP_VERSION_1_1 => HttpVersion::Http11,
+        curl_sys::CURL_HTTP_VERSION_2_0 => HttpVersion::Http2,
+        curl_sys::CURL_HTTP_VERSION_3 => HttpVersion::Http3,
+        _ => return None,
+    })
+}
+
+/// The host of a request URL to be resolved by the custom resolver.
+pub struct ResolveTarget {
+    resolver: DnsResolver,
+    host: String,
+    port: u16,
+}
+
+/// The addresses of the host of a request URL, resolved by the custom resolver.
+pub struct ResolvedHost {
+    host: String,
+    port: u16,
+    addrs: Vec<IpAddr>,
+}
+
+impl ResolveTarget {
+    /// Returns the host of `url` to be resolved if a custom resolver is set, unless it is an IP
+    /// literal or covered by the overrides.
+    pub fn of(url: &str, options: &ClientOptions) -> Option<Self> {
+        let dns = &options.dns;
+        let resolver = dns
+            .resolver
+            .as_ref()
+            .filter(|_| options.unix_socket.is_none())?;
+        let (host, port) = crate::url::host_port(url)?;
+        if host.parse::<IpAddr>().is_ok()
+            || host.starts_with('[')
+            || dns
+                .overrides
+                .iter()
+                .any(|o| o.port == port && o.host.eq_ignore_ascii_case(host))
+        {
+            return None;
+        }
+        Some(Self {
+            resolver: resolver.clone(),
+            host: host.to_owned(),
+            port,
+        })
+    }
+
+    /// Calls the resolver, which blocks like getaddrinfo.
+    pub fn resolve(self) -> NyquestResult<ResolvedHost> {
+        let addrs =
+            (self.resolver)(&self.host).map_err(|e| NyquestError::backend(ErrorKind::Dns, e))?;
+        if addrs.is_empty() {
+            return Err(NyquestError::backend(
+                ErrorKind::Dns,
+                format!("no addresses resolved for {}", self.host),
+            ));
+        }
+        Ok(ResolvedHost {
+            host: self.host,
+            port: self.port,
+            addrs,
+        })
+    }
+}
+
+/// Builds the `CURLOPT_RESOLVE` entries for the static overrides, and for the host resolved by
+/// the custom resolver.
+fn resolve_entries(
+    options: &ClientOptions,
+    resolved: Option<&ResolvedHost>,
+) -> Option<CurlStringList> {
+    fn entry(host: &str, port: u16, addrs: &[IpAddr]) -> String {
+        let addrs: Vec<_> = addrs
+            .iter()
+            .map(|addr| match addr {
+                IpAddr::V4(addr) => addr.to_string(),
+                IpAddr::V6(addr) => format!("[{addr}]"),
+            })
+            .collect();
+        format!("{host}:{port}:{}", addrs.join(","))
+    }
+
+    let overrides = &options.dns.overrides;
+    if overrides.is_empty() && resolved.is_none() {
+        return None;
+    }
+    let mut list = CurlStringList::default();
+    for o in overrides {
+        list.append(entry(&o.host, o.port, &o.addrs));
+    }
+    if let Some(resolved) = resolved {
+        list.append(entry(&resolved.host, resolved.port, &resolved.addrs));
+    }
+    Some(list)
+}
+
+/// Sets up `easy` to send `req` to `url`, with the host resolved by the custom resolver if any,
+/// see [`ResolveTarget`].
 pub fn populate_request<S, C: EasyCallback, R: MimePartReader + Send + 'static>(
     url: &str,
+    resolved: Option<&ResolvedHost>,
     mut req: Request<S>,
     options: &nyquest_interface::client::ClientOptions, // FIXME: strings in options are copied to be null-terminated. Can we avoid that?
     easy: Pin<&mut EasyHandle<C>>,
@@ -44,6 +275,7 @@ pub fn populate_request<S, C: EasyCallback, R: MimePartReader + Send + 'static>(
     mut populate_mime_stream: impl FnMut(S) -> MimePartContent<R>,
 ) -> NyquestResult<()> {
     let mut headers = CurlStringList::default();
+    let resolve = resolve_entries(options, resolved);
     easy.with_error_message(|mut e| {
         let mut raw = e.as_mut().as_raw_easy_mut();
         match &options.proxy_options {
@@ -72,20 +304,83 @@ pub fn populate_request<S, C: EasyCallback, R: MimePartReader + Send + 'static>(
                 }
             }
         }
+        if let Some(path) = &options.unix_socket {
+            raw.as_mut().set_unix_socket_path(path.to_string_lossy())?;
+        }
         if let Some(user_agent) = options.user_agent.as_deref() {
             raw.as_mut().set_useragent(user_agent)?;
         }
         if options.use_cookies {
             raw.as_mut().set_cookiefile("")?;
         }
-        if let Some(timeout) = options.request_timeout {
+        if let Some(timeout) = req.options.request_timeout.or(options.request_timeout) {
             raw.as_mut().set_timeout(timeout)?;
         }
+        if let Some(timeout) = req.options.connect_timeout.or(options.connect_timeout) {
+            raw.as_mut().set_connect_timeout(timeout)?;
+        }
+        if let Some(timeout) = req.options.read_timeout.or(options.read_timeout) {
+            // Abort when less than 1 byte per second is transferred on average over the timeout,
+            // which is close to but not the same as receiving no data during the timeout
+            raw.as_mut().set_low_speed_limit(1, timeout)?;
+        }
         if options.ignore_certificate_errors {
             raw.as_mut().set_ssl_verify_peer(false)?;
         }
-        if options.follow_redirects {
+        if let Some(version) = options.min_tls_version {
+            let version = match version {
+                TlsVersion::Tls1_0 => curl_sys::CURL_SSLVERSION_TLSv1_0,
+                TlsVersion::Tls1_1 => curl_sys::CURL_SSLVERSION_TLSv1_1,
+                TlsVersion::Tls1_2 => curl_sys::CURL_SSLVERSION_TLSv1_2,
+                TlsVersion::Tls1_3 => curl_sys::CURL_SSLVERSION_TLSv1_3,
+            };
+            raw.as_mut().set_ssl_version(version as _)?;
+        }
+        let http_version = match options.http_version {
+            HttpVersionPreference::Default => None,
+            HttpVersionPreference::Http1Only => Some(curl_sys::CURL_HTTP_VERSION_1_1),
+            HttpVersionPreference::PreferHttp2 => Some(curl_sys::CURL_HTTP_VERSION_2TLS),
+            HttpVersionPreference::Http2PriorKnowledge => {
+                Some(curl_sys::CURL_HTTP_VERSION_2_PRIOR_KNOWLEDGE)
+            }
+        };
+        if let Some(version) = http_version {
+            raw.as_mut().set_http_version(version as _)?;
+        }
+        if let Some(bundle) = options.root_certificates.first() {
+            raw.as_mut().set_ca_info_blob(bundle)?;
+        }
+        if options.only_custom_roots {
+            raw.as_mut().clear_default_ca_locations()?;
+        }
+        if !options.pinned_public_keys.is_empty() {
+            let pins: Vec<_> = options
+                .pinned_public_keys
+                .iter()
+                .map(|pin| format!("sha256//{pin}"))
+                .collect();
+            raw.as_mut().set_pinned_public_key(pins.join(";"))?;
+        }
+        match &options.client_identity {
+            None => {}
+            Some(ClientIdentity::Pem { cert, key }) => {
+                raw.as_mut().set_ssl_cert_blob(cert, "PEM")?;
+                raw.as_mut().set_ssl_key_blob(key, "PEM")?;
+            }
+            Some(ClientIdentity::Pkcs12 { der, password }) => {
+                raw.as_mut().set_ssl_cert_blob(der, "P12")?;
+                raw.as_mut().set_key_password(&**password)?;
+            }
+        }
+        if req
+            .options
+            .follow_redirects
+            .unwrap_or(options.follow_redirects)
+        {
             raw.as_mut().set_follow_location(true)?;
+            if let Some(max) = options.redirect_policy.max_redirects {
+                raw.as_mut().set_max_redirs(max)?;
+            }
         }
         raw.as_mut().set_url(url)?;
         if let Method::Other(method) = &req.method {
@@ -178,8 +473,9 @@ pub fn populate_request<S, C: EasyCallback, R: MimePartReader + Send + 'static>(
             }
             None => {}
         }
-        let header = e.as_easy_mut().as_easy_mut().as_easy_mut();
-        header.set_headers(Some(headers))?;
+        let mut header = e.as_easy_mut().as_easy_mut().as_easy_mut();
+        header.as_mut().set_headers(Some(headers))?;
+        header.set_resolve(resolve)?;
         Ok(())
     })?;
     Ok(())
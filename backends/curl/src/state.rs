@@ -1,9 +1,12 @@
+use nyquest_interface::RedirectHop;
+
 #[derive(Debug, Default)]
 pub(crate) struct RequestState {
     pub(crate) temp_status_code: u16,
     pub(crate) is_established: bool,
     pub(crate) header_finished: bool,
     pub(crate) response_headers_buffer: Vec<Vec<u8>>,
+    pub(crate) redirect_history: Vec<RedirectHop>,
     pub(crate) response_buffer: Vec<u8>,
 }
 
@@ -20,6 +23,14 @@ impl RequestState {
             self.response_headers_buffer
                 .push(data.strip_suffix(b"\r\n").unwrap_or(data).into());
         } else {
+            // A new status line starts a new response, e.g. after a redirect or an interim 100.
+            if (300..400).contains(&self.temp_status_code) {
+                self.redirect_history.push(RedirectHop {
+                    status: self.temp_status_code,
+                    location: self.find_header("location"),
+                });
+            }
+            self.response_headers_buffer.clear();
             let mut status_components = data.splitn(3, u8::is_ascii_whitespace).skip(1);
 
             if let Some(status) = status_components
@@ -37,6 +48,16 @@ impl RequestState {
         false
     }
 
+    fn find_header(&self, name: &str) -> Option<String> {
+        self.response_headers_buffer.iter().find_map(|line| {
+            let line = std::str::from_utf8(line).ok()?;
+            let (k, v) = line.split_once(':')?;
+            k.trim()
+                .eq_ignore_ascii_case(name)
+                .then(|| v.trim().to_owned())
+        })
+    }
+
     pub(crate) fn write_data(&mut self, data: &[u8]) {
         self.header_finished = true;
         // TODO: handle max response buffer size
@@ -40,6 +40,14 @@ impl AsyncResponse for NSUrlSessionAsyncResponse {
         self.inner.get_header(header)
     }
 
+    fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        self.inner.headers()
+    }
+
+    fn url(&self) -> Option<String> {
+        self.inner.url()
+    }
+
     async fn text(mut self: Pin<&mut Self>) -> NyquestResult<String> {
         let bytes = self.as_mut().bytes().await?;
         self.inner.convert_bytes_to_string(bytes)
@@ -110,7 +118,7 @@ impl AsyncClient for NSUrlSessionAsyncClient {
             }
         })?;
         let shared = {
-            let delegate = DataTaskDelegate::new(waker, self.inner.allow_redirects);
+            let delegate = DataTaskDelegate::new(waker, self.inner.max_redirects);
             task.setDelegate(Some(ProtocolObject::from_ref(&*delegate)));
             task.resume();
             DataTaskDelegate::into_shared(delegate)
@@ -54,6 +54,14 @@ impl BlockingResponse for NSUrlSessionBlockingResponse {
         self.inner.get_header(header)
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        self.inner.headers()
+    }
+
+    fn url(&self) -> Option<String> {
+        self.inner.url()
+    }
+
     fn text(&mut self) -> nyquest_interface::Result<String> {
         let bytes = self.bytes()?;
         self.inner.convert_bytes_to_string(bytes)
@@ -97,7 +105,7 @@ impl BlockingClient for NSUrlSessionBlockingClient {
             }
         })?;
         let shared = {
-            let delegate = DataTaskDelegate::new(waker, self.inner.allow_redirects);
+            let delegate = DataTaskDelegate::new(waker, self.inner.max_redirects);
             task.setDelegate(Some(ProtocolObject::from_ref(&*delegate)));
             task.resume();
             DataTaskDelegate::into_shared(delegate)
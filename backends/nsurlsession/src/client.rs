@@ -1,7 +1,9 @@
 use std::borrow::Cow;
 use std::sync::LazyLock;
 
-use nyquest_interface::client::{CachingBehavior, ClientOptions, ProxyOptions};
+use nyquest_interface::client::{
+    CachingBehavior, ClientOptions, HttpVersionPreference, ProxyOptions,
+};
 use nyquest_interface::{Body, Error as NyquestError, Method, Request, Result as NyquestResult};
 use objc2::rc::Retained;
 use objc2::runtime::{AnyObject, ProtocolObject};
@@ -21,11 +23,62 @@ pub struct NSUrlSessionClient {
     pub(crate) session: Retained<objc2_foundation::NSURLSession>,
     pub(crate) base_url: Option<Retained<NSURL>>,
     pub(crate) max_response_buffer_size: u64,
-    pub(crate) allow_redirects: bool,
+    pub(crate) max_redirects: u32,
 }
 
 impl NSUrlSessionClient {
     pub(crate) fn create(options: ClientOptions) -> NyquestResult<Self> {
+        if options.client_identity.is_some() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "client certificates are not supported by the NSURLSession backend",
+            )));
+        }
+        if !options.root_certificates.is_empty() || options.only_custom_roots {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "custom root certificates are not supported by the NSURLSession backend",
+            )));
+        }
+        if !options.pinned_public_keys.is_empty() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "public key pinning is not supported by the NSURLSession backend",
+            )));
+        }
+        if !options.dns.overrides.is_empty() || options.dns.resolver.is_some() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "DNS overrides are not supported by the NSURLSession backend",
+            )));
+        }
+        if options.unix_socket.is_some() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "Unix domain sockets are not supported by the NSURLSession backend",
+            )));
+        }
+        if options.cookie_file.is_some() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "cookie files are not supported by the NSURLSession backend",
+            )));
+        }
+        if options.min_tls_version.is_some() {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "minimum TLS versions are not supported by the NSURLSession backend",
+            )));
+        }
+        if !matches!(
+            options.http_version,
+            HttpVersionPreference::Default | HttpVersionPreference::PreferHttp2
+        ) {
+            return Err(NyquestError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "restricting HTTP versions is not supported by the NSURLSession backend",
+            )));
+        }
         let session = unsafe {
             let config = objc2_foundation::NSURLSessionConfiguration::defaultSessionConfiguration();
             if options.caching_behavior == CachingBehavior::Disabled {
@@ -120,7 +173,11 @@ impl NSUrlSessionClient {
             session,
             base_url,
             max_response_buffer_size: options.max_response_buffer_size.unwrap_or(u64::MAX),
-            allow_redirects: options.follow_redirects,
+            max_redirects: if options.follow_redirects {
+                options.redirect_policy.max_redirects.unwrap_or(30)
+            } else {
+                0
+            },
         })
     }
 
@@ -100,7 +100,7 @@ pub(crate) struct DataTaskSharedContextRetained {
 }
 
 impl DataTaskDelegate {
-    pub(crate) fn new(waker: GenericWaker, allow_redirects: bool) -> Retained<Self> {
+    pub(crate) fn new(waker: GenericWaker, max_redirects: u32) -> Retained<Self> {
         let this = Self::alloc().set_ivars(DataTaskIvars {
             // continue_response_block: ArcSwapAny::new(None),
             shared: DataTaskIvarsShared {
@@ -111,7 +111,7 @@ impl DataTaskDelegate {
                 response_buffer: Default::default(),
                 max_response_buffer_size: u64::MAX.into(),
             },
-            redirects_allowed: if allow_redirects { 30 } else { 0 }.into(),
+            redirects_allowed: max_redirects.into(),
         });
         // SAFETY: The signature of `NSObject`'s `init` method is correct.
         unsafe { msg_send![super(this), init] }
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Mutex;
 
 use arc_swap::ArcSwapAny;
@@ -14,7 +14,7 @@ pub(crate) struct DataTaskIvars {
     // pub(super) continue_response_block:
     //     ArcSwapAny<Option<SwappableRcBlock<dyn Fn(NSURLSessionResponseDisposition)>>>,
     pub(super) shared: DataTaskIvarsShared,
-    pub(super) redirects_allowed: AtomicU8,
+    pub(super) redirects_allowed: AtomicU32,
 }
 
 pub(super) struct DataTaskIvarsShared {
@@ -1,8 +1,30 @@
 use std::io;
 
-use nyquest_interface::{Error as NyquestError, Result as NyquestResult};
+use nyquest_interface::{Error as NyquestError, ErrorKind, Result as NyquestResult};
 use objc2::rc::{autoreleasepool, Retained};
-use objc2_foundation::{NSError, NSURLErrorTimedOut};
+use objc2_foundation::{
+    NSError, NSInteger, NSURLErrorCannotConnectToHost, NSURLErrorCannotDecodeContentData,
+    NSURLErrorCannotDecodeRawData, NSURLErrorCannotFindHost, NSURLErrorDNSLookupFailed,
+    NSURLErrorHTTPTooManyRedirects, NSURLErrorNetworkConnectionLost,
+    NSURLErrorSecureConnectionFailed, NSURLErrorServerCertificateHasBadDate,
+    NSURLErrorServerCertificateHasUnknownRoot, NSURLErrorServerCertificateNotYetValid,
+    NSURLErrorServerCertificateUntrusted, NSURLErrorTimedOut, NSURLErrorZeroByteResource,
+};
+
+fn error_kind(code: NSInteger) -> Option<ErrorKind> {
+    Some(match code {
+        NSURLErrorCannotFindHost | NSURLErrorDNSLookupFailed => ErrorKind::Dns,
+        NSURLErrorCannotConnectToHost => ErrorKind::Connect,
+        NSURLErrorNetworkConnectionLost | NSURLErrorZeroByteResource => ErrorKind::BodyRead,
+        NSURLErrorSecureConnectionFailed => ErrorKind::Tls,
+        NSURLErrorServerCertificateHasBadDate
+        | NSURLErrorServerCertificateUntrusted
+        | NSURLErrorServerCertificateHasUnknownRoot
+        | NSURLErrorServerCertificateNotYetValid => ErrorKind::Certificate,
+        NSURLErrorCannotDecodeRawData | NSURLErrorCannotDecodeContentData => ErrorKind::Decode,
+        _ => return None,
+    })
+}
 
 pub(crate) trait IntoNyquestResult<T> {
     fn into_nyquest_result(self) -> NyquestResult<T>;
@@ -11,16 +33,18 @@ pub(crate) trait IntoNyquestResult<T> {
 impl<T> IntoNyquestResult<T> for Result<T, Retained<NSError>> {
     fn into_nyquest_result(self) -> NyquestResult<T> {
         self.map_err(|e| {
-            if e.code() == NSURLErrorTimedOut {
-                return NyquestError::RequestTimeout;
+            match e.code() {
+                NSURLErrorTimedOut => return NyquestError::RequestTimeout,
+                NSURLErrorHTTPTooManyRedirects => return NyquestError::TooManyRedirects,
+                _ => {}
             }
             let msg =
                 autoreleasepool(|pool| unsafe { e.localizedDescription().to_str(pool).to_owned() });
-            NyquestError::Io(io::Error::other(format!(
-                "NSURLSession error {}: {}",
-                e.code(),
-                msg
-            )))
+            let err = io::Error::other(format!("NSURLSession error {}: {}", e.code(), msg));
+            match error_kind(e.code()) {
+                Some(kind) => NyquestError::backend(kind, err),
+                None => NyquestError::Io(err),
+            }
         })
     }
 }
@@ -37,6 +37,28 @@ impl NSUrlSessionResponse {
             .collect())
     }
 
+    pub(crate) fn url(&self) -> Option<String> {
+        let url = self.response.URL()?.absoluteString()?;
+        Some(autoreleasepool(|pool| unsafe {
+            url.to_str(pool).to_owned()
+        }))
+    }
+
+    pub(crate) fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        // Foundation merges duplicated headers and does not keep the original order.
+        let (keys, values) = self.response.allHeaderFields().to_vecs();
+        Ok(autoreleasepool(|pool| {
+            keys.into_iter()
+                .zip(values)
+                .filter_map(|(k, v)| {
+                    let k = k.downcast::<NSString>().ok()?;
+                    let v = v.downcast::<NSString>().ok()?;
+                    Some(unsafe { (k.to_str(pool).to_owned(), v.to_str(pool).to_owned()) })
+                })
+                .collect()
+        }))
+    }
+
     fn detect_response_encoding(&self) -> Option<NSStringEncoding> {
         let content_type = self
             .response
@@ -1,16 +1,18 @@
 use std::future::{poll_fn, Future};
 use std::pin::{pin, Pin};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use futures::future::{select, Either};
 use nyquest_interface::client::ClientOptions;
 use nyquest_interface::r#async::{AsyncClient, AsyncResponse, Request};
-use nyquest_interface::Result as NyquestResult;
+use nyquest_interface::{ProgressCallback, Result as NyquestResult};
 use tokio::runtime::{Handle, Runtime};
 
 use crate::client::ReqwestClient;
-use crate::error::{ReqwestBackendError, Result};
-use crate::response::ReqwestResponse;
+use crate::error::Result;
+use crate::progress::ProgressTracker;
+use crate::response::{with_read_timeout, ReqwestResponse};
 
 #[cfg(feature = "async-stream")]
 mod stream;
@@ -25,16 +27,15 @@ impl ReqwestAsyncClient {
         let inner = ReqwestClient::new(options)?;
         Ok(Self { inner })
     }
-}
-
-impl AsyncClient for ReqwestAsyncClient {
-    type Response = ReqwestAsyncResponse;
-
-    fn describe(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ReqwestAsyncClient")
-    }
 
-    async fn request(&self, req: Request) -> NyquestResult<Self::Response> {
+    async fn request_inner(
+        &self,
+        req: Request,
+        progress: Option<ProgressCallback>,
+    ) -> NyquestResult<ReqwestAsyncResponse> {
+        let max_response_buffer_size = self.inner.max_response_buffer_size(&req.options);
+        let follow_redirects = req.options.follow_redirects;
+        let read_timeout = req.options.read_timeout;
         #[cfg(feature = "async-stream")]
         let mut stream_task_collection = stream::StreamTaskCollection::default();
         let request_builder = self.inner.request(req, |stream| {
@@ -54,15 +55,25 @@ impl AsyncClient for ReqwestAsyncClient {
                 unreachable!("async-stream feature is disabled")
             }
         })?;
+        let progress = progress.map(ProgressTracker::new);
+        let request_builder = match &progress {
+            Some(progress) => progress.track_upload(request_builder)?,
+            None => request_builder,
+        };
 
         // Execute the request using shared runtime handling
+        let proxies = self.inner.proxies.clone();
         let req_task = pin!(execute_with_runtime_async(
             &self.inner.managed_runtime,
-            || async {
-                request_builder
-                    .send()
-                    .await
-                    .map_err(ReqwestBackendError::Reqwest)
+            move || async move {
+                let send = async {
+                    let (res, history) =
+                        crate::redirect::with_override(follow_redirects, request_builder.send())
+                            .await;
+                    res.map(|res| (res, history))
+                        .map_err(|e| proxies.send_error(e))
+                };
+                with_read_timeout(read_timeout, send).await
             }
         ));
         #[cfg(not(feature = "async-stream"))]
@@ -75,9 +86,43 @@ impl AsyncClient for ReqwestAsyncClient {
         } else {
             unreachable!()
         };
-        ReqwestAsyncResponse::new(response?, self.inner.max_response_buffer_size, handle)
-            .await
-            .map_err(Into::into)
+        let (response, redirect_history) = response?;
+        let mut response =
+            ReqwestAsyncResponse::new(response, max_response_buffer_size, read_timeout, handle)
+                .await?;
+        response.response.set_redirect_history(redirect_history);
+        if let Some(progress) = progress {
+            response.response.track_progress(progress);
+        }
+        Ok(response)
+    }
+}
+
+impl AsyncClient for ReqwestAsyncClient {
+    type Response = ReqwestAsyncResponse;
+
+    fn describe(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReqwestAsyncClient")
+    }
+
+    fn cookie_jar(&self) -> Option<Arc<dyn nyquest_interface::CookieJar>> {
+        self.inner.cookie_jar()
+    }
+
+    fn flush_cookies(&self) -> NyquestResult<()> {
+        self.inner.flush_cookies()
+    }
+
+    async fn request(&self, req: Request) -> NyquestResult<Self::Response> {
+        self.request_inner(req, None).await
+    }
+
+    async fn request_with_progress(
+        &self,
+        req: Request,
+        progress: ProgressCallback,
+    ) -> NyquestResult<Self::Response> {
+        self.request_inner(req, Some(progress)).await
     }
 }
 
@@ -126,10 +171,11 @@ impl ReqwestAsyncResponse {
     async fn new(
         response: reqwest::Response,
         max_response_buffer_size: Option<u64>,
+        read_timeout: Option<Duration>,
         current_handle: Handle,
     ) -> Result<Self> {
         Ok(Self {
-            response: ReqwestResponse::new(response, max_response_buffer_size),
+            response: ReqwestResponse::new(response, max_response_buffer_size, read_timeout),
             current_handle,
         })
     }
@@ -152,6 +198,22 @@ impl AsyncResponse for ReqwestAsyncResponse {
         self.response.get_header(header)
     }
 
+    fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        self.response.headers()
+    }
+
+    fn url(&self) -> Option<String> {
+        Some(self.response.url().into())
+    }
+
+    fn version(&self) -> Option<nyquest_interface::HttpVersion> {
+        self.response.version()
+    }
+
+    fn redirect_history(&self) -> Vec<nyquest_interface::RedirectHop> {
+        self.response.redirect_history().to_vec()
+    }
+
     async fn text(self: Pin<&mut Self>) -> NyquestResult<String> {
         #[cfg(feature = "charset")]
         {
@@ -1,15 +1,22 @@
+use std::future::Future;
 #[cfg(feature = "blocking-stream")]
 use std::io::{self, Read};
+use std::pin::pin;
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use futures::future::{select, Either};
 
 use nyquest_interface::blocking::{BlockingClient, BlockingResponse, Request};
 use nyquest_interface::client::ClientOptions;
-use nyquest_interface::Result as NyquestResult;
+use nyquest_interface::{
+    CancellationToken, Error as NyquestError, ProgressCallback, Result as NyquestResult,
+};
 use tokio::runtime::{Handle, Runtime};
 
 use crate::client::ReqwestClient;
-use crate::error::ReqwestBackendError;
-use crate::response::ReqwestResponse;
+use crate::progress::ProgressTracker;
+use crate::response::{with_read_timeout, ReqwestResponse};
 
 #[cfg(feature = "blocking-stream")]
 mod stream;
@@ -33,9 +40,27 @@ impl BlockingClient for ReqwestBlockingClient {
         write!(f, "ReqwestBlockingClient")
     }
 
+    fn cookie_jar(&self) -> Option<Arc<dyn nyquest_interface::CookieJar>> {
+        self.inner.cookie_jar()
+    }
+
+    fn flush_cookies(&self) -> NyquestResult<()> {
+        self.inner.flush_cookies()
+    }
+
     fn request(&self, req: Request) -> NyquestResult<Self::Response> {
         execute_with_runtime(&self.inner.managed_runtime, || async {
-            execute_request(self, req).await
+            execute_request(self, req, None).await
+        })
+    }
+
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: ProgressCallback,
+    ) -> NyquestResult<Self::Response> {
+        execute_with_runtime(&self.inner.managed_runtime, || async {
+            execute_request(self, req, Some(progress)).await
         })
     }
 }
@@ -43,7 +68,12 @@ impl BlockingClient for ReqwestBlockingClient {
 async fn execute_request(
     this: &ReqwestBlockingClient,
     req: Request,
+    progress: Option<ProgressCallback>,
 ) -> NyquestResult<ReqwestBlockingResponse> {
+    let cancellation = req.cancellation.clone();
+    let max_response_buffer_size = this.inner.max_response_buffer_size(&req.options);
+    let follow_redirects = req.options.follow_redirects;
+    let read_timeout = req.options.read_timeout;
     let request_builder = this.inner.request(req, |stream| {
         #[cfg(feature = "blocking-stream")]
         {
@@ -63,35 +93,72 @@ async fn execute_request(
             unreachable!("blocking-stream feature is disabled")
         }
     })?;
+    let progress = progress.map(ProgressTracker::new);
+    let request_builder = match &progress {
+        Some(progress) => progress.track_upload(request_builder)?,
+        None => request_builder,
+    };
 
-    let response = request_builder
-        .send()
-        .await
-        .map_err(ReqwestBackendError::Reqwest)?;
+    let send = async {
+        let (res, history) =
+            crate::redirect::with_override(follow_redirects, request_builder.send()).await;
+        res.map(|res| (res, history))
+            .map_err(|e| this.inner.proxies.send_error(e))
+    };
+    let (response, redirect_history) =
+        until_cancelled(cancellation.as_ref(), with_read_timeout(read_timeout, send))
+            .await
+            .ok_or(NyquestError::Cancelled)??;
 
-    Ok(ReqwestBlockingResponse::new(
+    let mut response = ReqwestBlockingResponse::new(
         response,
-        this.inner.max_response_buffer_size,
+        max_response_buffer_size,
+        read_timeout,
         this.inner.managed_runtime.clone(),
-    )?)
+        cancellation,
+    )?;
+    response.response.set_redirect_history(redirect_history);
+    if let Some(progress) = progress {
+        response.response.track_progress(progress);
+    }
+    Ok(response)
+}
+
+/// Drives `fut` to completion unless the token is cancelled first, in which case `fut` is
+/// dropped and `None` is returned.
+async fn until_cancelled<F: Future>(
+    cancellation: Option<&CancellationToken>,
+    fut: F,
+) -> Option<F::Output> {
+    let Some(cancellation) = cancellation else {
+        return Some(fut.await);
+    };
+    match select(pin!(fut), pin!(cancellation.cancelled())).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(((), _)) => None,
+    }
 }
 
 pub struct ReqwestBlockingResponse {
     response: ReqwestResponse,
     managed_runtime: Arc<OnceLock<Runtime>>,
+    cancellation: Option<CancellationToken>,
 }
 
 impl ReqwestBlockingResponse {
     fn new(
         response: reqwest::Response,
         max_response_buffer_size: Option<u64>,
+        read_timeout: Option<Duration>,
         managed_runtime: Arc<OnceLock<Runtime>>,
+        cancellation: Option<CancellationToken>,
     ) -> crate::error::Result<Self> {
-        let response = ReqwestResponse::new(response, max_response_buffer_size);
+        let response = ReqwestResponse::new(response, max_response_buffer_size, read_timeout);
 
         Ok(Self {
             response,
             managed_runtime,
+            cancellation,
         })
     }
 }
@@ -139,6 +206,22 @@ impl BlockingResponse for ReqwestBlockingResponse {
         self.response.get_header(header)
     }
 
+    fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        self.response.headers()
+    }
+
+    fn url(&self) -> Option<String> {
+        Some(self.response.url().into())
+    }
+
+    fn version(&self) -> Option<nyquest_interface::HttpVersion> {
+        self.response.version()
+    }
+
+    fn redirect_history(&self) -> Vec<nyquest_interface::RedirectHop> {
+        self.response.redirect_history().to_vec()
+    }
+
     fn text(&mut self) -> NyquestResult<String> {
         #[cfg(feature = "charset")]
         {
@@ -156,7 +239,13 @@ impl BlockingResponse for ReqwestBlockingResponse {
     }
 
     fn bytes(&mut self) -> NyquestResult<Vec<u8>> {
-        execute_with_runtime(&self.managed_runtime, || self.response.collect_all_bytes())
+        execute_with_runtime(&self.managed_runtime, || {
+            until_cancelled(
+                self.cancellation.as_ref(),
+                self.response.collect_all_bytes(),
+            )
+        })
+        .ok_or(NyquestError::Cancelled)?
     }
 }
 
@@ -169,8 +258,12 @@ impl Read for ReqwestBlockingResponse {
                 break Ok(written);
             }
             let received = execute_with_runtime(&self.managed_runtime, || {
-                self.response.receive_data_frame_buffered()
-            })?;
+                until_cancelled(
+                    self.cancellation.as_ref(),
+                    self.response.receive_data_frame_buffered(),
+                )
+            })
+            .ok_or_else(|| io::Error::other(NyquestError::Cancelled))??;
             if received == 0 {
                 break Ok(0);
             }
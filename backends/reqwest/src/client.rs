@@ -2,11 +2,16 @@
 use std::sync::{Arc, OnceLock};
 
 use http::{HeaderMap, HeaderName, HeaderValue};
-use nyquest_interface::{client::ClientOptions, Result as NyquestResult};
+use nyquest_interface::client::ClientOptions;
+use nyquest_interface::{RequestOptions, Result as NyquestResult};
 use reqwest::Client;
 use url::Url;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cookie::{CookieFile, ReqwestCookieJar};
 use crate::error::{ReqwestBackendError, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::proxy::Proxies;
 
 #[derive(Clone)]
 pub struct ReqwestClient {
@@ -17,10 +22,21 @@ pub struct ReqwestClient {
     pub(crate) wasm_options: crate::wasm::WasmOptions,
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) managed_runtime: Arc<OnceLock<tokio::runtime::Runtime>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cookie_jar: Option<Arc<ReqwestCookieJar>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cookie_file: Option<Arc<CookieFile>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) proxies: Arc<Proxies>,
 }
 
 impl ReqwestClient {
     pub fn new(options: ClientOptions) -> NyquestResult<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let cookie_jar: Option<Arc<ReqwestCookieJar>> = options.use_cookies.then(Default::default);
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = build_reqwest_client(&options, cookie_jar.clone())?;
+        #[cfg(target_arch = "wasm32")]
         let client = build_reqwest_client(&options)?;
 
         let base_url = if let Some(ref base_url_str) = options.base_url {
@@ -33,6 +49,11 @@ impl ReqwestClient {
         } else {
             None
         };
+        #[cfg(not(target_arch = "wasm32"))]
+        let cookie_file = match (&cookie_jar, &options.cookie_file) {
+            (Some(jar), Some(path)) => Some(Arc::new(CookieFile::load(jar.clone(), path.clone())?)),
+            _ => None,
+        };
 
         Ok(Self {
             client,
@@ -46,11 +67,41 @@ impl ReqwestClient {
             },
             #[cfg(not(target_arch = "wasm32"))]
             managed_runtime: Arc::new(OnceLock::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            cookie_jar,
+            #[cfg(not(target_arch = "wasm32"))]
+            cookie_file,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxies: Arc::new(Proxies::new(&options)),
         })
     }
+
+    /// Returns the cookie jar of the client, if cookies are enabled.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn cookie_jar(&self) -> Option<Arc<dyn nyquest_interface::CookieJar>> {
+        let cookie_jar = self.cookie_jar.clone()?;
+        Some(cookie_jar)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn flush_cookies(&self) -> NyquestResult<()> {
+        match &self.cookie_file {
+            Some(cookie_file) => cookie_file.save(),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn max_response_buffer_size(&self, options: &RequestOptions) -> Option<u64> {
+        options
+            .max_response_buffer_size
+            .or(self.max_response_buffer_size)
+    }
 }
 
-pub fn build_reqwest_client(options: &ClientOptions) -> Result<Client> {
+pub fn build_reqwest_client(
+    options: &ClientOptions,
+    #[cfg(not(target_arch = "wasm32"))] cookie_jar: Option<Arc<ReqwestCookieJar>>,
+) -> Result<Client> {
     let mut builder = Client::builder();
 
     if let Some(user_agent) = &options.user_agent {
@@ -71,7 +122,53 @@ pub fn build_reqwest_client(options: &ClientOptions) -> Result<Client> {
 
     #[cfg(not(target_arch = "wasm32"))]
     {
-        builder = build_non_wasm(builder, options).map_err(ReqwestBackendError::Reqwest)?;
+        if cfg!(not(unix)) && options.unix_socket.is_some() {
+            return Err(unsupported_unix_socket());
+        }
+        builder =
+            build_non_wasm(builder, options, cookie_jar).map_err(ReqwestBackendError::Reqwest)?;
+        builder = build_tls(builder, options)?;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        use nyquest_interface::client::HttpVersionPreference;
+
+        if options.unix_socket.is_some() {
+            return Err(unsupported_unix_socket());
+        }
+        if options.cookie_file.is_some() {
+            return Err(ReqwestBackendError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "cookies cannot be persisted to a file in the browser",
+            )));
+        }
+        if !options.dns.overrides.is_empty() || options.dns.resolver.is_some() {
+            return Err(ReqwestBackendError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "host name resolution cannot be overridden in the browser",
+            )));
+        }
+        if options.client_identity.is_some() {
+            return Err(unsupported_tls("client certificates", "the browser"));
+        }
+        if !options.root_certificates.is_empty() || options.only_custom_roots {
+            return Err(unsupported_tls("custom root certificates", "the browser"));
+        }
+        if !options.pinned_public_keys.is_empty() {
+            return Err(unsupported_tls("public key pins", "the browser"));
+        }
+        if options.min_tls_version.is_some() {
+            return Err(unsupported_tls("minimum TLS versions", "the browser"));
+        }
+        if matches!(
+            options.http_version,
+            HttpVersionPreference::Http1Only | HttpVersionPreference::Http2PriorKnowledge
+        ) {
+            return Err(ReqwestBackendError::Io(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "HTTP versions cannot be enforced by the browser",
+            )));
+        }
     }
 
     builder
@@ -84,8 +181,9 @@ pub fn build_reqwest_client(options: &ClientOptions) -> Result<Client> {
 fn build_non_wasm(
     mut builder: reqwest::ClientBuilder,
     options: &ClientOptions,
+    cookie_jar: Option<Arc<ReqwestCookieJar>>,
 ) -> reqwest::Result<reqwest::ClientBuilder> {
-    use nyquest_interface::client::ProxyOptions;
+    use nyquest_interface::client::{HttpVersionPreference, ProxyOptions};
 
     match &options.proxy_options {
         ProxyOptions::Default => {
@@ -110,13 +208,34 @@ fn build_non_wasm(
             }
         }
     }
-    builder = builder
-        .cookie_store(options.use_cookies)
-        .redirect(if options.follow_redirects {
-            reqwest::redirect::Policy::default()
-        } else {
-            reqwest::redirect::Policy::none()
-        });
+    for o in &options.dns.overrides {
+        let addrs: Vec<_> = o
+            .addrs
+            .iter()
+            .map(|ip| std::net::SocketAddr::new(*ip, o.port))
+            .collect();
+        builder = builder.resolve_to_addrs(&o.host, &addrs);
+    }
+    builder = match &options.dns.resolver {
+        Some(resolver) => {
+            builder.dns_resolver(Arc::new(crate::resolve::CustomResolver(resolver.clone())))
+        }
+        None => builder.dns_resolver(Arc::new(crate::resolve::SystemResolver)),
+    };
+    #[cfg(unix)]
+    if let Some(path) = &options.unix_socket {
+        builder = builder.unix_socket(path.clone());
+    }
+    if let Some(cookie_jar) = cookie_jar {
+        builder = builder.cookie_provider(cookie_jar);
+    }
+    builder = match options.http_version {
+        // HTTP/2 is only used when preferred explicitly, as it used to be unsupported
+        HttpVersionPreference::Default | HttpVersionPreference::Http1Only => builder.http1_only(),
+        HttpVersionPreference::PreferHttp2 => builder,
+        HttpVersionPreference::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+    };
+    builder = builder.redirect(crate::redirect::policy(options));
     #[cfg(any(
         feature = "default-tls",
         feature = "native-tls",
@@ -129,5 +248,138 @@ fn build_non_wasm(
     if let Some(timeout) = options.request_timeout {
         builder = builder.timeout(timeout);
     }
+    if let Some(timeout) = options.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = options.read_timeout {
+        builder = builder.read_timeout(timeout);
+    }
+    Ok(builder)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_tls(
+    mut builder: reqwest::ClientBuilder,
+    options: &ClientOptions,
+) -> Result<reqwest::ClientBuilder> {
+    if !options.pinned_public_keys.is_empty() {
+        // Pinning requires a custom certificate verifier, which is only possible with rustls
+        #[cfg(feature = "rustls-tls-minimal")]
+        return Ok(builder.use_preconfigured_tls(crate::pinning::build_tls_config(options)?));
+        #[cfg(not(feature = "rustls-tls-minimal"))]
+        return Err(unsupported_tls(
+            "public key pins",
+            "reqwest without rustls-tls-minimal",
+        ));
+    }
+    #[cfg(any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls-minimal",
+    ))]
+    {
+        for pem in &options.root_certificates {
+            let certs =
+                reqwest::Certificate::from_pem_bundle(pem).map_err(ReqwestBackendError::Reqwest)?;
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+        if options.only_custom_roots {
+            builder = builder.tls_built_in_root_certs(false);
+        }
+        if let Some(version) = options.min_tls_version {
+            use nyquest_interface::client::TlsVersion;
+
+            builder = builder.min_tls_version(match version {
+                TlsVersion::Tls1_0 => reqwest::tls::Version::TLS_1_0,
+                TlsVersion::Tls1_1 => reqwest::tls::Version::TLS_1_1,
+                TlsVersion::Tls1_2 => reqwest::tls::Version::TLS_1_2,
+                TlsVersion::Tls1_3 => reqwest::tls::Version::TLS_1_3,
+            });
+        }
+        if let Some(identity) = &options.client_identity {
+            builder = builder.identity(build_identity(identity)?);
+        }
+    }
+    #[cfg(not(any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls-minimal",
+    )))]
+    {
+        if options.client_identity.is_some() {
+            return Err(unsupported_tls(
+                "client certificates",
+                "reqwest without TLS",
+            ));
+        }
+        if !options.root_certificates.is_empty() || options.only_custom_roots {
+            return Err(unsupported_tls(
+                "custom root certificates",
+                "reqwest without TLS",
+            ));
+        }
+        if options.min_tls_version.is_some() {
+            return Err(unsupported_tls(
+                "minimum TLS versions",
+                "reqwest without TLS",
+            ));
+        }
+    }
     Ok(builder)
 }
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls-minimal",
+    )
+))]
+fn build_identity(
+    identity: &nyquest_interface::client::ClientIdentity,
+) -> Result<reqwest::Identity> {
+    use nyquest_interface::client::ClientIdentity;
+
+    match identity {
+        #[cfg(any(feature = "default-tls", feature = "native-tls"))]
+        ClientIdentity::Pem { cert, key } => {
+            reqwest::Identity::from_pkcs8_pem(cert, key).map_err(ReqwestBackendError::Reqwest)
+        }
+        #[cfg(not(any(feature = "default-tls", feature = "native-tls")))]
+        ClientIdentity::Pem { cert, key } => {
+            reqwest::Identity::from_pem(&[&cert[..], b"\n", key].concat())
+                .map_err(ReqwestBackendError::Reqwest)
+        }
+        #[cfg(any(feature = "default-tls", feature = "native-tls"))]
+        ClientIdentity::Pkcs12 { der, password } => {
+            reqwest::Identity::from_pkcs12_der(der, password).map_err(ReqwestBackendError::Reqwest)
+        }
+        #[cfg(not(any(feature = "default-tls", feature = "native-tls")))]
+        ClientIdentity::Pkcs12 { .. } => Err(unsupported_tls("PKCS#12 identities", "rustls")),
+    }
+}
+
+#[cfg_attr(
+    all(
+        not(target_arch = "wasm32"),
+        feature = "rustls-tls-minimal",
+        any(feature = "default-tls", feature = "native-tls")
+    ),
+    allow(dead_code)
+)]
+fn unsupported_tls(what: &str, implementation: &str) -> ReqwestBackendError {
+    ReqwestBackendError::Io(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("{what} are not supported by {implementation}"),
+    ))
+}
+
+fn unsupported_unix_socket() -> ReqwestBackendError {
+    ReqwestBackendError::Io(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Unix domain sockets are not supported on this platform",
+    ))
+}
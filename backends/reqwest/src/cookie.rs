@@ -0,0 +1,144 @@
+//! A cookie store exposing its cookies through [`CookieJar`], as the jar of reqwest does not
+//! allow listing or removing cookies, along with persisting them in a Netscape cookie file.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::SystemTime;
+
+use cookie::time::OffsetDateTime;
+use cookie_store::{CookieDomain, CookieStore as Store, RawCookie};
+use http::HeaderValue;
+use nyquest_interface::{write_netscape_cookie_file, Cookie, CookieJar, Result as NyquestResult};
+use url::Url;
+
+#[derive(Default)]
+pub(crate) struct ReqwestCookieJar(RwLock<Store>);
+
+impl ReqwestCookieJar {
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.0.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl reqwest::cookie::CookieStore for ReqwestCookieJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers.filter_map(|value| {
+            let value = std::str::from_utf8(value.as_bytes()).ok()?;
+            RawCookie::parse(value).ok().map(RawCookie::into_owned)
+        });
+        self.write().store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let value = self
+            .read()
+            .get_request_values(url)
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        if value.is_empty() {
+            return None;
+        }
+        HeaderValue::from_str(&value).ok()
+    }
+}
+
+impl CookieJar for ReqwestCookieJar {
+    fn cookies(&self) -> NyquestResult<Vec<Cookie>> {
+        Ok(self
+            .read()
+            .iter_unexpired()
+            .filter_map(|cookie| {
+                let (domain, include_subdomains) = match &cookie.domain {
+                    CookieDomain::HostOnly(domain) => (domain.clone(), false),
+                    CookieDomain::Suffix(domain) => (domain.clone(), true),
+                    CookieDomain::NotPresent | CookieDomain::Empty => return None,
+                };
+                Some(Cookie {
+                    name: cookie.name().into(),
+                    value: cookie.value().into(),
+                    domain,
+                    include_subdomains,
+                    path: cookie.path.as_ref().into(),
+                    secure: cookie.secure().unwrap_or_default(),
+                    http_only: cookie.http_only().unwrap_or_default(),
+                    expires: cookie.expires_datetime().map(SystemTime::from),
+                })
+            })
+            .collect())
+    }
+
+    fn add(&self, cookie: Cookie) -> NyquestResult<()> {
+        let url = Url::parse(&format!("https://{}{}", cookie.domain, cookie.path))
+            .map_err(|e| invalid_cookie(e.to_string()))?;
+        let mut raw = RawCookie::build((cookie.name, cookie.value))
+            .path(cookie.path)
+            .secure(cookie.secure)
+            .http_only(cookie.http_only);
+        if cookie.include_subdomains {
+            raw = raw.domain(cookie.domain);
+        }
+        if let Some(expires) = cookie.expires {
+            raw = raw.expires(OffsetDateTime::from(expires));
+        }
+        match self.write().insert_raw(&raw.build(), &url) {
+            Ok(_) | Err(cookie_store::CookieError::Expired) => Ok(()),
+            Err(e) => Err(invalid_cookie(e.to_string())),
+        }
+    }
+
+    fn remove(&self, name: &str, domain: &str, path: &str) -> NyquestResult<()> {
+        self.write().remove(domain, path, name);
+        Ok(())
+    }
+
+    fn clear(&self) -> NyquestResult<()> {
+        self.write().clear();
+        Ok(())
+    }
+}
+
+/// The cookie file of a client, loaded into the jar when the client is created and saved when the
+/// client is dropped.
+pub(crate) struct CookieFile {
+    jar: Arc<ReqwestCookieJar>,
+    path: PathBuf,
+}
+
+impl CookieFile {
+    /// Loads the cookies in `path`, if it exists. Lines that cannot be parsed are skipped.
+    pub(crate) fn load(jar: Arc<ReqwestCookieJar>, path: PathBuf) -> NyquestResult<Self> {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        for cookie in content.lines().filter_map(Cookie::from_netscape_line) {
+            jar.add(cookie).ok();
+        }
+        Ok(Self { jar, path })
+    }
+
+    /// Writes all cookies to the file, including session cookies.
+    pub(crate) fn save(&self) -> NyquestResult<()> {
+        let cookies = self.jar.cookies()?;
+        write_netscape_cookie_file(&self.path, cookies.iter().map(Cookie::to_netscape_line))?;
+        Ok(())
+    }
+}
+
+impl Drop for CookieFile {
+    fn drop(&mut self) {
+        self.save().ok();
+    }
+}
+
+fn invalid_cookie(msg: String) -> nyquest_interface::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg).into()
+}
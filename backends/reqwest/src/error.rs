@@ -1,15 +1,23 @@
 use std::io;
 
-use nyquest_interface::Error as NyquestError;
+use nyquest_interface::{Error as NyquestError, ErrorKind};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ReqwestBackendError {
     #[error("reqwest error: {0}")]
     Reqwest(#[from] reqwest::Error),
+    /// A connection failure of a request sent through a proxy.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("reqwest error: {0}")]
+    ProxyConnect(reqwest::Error),
     #[cfg(not(target_arch = "wasm32"))]
     #[error("tokio error: {0}")]
     Tokio(#[from] tokio::task::JoinError),
+    /// No response arrived within the read timeout of a request.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("read timed out")]
+    ReadTimeout,
     #[error("response too large")]
     ResponseTooLarge,
     #[error("invalid header name: {0}")]
@@ -33,10 +41,27 @@ impl From<ReqwestBackendError> for NyquestError {
             ReqwestBackendError::Reqwest(e) => {
                 if e.is_timeout() {
                     NyquestError::RequestTimeout
+                } else if e.is_redirect() {
+                    NyquestError::TooManyRedirects
+                } else if let Some(kind) = error_kind(&e) {
+                    NyquestError::backend(kind, e)
                 } else {
                     NyquestError::Io(io::Error::other(e))
                 }
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            ReqwestBackendError::ProxyConnect(e) => {
+                if e.is_timeout() {
+                    NyquestError::RequestTimeout
+                } else {
+                    // The proxy resolves and connects to the target, so only TLS failures of the
+                    // tunneled connection are not caused by the proxy
+                    let kind = tls_error_kind(&e).unwrap_or(ErrorKind::Proxy);
+                    NyquestError::backend(kind, e)
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            ReqwestBackendError::ReadTimeout => NyquestError::RequestTimeout,
             ReqwestBackendError::ResponseTooLarge => NyquestError::ResponseTooLarge,
             ReqwestBackendError::InvalidUrl(msg) => {
                 NyquestError::Io(io::Error::new(io::ErrorKind::InvalidInput, msg))
@@ -47,4 +72,61 @@ impl From<ReqwestBackendError> for NyquestError {
     }
 }
 
+fn error_kind(e: &reqwest::Error) -> Option<ErrorKind> {
+    if e.is_decode() {
+        return Some(ErrorKind::Decode);
+    }
+    if e.is_body() {
+        return Some(ErrorKind::BodyRead);
+    }
+    if !e.is_connect() {
+        return None;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(kind) = tls_error_kind(e) {
+            return Some(kind);
+        }
+        if sources(e).any(|err| err.is::<crate::resolve::DnsError>()) {
+            return Some(ErrorKind::Dns);
+        }
+    }
+    Some(ErrorKind::Connect)
+}
+
+/// Identifies the TLS failures among the causes of a connection failure by their type.
+#[cfg(not(target_arch = "wasm32"))]
+fn tls_error_kind(e: &reqwest::Error) -> Option<ErrorKind> {
+    sources(e).find_map(|err| {
+        #[cfg(feature = "rustls-tls-minimal")]
+        if let Some(err) = err.downcast_ref::<rustls::Error>() {
+            return Some(match err {
+                rustls::Error::InvalidCertificate(_) => ErrorKind::Certificate,
+                _ => ErrorKind::Tls,
+            });
+        }
+        // native-tls does not tell certificate failures apart from other TLS failures
+        #[cfg(any(feature = "default-tls", feature = "native-tls"))]
+        if err.is::<native_tls::Error>() {
+            return Some(ErrorKind::Tls);
+        }
+        let _ = err;
+        None
+    })
+}
+
+/// Iterates over the causes of an error, including the errors wrapped by [`io::Error`], which
+/// are not returned as their sources.
+#[cfg(not(target_arch = "wasm32"))]
+fn sources<'a>(
+    e: &'a (dyn std::error::Error + 'static),
+) -> impl Iterator<Item = &'a (dyn std::error::Error + 'static)> {
+    std::iter::successors(e.source(), |&err| {
+        match err.downcast_ref::<io::Error>().and_then(io::Error::get_ref) {
+            Some(inner) => Some(inner as &(dyn std::error::Error + 'static)),
+            None => err.source(),
+        }
+    })
+}
+
 pub type Result<T> = std::result::Result<T, ReqwestBackendError>;
@@ -27,6 +27,13 @@ cfg_if::cfg_if! {
         #[cfg(feature = "blocking")]
         #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
         mod blocking;
+        mod cookie;
+        #[cfg(feature = "rustls-tls-minimal")]
+        mod pinning;
+        mod progress;
+        mod proxy;
+        mod redirect;
+        mod resolve;
         mod response;
     }
 }
@@ -0,0 +1,171 @@
+//! Public key pinning with a custom rustls certificate verifier, as reqwest does not support
+//! pinning natively.
+
+use std::io;
+use std::sync::Arc;
+
+use base64::Engine as _;
+use nyquest_interface::client::{ClientIdentity, ClientOptions, TlsVersion};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{
+    CertificateError, ClientConfig, DigitallySignedStruct, Error as TlsError, RootCertStore,
+    SignatureScheme,
+};
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+
+/// Builds a rustls configuration that checks the pinned public keys on top of the usual
+/// verification of the server certificate.
+///
+/// The root certificates of the platform are trusted along with
+/// [`ClientOptions::root_certificates`], unless [`ClientOptions::only_custom_roots`] is set.
+pub(crate) fn build_tls_config(options: &ClientOptions) -> Result<ClientConfig> {
+    let provider = CryptoProvider::get_default()
+        .cloned()
+        .ok_or_else(|| config_error("no default rustls crypto provider is installed"))?;
+    let pins = options
+        .pinned_public_keys
+        .iter()
+        .map(|pin| decode_pin(pin))
+        .collect::<Result<_>>()?;
+    let inner = if options.ignore_certificate_errors {
+        None
+    } else {
+        let mut roots = RootCertStore::empty();
+        if !options.only_custom_roots {
+            // Like reqwest, skip platform certificates that cannot be loaded or parsed
+            let native = rustls_native_certs::load_native_certs();
+            roots.add_parsable_certificates(native.certs);
+        }
+        for pem in &options.root_certificates {
+            for cert in CertificateDer::pem_slice_iter(pem) {
+                let cert = cert.map_err(config_error)?;
+                roots.add(cert).map_err(config_error)?;
+            }
+        }
+        let verifier =
+            WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+                .build()
+                .map_err(config_error)?;
+        Some(verifier)
+    };
+    let verifier = PinningVerifier {
+        inner,
+        pins,
+        provider: provider.clone(),
+    };
+
+    // rustls only implements TLS 1.2 and 1.3
+    let versions: &[&rustls::SupportedProtocolVersion] = match options.min_tls_version {
+        Some(TlsVersion::Tls1_3) => &[&rustls::version::TLS13],
+        _ => rustls::DEFAULT_VERSIONS,
+    };
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_protocol_versions(versions)
+        .map_err(config_error)?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier));
+    let config = match &options.client_identity {
+        None => builder.with_no_client_auth(),
+        Some(ClientIdentity::Pem { cert, key }) => {
+            let certs = CertificateDer::pem_slice_iter(cert)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(config_error)?;
+            let key = PrivateKeyDer::from_pem_slice(key).map_err(config_error)?;
+            builder
+                .with_client_auth_cert(certs, key)
+                .map_err(config_error)?
+        }
+        Some(ClientIdentity::Pkcs12 { .. }) => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "PKCS#12 identities are not supported by rustls",
+            )
+            .into())
+        }
+    };
+    Ok(config)
+}
+
+fn decode_pin(pin: &str) -> Result<[u8; 32]> {
+    base64::engine::general_purpose::STANDARD
+        .decode(pin)
+        .ok()
+        .and_then(|hash| hash.try_into().ok())
+        .ok_or_else(|| config_error(format!("invalid SHA-256 public key pin: {pin}")))
+}
+
+fn config_error(e: impl ToString) -> crate::error::ReqwestBackendError {
+    io::Error::new(io::ErrorKind::InvalidInput, e.to_string()).into()
+}
+
+#[derive(Debug)]
+struct PinningVerifier {
+    /// The verifier for the certificate chain, or `None` when certificate errors are ignored.
+    inner: Option<Arc<WebPkiServerVerifier>>,
+    pins: Vec<[u8; 32]>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinningVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, TlsError> {
+        if let Some(inner) = &self.inner {
+            inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+        }
+        let cert = webpki::EndEntityCert::try_from(end_entity)
+            .map_err(|_| TlsError::InvalidCertificate(CertificateError::BadEncoding))?;
+        let hash: [u8; 32] = Sha256::digest(cert.subject_public_key_info().as_ref()).into();
+        if !self.pins.contains(&hash) {
+            return Err(TlsError::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ));
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, TlsError> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, TlsError> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
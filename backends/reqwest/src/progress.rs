@@ -0,0 +1,99 @@
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use nyquest_interface::{Progress, ProgressCallback};
+use reqwest::RequestBuilder;
+
+use crate::error::Result;
+
+/// Tracks the transfer progress of a request by counting the body chunks sent and received.
+pub(crate) struct ProgressTracker {
+    callback: ProgressCallback,
+    progress: Mutex<Progress>,
+}
+
+impl fmt::Debug for ProgressTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressTracker")
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(callback: ProgressCallback) -> Arc<Self> {
+        Arc::new(Self {
+            callback,
+            progress: Default::default(),
+        })
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Progress)) {
+        let progress = {
+            let mut progress = self.progress.lock().unwrap();
+            f(&mut progress);
+            *progress
+        };
+        (self.callback)(progress);
+    }
+
+    /// Wraps the request body so that the chunks polled by the connection are counted.
+    pub(crate) fn track_upload(
+        self: &Arc<Self>,
+        builder: RequestBuilder,
+    ) -> Result<RequestBuilder> {
+        let (client, request) = builder.build_split();
+        let mut request = request?;
+        if let Some(body) = request.body_mut().take() {
+            let total = body.size_hint().exact();
+            self.update(|p| p.upload_total = total);
+            *request.body_mut() = Some(reqwest::Body::wrap(ProgressBody {
+                inner: body,
+                tracker: self.clone(),
+            }));
+        }
+        Ok(RequestBuilder::from_parts(client, request))
+    }
+
+    pub(crate) fn start_download(&self, total: Option<u64>) {
+        self.update(|p| p.download_total = total);
+    }
+
+    pub(crate) fn add_downloaded(&self, len: usize) {
+        self.update(|p| p.downloaded += len as u64);
+    }
+}
+
+struct ProgressBody {
+    inner: reqwest::Body,
+    tracker: Arc<ProgressTracker>,
+}
+
+impl Body for ProgressBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<Frame<Bytes>, reqwest::Error>>> {
+        let frame = ready!(Pin::new(&mut self.inner).poll_frame(cx));
+        if let Some(data) = frame.as_ref().and_then(|f| f.as_ref().ok()?.data_ref()) {
+            let len = data.len() as u64;
+            self.tracker.update(|p| p.uploaded += len);
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,54 @@
+//! Tells which requests go through a proxy, with the same rules as reqwest.
+
+use hyper_util::client::proxy::matcher::Matcher;
+use nyquest_interface::client::{ClientOptions, ProxyOptions};
+use url::Url;
+
+use crate::error::ReqwestBackendError;
+
+/// The proxies of a client, as configured on the reqwest client in [`crate::client`].
+pub(crate) struct Proxies(Vec<Matcher>);
+
+impl Proxies {
+    pub(crate) fn new(options: &ClientOptions) -> Self {
+        let matchers = match &options.proxy_options {
+            // reqwest reads the proxies of the system unless configured otherwise
+            ProxyOptions::Default => vec![Matcher::from_system()],
+            ProxyOptions::None => vec![],
+            ProxyOptions::Custom {
+                http,
+                https,
+                proxy_bypass,
+            } => {
+                let no_proxy = proxy_bypass.as_deref().unwrap_or_default();
+                let http = http
+                    .as_deref()
+                    .filter(|url| reqwest::Proxy::http(*url).is_ok())
+                    .map(|url| Matcher::builder().http(url).no(no_proxy).build());
+                let https = https
+                    .as_deref()
+                    .filter(|url| reqwest::Proxy::https(*url).is_ok())
+                    .map(|url| Matcher::builder().https(url).no(no_proxy).build());
+                http.into_iter().chain(https).collect()
+            }
+        };
+        Self(matchers)
+    }
+
+    /// Wraps an error sending a request, noting whether it failed to connect through a proxy.
+    pub(crate) fn send_error(&self, e: reqwest::Error) -> ReqwestBackendError {
+        if e.is_connect() && e.url().is_some_and(|url| self.intercepts(url)) {
+            ReqwestBackendError::ProxyConnect(e)
+        } else {
+            ReqwestBackendError::Reqwest(e)
+        }
+    }
+
+    /// Returns whether requests to `url` are sent through a proxy.
+    fn intercepts(&self, url: &Url) -> bool {
+        let Ok(uri) = url.as_str().parse::<http::Uri>() else {
+            return false;
+        };
+        self.0.iter().any(|m| m.intercept(&uri).is_some())
+    }
+}
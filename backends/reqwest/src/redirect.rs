@@ -0,0 +1,95 @@
+//! Redirect policy of the reqwest client, honoring per-request overrides of whether redirects are
+//! followed.
+//!
+//! reqwest only supports a redirect policy per client, and does not pass the request to the
+//! policy. Instead, the override of a request is kept in a thread local while the future sending
+//! the request is polled, as reqwest checks redirects synchronously from within that poll. The
+//! redirects followed are collected the same way, as reqwest does not report them.
+
+use std::cell::{Cell, RefCell};
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+
+use nyquest_interface::client::ClientOptions;
+use nyquest_interface::RedirectHop;
+use reqwest::redirect::Policy;
+
+thread_local! {
+    static FOLLOW_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+    static HISTORY: RefCell<Option<Vec<RedirectHop>>> = const { RefCell::new(None) };
+}
+
+/// Builds the redirect policy of a client, following redirects as configured in `options` unless
+/// a request overrides it.
+pub(crate) fn policy(options: &ClientOptions) -> Policy {
+    let follow = options.follow_redirects;
+    // Same as the default policy of reqwest
+    let max_redirects = options
+        .redirect_policy
+        .max_redirects
+        .map_or(10, |max| max as usize);
+    Policy::custom(move |attempt| {
+        if !FOLLOW_OVERRIDE.with(Cell::get).unwrap_or(follow) {
+            attempt.stop()
+        } else if attempt.previous().len() > max_redirects {
+            // The first URL in the previous URLs is the initial one, not a redirect
+            attempt.error("too many redirects")
+        } else {
+            HISTORY.with_borrow_mut(|history| {
+                if let Some(history) = history {
+                    history.push(RedirectHop {
+                        status: attempt.status().as_u16(),
+                        // The Location header is not available, only the URL it resolves to
+                        location: Some(attempt.url().to_string()),
+                    });
+                }
+            });
+            attempt.follow()
+        }
+    })
+}
+
+/// Runs `fut` sending a request, with redirects followed according to `follow` if set, and
+/// returns the redirects followed along with its output.
+pub(crate) async fn with_override<F: Future>(
+    follow: Option<bool>,
+    fut: F,
+) -> (F::Output, Vec<RedirectHop>) {
+    let mut fut = pin!(fut);
+    let mut history = vec![];
+    let output = poll_fn(|cx| {
+        let _scope = Scope::enter(follow, &mut history);
+        fut.as_mut().poll(cx)
+    })
+    .await;
+    (output, history)
+}
+
+/// Installs the override and history of a request while polling it, and restores the previous
+/// ones when dropped, even if polling panics.
+struct Scope<'a> {
+    history: &'a mut Vec<RedirectHop>,
+    prev_follow: Option<bool>,
+    prev_history: Option<Vec<RedirectHop>>,
+}
+
+impl<'a> Scope<'a> {
+    fn enter(follow: Option<bool>, history: &'a mut Vec<RedirectHop>) -> Self {
+        let prev_follow = FOLLOW_OVERRIDE.replace(follow);
+        let prev_history = HISTORY.replace(Some(std::mem::take(history)));
+        Self {
+            history,
+            prev_follow,
+            prev_history,
+        }
+    }
+}
+
+impl Drop for Scope<'_> {
+    fn drop(&mut self) {
+        FOLLOW_OVERRIDE.set(self.prev_follow);
+        *self.history = HISTORY
+            .replace(self.prev_history.take())
+            .unwrap_or_default();
+    }
+}
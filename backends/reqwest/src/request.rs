@@ -152,18 +152,37 @@ impl ReqwestClient {
         req: nyquest_interface::Request<S>,
         transform_stream: impl FnMut(S) -> (reqwest::Body, Option<u64>),
     ) -> nyquest_interface::Result<reqwest::RequestBuilder> {
-        #[allow(unused_mut)]
+        // Connect timeouts can only be set on the reqwest client, and read timeouts are applied
+        // while receiving the response instead
+        #[cfg(not(target_arch = "wasm32"))]
+        if req.options.connect_timeout.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "reqwest does not support connect timeouts per request",
+            )
+            .into());
+        }
+        let timeout = req.options.request_timeout;
+        #[cfg(target_arch = "wasm32")]
+        let caching_behavior = req.options.caching_behavior;
         let mut builder =
             build_request_generic(&self.client, self.base_url.as_ref(), req, transform_stream)?;
         #[cfg(target_arch = "wasm32")]
         {
-            builder = self.build_request_wasm(builder);
+            builder = self.build_request_wasm(builder, caching_behavior);
+        }
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
         }
         Ok(builder)
     }
 
     #[cfg(target_arch = "wasm32")]
-    fn build_request_wasm(&self, mut builder: RequestBuilder) -> RequestBuilder {
+    fn build_request_wasm(
+        &self,
+        mut builder: RequestBuilder,
+        caching_behavior: Option<nyquest_interface::client::CachingBehavior>,
+    ) -> RequestBuilder {
         use nyquest_interface::client::CachingBehavior;
 
         if let Some(timeout) = self.wasm_options.request_timeout {
@@ -174,7 +193,7 @@ impl ReqwestClient {
         } else {
             builder.fetch_credentials_omit()
         };
-        match self.wasm_options.caching_behavior {
+        match caching_behavior.unwrap_or(self.wasm_options.caching_behavior) {
             CachingBehavior::Disabled => builder.fetch_cache_no_store(),
             CachingBehavior::BestEffort => builder.fetch_cache_default(),
         }
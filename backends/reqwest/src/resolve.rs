@@ -0,0 +1,68 @@
+//! Resolves host names for reqwest, with the host name resolver callback of nyquest or the system
+//! resolver.
+//!
+//! Resolution failures are wrapped in [`DnsError`], so that they can be told apart from other
+//! connection failures by their type.
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use nyquest_interface::client::DnsResolver;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+pub(crate) struct CustomResolver(pub(crate) DnsResolver);
+
+impl Resolve for CustomResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.0.clone();
+        Box::pin(async move {
+            let host = name.as_str().to_owned();
+            // The callback is blocking, like getaddrinfo
+            let addrs = tokio::task::spawn_blocking(move || resolver(&host))
+                .await?
+                .map_err(DnsError::boxed)?;
+            // Port 0 is replaced by the port of the URL
+            let addrs: Addrs = Box::new(addrs.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+            Ok(addrs)
+        })
+    }
+}
+
+/// Resolves host names with getaddrinfo, like the default resolver of reqwest.
+pub(crate) struct SystemResolver;
+
+impl Resolve for SystemResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_owned();
+            let addrs = tokio::task::spawn_blocking(move || (host, 0).to_socket_addrs())
+                .await?
+                .map_err(DnsError::boxed)?;
+            let addrs: Addrs = Box::new(addrs);
+            Ok(addrs)
+        })
+    }
+}
+
+/// A host name that could not be resolved.
+#[derive(Debug)]
+pub(crate) struct DnsError(std::io::Error);
+
+impl DnsError {
+    fn boxed(e: std::io::Error) -> Box<dyn StdError + Send + Sync> {
+        Box::new(Self(e))
+    }
+}
+
+impl fmt::Display for DnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to resolve host name")
+    }
+}
+
+impl StdError for DnsError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.0)
+    }
+}
@@ -1,40 +1,93 @@
 use std::{
-    future::poll_fn,
+    future::{poll_fn, Future},
     io,
     pin::Pin,
+    sync::Arc,
     task::{ready, Context, Poll},
+    time::Duration,
 };
 
 use bytes::Bytes;
 use http::response::Parts;
 use http_body::Body as _;
-use nyquest_interface::Result as NyquestResult;
+use nyquest_interface::{Error as NyquestError, HttpVersion, RedirectHop, Result as NyquestResult};
 
 use crate::error::ReqwestBackendError;
+use crate::progress::ProgressTracker;
 
 #[derive(Debug)]
 pub(crate) struct ReqwestResponse {
     parts: Parts,
+    url: String,
     body: Pin<Box<reqwest::Body>>,
     #[cfg(any(feature = "async-stream", feature = "blocking-stream"))]
     buffer: Bytes,
     max_response_buffer_size: Option<u64>,
+    progress: Option<Arc<ProgressTracker>>,
+    read_timeout: Option<ReadTimeout>,
+    redirect_history: Vec<RedirectHop>,
+}
+
+/// The read timeout of a request, as the one of reqwest can only be set on the client.
+#[derive(Debug)]
+struct ReadTimeout {
+    duration: Duration,
+    /// Restarted whenever a frame is received, created on the first poll within the runtime.
+    sleep: Option<Pin<Box<tokio::time::Sleep>>>,
+}
+
+/// Waits for the response to a request, failing if it does not arrive within `read_timeout`.
+pub(crate) async fn with_read_timeout<T>(
+    read_timeout: Option<Duration>,
+    fut: impl Future<Output = Result<T, ReqwestBackendError>>,
+) -> Result<T, ReqwestBackendError> {
+    match read_timeout {
+        Some(duration) => tokio::time::timeout(duration, fut)
+            .await
+            .unwrap_or(Err(ReqwestBackendError::ReadTimeout)),
+        None => fut.await,
+    }
 }
 
 impl ReqwestResponse {
-    pub fn new(response: reqwest::Response, max_response_buffer_size: Option<u64>) -> Self {
+    pub fn new(
+        response: reqwest::Response,
+        max_response_buffer_size: Option<u64>,
+        read_timeout: Option<Duration>,
+    ) -> Self {
+        let url = response.url().to_string();
         let http_response: http::Response<reqwest::Body> = response.into();
         let (parts, body) = http_response.into_parts();
 
         Self {
             parts,
+            url,
             body: Box::pin(body),
             #[cfg(any(feature = "async-stream", feature = "blocking-stream"))]
             buffer: Bytes::new(),
             max_response_buffer_size,
+            progress: None,
+            read_timeout: read_timeout.map(|duration| ReadTimeout {
+                duration,
+                sleep: None,
+            }),
+            redirect_history: vec![],
         }
     }
 
+    pub fn track_progress(&mut self, tracker: Arc<ProgressTracker>) {
+        tracker.start_download(self.content_length());
+        self.progress = Some(tracker);
+    }
+
+    pub fn set_redirect_history(&mut self, history: Vec<RedirectHop>) {
+        self.redirect_history = history;
+    }
+
+    pub fn redirect_history(&self) -> &[RedirectHop] {
+        &self.redirect_history
+    }
+
     pub fn status(&self) -> u16 {
         self.parts.status.as_u16()
     }
@@ -53,6 +106,31 @@ impl ReqwestResponse {
             .collect::<Vec<_>>())
     }
 
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn version(&self) -> Option<HttpVersion> {
+        Some(match self.parts.version {
+            http::Version::HTTP_09 => HttpVersion::Http09,
+            http::Version::HTTP_10 => HttpVersion::Http10,
+            http::Version::HTTP_11 => HttpVersion::Http11,
+            http::Version::HTTP_2 => HttpVersion::Http2,
+            http::Version::HTTP_3 => HttpVersion::Http3,
+            _ => return None,
+        })
+    }
+
+    pub fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        // `http::HeaderMap` groups values by name, so interleaved headers are not kept in order.
+        Ok(self
+            .parts
+            .headers
+            .iter()
+            .map(|(k, v)| (k.as_str().into(), v.to_str().unwrap_or_default().into()))
+            .collect())
+    }
+
     #[cfg(feature = "charset")]
     pub fn get_best_encoding(&self) -> &'static encoding_rs::Encoding {
         use encoding_rs::{Encoding, UTF_8};
@@ -77,7 +155,14 @@ impl ReqwestResponse {
         let mut bufs = vec![];
         let mut collected_size = 0;
         loop {
-            let Some(frame) = self.receive_data_frame().await? else {
+            let frame = self
+                .receive_data_frame()
+                .await
+                .map_err(|e| match e.kind() {
+                    io::ErrorKind::TimedOut => NyquestError::RequestTimeout,
+                    _ => e.into(),
+                })?;
+            let Some(frame) = frame else {
                 break;
             };
             if self
@@ -104,11 +189,33 @@ impl ReqwestResponse {
     }
 
     fn poll_receive_data_frame(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<Bytes>>> {
-        let frame = ready!(self.body.as_mut().poll_frame(cx));
+        let Poll::Ready(frame) = self.body.as_mut().poll_frame(cx) else {
+            if let Some(timeout) = &mut self.read_timeout {
+                let sleep = timeout
+                    .sleep
+                    .get_or_insert_with(|| Box::pin(tokio::time::sleep(timeout.duration)));
+                ready!(sleep.as_mut().poll(cx));
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "read timed out",
+                )));
+            }
+            return Poll::Pending;
+        };
+        if let Some(timeout) = &mut self.read_timeout {
+            timeout.sleep = None;
+        }
         Poll::Ready(match frame {
             None => Ok(None),
+            Some(Err(e)) if e.is_timeout() => Err(io::Error::new(io::ErrorKind::TimedOut, e)),
             Some(Err(e)) => Err(io::Error::other(e)),
-            Some(Ok(f)) => Ok(f.into_data().ok().filter(|d| !d.is_empty())),
+            Some(Ok(f)) => {
+                let data = f.into_data().ok().filter(|d| !d.is_empty());
+                if let (Some(data), Some(progress)) = (&data, &self.progress) {
+                    progress.add_downloaded(data.len());
+                }
+                Ok(data)
+            }
         })
     }
 
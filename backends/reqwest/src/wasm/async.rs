@@ -58,6 +58,24 @@ impl AsyncResponse for ReqwestAsyncResponse {
             .collect())
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        Ok(self
+            .response_ref()
+            .headers()
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.as_str().into(),
+                    v.to_str().ok().unwrap_or_default().into(),
+                )
+            })
+            .collect())
+    }
+
+    fn url(&self) -> Option<String> {
+        Some(self.response_ref().url().to_string())
+    }
+
     async fn text(self: Pin<&mut Self>) -> nyquest_interface::Result<String> {
         let content_type = self
             .get_header("content-type")?
@@ -110,6 +128,7 @@ impl AsyncClient for ReqwestAsyncClient {
         &self,
         req: nyquest_interface::r#async::Request,
     ) -> nyquest_interface::Result<Self::Response> {
+        let max_response_buffer_size = self.inner.max_response_buffer_size(&req.options);
         let request_builder = self.inner.request(req, |_body| unimplemented!())?;
 
         // Execute the request using shared runtime handling
@@ -119,7 +138,7 @@ impl AsyncClient for ReqwestAsyncClient {
 
         Ok(ReqwestAsyncResponse {
             response: Some(SendWrapper::new(response)),
-            max_response_buffer_size: self.inner.max_response_buffer_size,
+            max_response_buffer_size,
         })
     }
 }
@@ -29,6 +29,10 @@ impl BlockingResponse for DummyBlockingResponse {
         bail_unimplemented()
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        bail_unimplemented()
+    }
+
     fn text(&mut self) -> nyquest_interface::Result<String> {
         bail_unimplemented()
     }
@@ -27,6 +27,8 @@ fn test_blocking_outside_runtime() {
         relative_uri: Cow::Borrowed(TEST_URL),
         additional_headers: vec![],
         body: None,
+        cancellation: None,
+        options: Default::default(),
     };
 
     let response = client.request(request).unwrap();
@@ -52,6 +54,8 @@ async fn test_blocking_inside_tokio_runtime() {
         relative_uri: Cow::Borrowed(TEST_URL),
         additional_headers: vec![],
         body: None,
+        cancellation: None,
+        options: Default::default(),
     };
 
     // Run blocking request inside tokio runtime using spawn_blocking
@@ -78,6 +82,8 @@ async fn test_async_inside_tokio_runtime() {
         relative_uri: Cow::Borrowed(TEST_URL),
         additional_headers: vec![],
         body: None,
+        cancellation: None,
+        options: Default::default(),
     };
 
     let response = client.request(request).await.unwrap();
@@ -117,6 +123,8 @@ fn test_async_inside_futures_executor() {
                 relative_uri: Cow::Borrowed(TEST_URL),
                 additional_headers: vec![],
                 body: None,
+                cancellation: None,
+                options: Default::default(),
             };
 
             let response = client.request(request).await.unwrap();
@@ -163,6 +171,8 @@ fn test_async_inside_async_std_runtime() {
                 relative_uri: Cow::Borrowed(TEST_URL),
                 additional_headers: vec![],
                 body: None,
+                cancellation: None,
+                options: Default::default(),
             };
 
             let response = client.request(request).await.unwrap();
@@ -203,6 +213,8 @@ async fn test_concurrent_async_requests() {
                     Cow::Owned(format!("{}", i)),
                 )],
                 body: None,
+                cancellation: None,
+                options: Default::default(),
             };
 
             let response = client_clone.request(request).await.unwrap();
@@ -243,6 +255,8 @@ fn test_concurrent_blocking_requests() {
                     Cow::Owned(format!("{}", i)),
                 )],
                 body: None,
+                cancellation: None,
+                options: Default::default(),
             };
 
             let response = client_clone.request(request).unwrap();
@@ -278,6 +292,8 @@ async fn test_mixed_runtime_usage() {
         relative_uri: Cow::Borrowed(TEST_URL),
         additional_headers: vec![(Cow::Borrowed("X-Client-Type"), Cow::Borrowed("async"))],
         body: None,
+        cancellation: None,
+        options: Default::default(),
     };
 
     let async_response = async_client.request(async_request).await.unwrap();
@@ -290,6 +306,8 @@ async fn test_mixed_runtime_usage() {
             relative_uri: Cow::Borrowed(TEST_URL),
             additional_headers: vec![(Cow::Borrowed("X-Client-Type"), Cow::Borrowed("blocking"))],
             body: None,
+            cancellation: None,
+            options: Default::default(),
         };
 
         blocking_client.request(blocking_request)
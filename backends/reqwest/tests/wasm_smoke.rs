@@ -26,6 +26,8 @@ async fn get_1111_query() {
         relative_uri: "dns-query?name=github.com&type=A".into(),
         additional_headers: vec![],
         body: None,
+        cancellation: None,
+        options: Default::default(),
     };
 
     let mut response = client.request(request).await.unwrap();
@@ -52,6 +54,8 @@ async fn post_1111_query() {
             content: std::borrow::Cow::Borrowed(body),
             content_type: "application/dns-message".into(),
         }),
+        cancellation: None,
+        options: Default::default(),
     };
     let mut response = client.request(request).await.unwrap();
     assert_eq!(response.status(), 200);
@@ -35,6 +35,7 @@ pub struct WinHttpAsyncClient {
 
 impl WinHttpAsyncClient {
     pub(crate) async fn new(options: ClientOptions) -> NyquestResult<Self> {
+        WinHttpSession::check_supported(&options)?;
         // Create async session
         let session = WinHttpSession::new(options, true).into_nyquest()?;
 
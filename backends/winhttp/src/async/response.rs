@@ -145,6 +145,15 @@ impl AsyncResponse for WinHttpAsyncResponse {
         Ok(headers)
     }
 
+    fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        let headers = self.request.query_all_headers()?;
+        Ok(headers)
+    }
+
+    fn url(&self) -> Option<String> {
+        self.request.query_url()
+    }
+
     async fn text(mut self: Pin<&mut Self>) -> NyquestResult<String> {
         let bytes = self.as_mut().bytes().await?;
 
@@ -28,6 +28,7 @@ pub struct WinHttpBlockingClient {
 
 impl WinHttpBlockingClient {
     pub(crate) fn new(options: ClientOptions) -> NyquestResult<Self> {
+        WinHttpSession::check_supported(&options)?;
         let session = WinHttpSession::new(options, false).into_nyquest()?;
         Ok(Self { session })
     }
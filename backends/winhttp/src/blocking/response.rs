@@ -76,6 +76,15 @@ impl BlockingResponse for WinHttpBlockingResponse {
         Ok(headers)
     }
 
+    fn headers(&self) -> NyquestResult<Vec<(String, String)>> {
+        let headers = self.request.query_all_headers()?;
+        Ok(headers)
+    }
+
+    fn url(&self) -> Option<String> {
+        self.request.query_url()
+    }
+
     fn text(&mut self) -> NyquestResult<String> {
         let bytes = self.bytes()?;
 
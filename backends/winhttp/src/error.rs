@@ -51,16 +51,28 @@ impl From<WinHttpError> for io::Error {
 
 impl From<WinHttpError> for nyquest_interface::Error {
     fn from(err: WinHttpError) -> Self {
+        use nyquest_interface::ErrorKind;
         use windows_sys::Win32::Networking::WinHttp::*;
 
+        fn backend_error(kind: ErrorKind, err: WinHttpError) -> nyquest_interface::Error {
+            nyquest_interface::Error::backend(kind, io::Error::from(err))
+        }
+
         match err.code {
             ERROR_WINHTTP_TIMEOUT => nyquest_interface::Error::RequestTimeout,
-            ERROR_WINHTTP_CANNOT_CONNECT
-            | ERROR_WINHTTP_CONNECTION_ERROR
-            | ERROR_WINHTTP_NAME_NOT_RESOLVED => nyquest_interface::Error::Io(io::Error::from(err)),
-            ERROR_WINHTTP_SECURE_FAILURE => {
-                nyquest_interface::Error::Io(io::Error::new(io::ErrorKind::InvalidData, err))
-            }
+            ERROR_WINHTTP_REDIRECT_FAILED => nyquest_interface::Error::TooManyRedirects,
+            ERROR_WINHTTP_NAME_NOT_RESOLVED => backend_error(ErrorKind::Dns, err),
+            ERROR_WINHTTP_CANNOT_CONNECT => backend_error(ErrorKind::Connect, err),
+            ERROR_WINHTTP_CONNECTION_ERROR => backend_error(ErrorKind::Connect, err),
+            ERROR_WINHTTP_SECURE_CHANNEL_ERROR => backend_error(ErrorKind::Tls, err),
+            ERROR_WINHTTP_SECURE_FAILURE
+            | ERROR_WINHTTP_SECURE_CERT_DATE_INVALID
+            | ERROR_WINHTTP_SECURE_CERT_CN_INVALID
+            | ERROR_WINHTTP_SECURE_INVALID_CA
+            | ERROR_WINHTTP_SECURE_CERT_REV_FAILED
+            | ERROR_WINHTTP_SECURE_CERT_REVOKED
+            | ERROR_WINHTTP_SECURE_INVALID_CERT
+            | ERROR_WINHTTP_SECURE_CERT_WRONG_USAGE => backend_error(ErrorKind::Certificate, err),
             _ => nyquest_interface::Error::Io(io::Error::from(err)),
         }
     }
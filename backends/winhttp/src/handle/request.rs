@@ -281,6 +281,55 @@ impl RequestHandle {
         }
     }
 
+    /// Queries all response headers in the order they were received.
+    pub(crate) fn query_all_headers(&self) -> Result<Vec<(String, String)>> {
+        // First, query the required buffer size
+        let mut size: u32 = 0;
+        let result = unsafe {
+            WinHttpQueryHeaders(
+                self.as_raw(),
+                WINHTTP_QUERY_RAW_HEADERS_CRLF,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+                &mut size,
+                std::ptr::null_mut(),
+            )
+        };
+        if result == 0 {
+            let error = unsafe { windows_sys::Win32::Foundation::GetLastError() };
+            if error != windows_sys::Win32::Foundation::ERROR_INSUFFICIENT_BUFFER {
+                return Err(WinHttpError::from_code(
+                    error,
+                    "WinHttpQueryHeaders (raw size query)",
+                ));
+            }
+        }
+
+        let mut buffer: Vec<u16> = vec![0; (size / 2) as usize + 1];
+        let result = unsafe {
+            WinHttpQueryHeaders(
+                self.as_raw(),
+                WINHTTP_QUERY_RAW_HEADERS_CRLF,
+                std::ptr::null(),
+                buffer.as_mut_ptr() as *mut std::ffi::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+            )
+        };
+        if result == 0 {
+            return Err(WinHttpError::from_last_error("WinHttpQueryHeaders (raw)"));
+        }
+
+        let raw = String::from_utf16_lossy(&buffer[..(size / 2) as usize]);
+        Ok(raw
+            .split("\r\n")
+            // The first line is the status line
+            .skip(1)
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+            .collect())
+    }
+
     /// Queries available data to read.
     /// # Safety
     /// The caller must ensure that the handle is in synchronous mode.
@@ -396,6 +445,39 @@ impl RequestHandle {
         Ok(())
     }
 
+    /// Queries the URL of the resource, which reflects any redirects that have been followed.
+    pub(crate) fn query_url(&self) -> Option<String> {
+        // First, query the required buffer size in bytes
+        let mut size: u32 = 0;
+        unsafe {
+            WinHttpQueryOption(
+                self.as_raw(),
+                WINHTTP_OPTION_URL,
+                std::ptr::null_mut(),
+                &mut size,
+            )
+        };
+        if size == 0 {
+            return None;
+        }
+
+        let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+        let result = unsafe {
+            WinHttpQueryOption(
+                self.as_raw(),
+                WINHTTP_OPTION_URL,
+                buffer.as_mut_ptr() as *mut std::ffi::c_void,
+                &mut size,
+            )
+        };
+        if result == 0 {
+            return None;
+        }
+
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Some(String::from_utf16_lossy(&buffer[..len]))
+    }
+
     /// Ignores certificate errors on this request.
     pub(crate) fn ignore_certificate_errors(&self) -> Result<()> {
         let flags: u32 = SECURITY_FLAG_IGNORE_UNKNOWN_CA
@@ -166,6 +166,17 @@ impl SessionHandle {
         }
     }
 
+    /// Sets the maximum number of automatic redirects.
+    pub(crate) fn set_max_redirects(&self, max_redirects: u32) -> Result<()> {
+        unsafe {
+            self.set_option(
+                WINHTTP_OPTION_MAX_HTTP_AUTOMATIC_REDIRECTS,
+                &max_redirects,
+                "WinHttpSetOption (set_max_redirects)",
+            )
+        }
+    }
+
     /// Enables automatic redirects.
     pub(crate) fn enable_redirects(&self) -> Result<()> {
         let policy: u32 = WINHTTP_OPTION_REDIRECT_POLICY_ALWAYS;
@@ -178,6 +189,29 @@ impl SessionHandle {
         }
     }
 
+    /// Sets the enabled TLS protocols as a combination of `WINHTTP_FLAG_SECURE_PROTOCOL_*` flags.
+    pub(crate) fn set_secure_protocols(&self, flags: u32) -> Result<()> {
+        unsafe {
+            self.set_option(
+                WINHTTP_OPTION_SECURE_PROTOCOLS,
+                &flags,
+                "WinHttpSetOption (set_secure_protocols)",
+            )
+        }
+    }
+
+    /// Enables HTTP/2 to be negotiated with the server.
+    pub(crate) fn enable_http2(&self) -> Result<()> {
+        let flags: u32 = WINHTTP_PROTOCOL_FLAG_HTTP2;
+        unsafe {
+            self.set_option(
+                WINHTTP_OPTION_ENABLE_HTTP_PROTOCOL,
+                &flags,
+                "WinHttpSetOption (enable_http2)",
+            )
+        }
+    }
+
     /// Sets the receive response timeout (time to wait for server to start sending response).
     pub(crate) fn set_receive_response_timeout(&self, timeout_ms: u32) -> Result<()> {
         unsafe {
@@ -2,7 +2,11 @@
 
 use std::sync::Arc;
 
-use nyquest_interface::client::ClientOptions;
+use nyquest_interface::client::{ClientOptions, HttpVersionPreference, TlsVersion};
+use windows_sys::Win32::Networking::WinHttp::{
+    WINHTTP_FLAG_SECURE_PROTOCOL_TLS1, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_1,
+    WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_2, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_3,
+};
 
 use crate::error::Result;
 use crate::handle::SessionHandle;
@@ -15,6 +19,60 @@ pub(crate) struct WinHttpSession {
 }
 
 impl WinHttpSession {
+    /// Rejects options that the backend is not able to honor.
+    pub(crate) fn check_supported(options: &ClientOptions) -> nyquest_interface::Result<()> {
+        if options.client_identity.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "client certificates are not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if !options.root_certificates.is_empty() || options.only_custom_roots {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "custom root certificates are not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if !options.pinned_public_keys.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "public key pinning is not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if !options.dns.overrides.is_empty() || options.dns.resolver.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "DNS overrides are not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if options.unix_socket.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "Unix domain sockets are not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if options.cookie_file.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "cookie files are not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        if options.http_version == HttpVersionPreference::Http2PriorKnowledge {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "HTTP/2 with prior knowledge is not supported by the WinHTTP backend",
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     /// Creates a new WinHTTP session.
     pub(crate) fn new(options: ClientOptions, is_async: bool) -> Result<Arc<Self>> {
         let session = SessionHandle::new(
@@ -46,10 +104,36 @@ impl WinHttpSession {
 
         if options.follow_redirects {
             session.enable_redirects()?;
+            if let Some(max_redirects) = options.redirect_policy.max_redirects {
+                session.set_max_redirects(max_redirects)?;
+            }
         } else {
             session.disable_redirects()?;
         }
 
+        if let Some(min_version) = options.min_tls_version {
+            let flags = [
+                (TlsVersion::Tls1_0, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1),
+                (TlsVersion::Tls1_1, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_1),
+                (TlsVersion::Tls1_2, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_2),
+                (TlsVersion::Tls1_3, WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_3),
+            ]
+            .into_iter()
+            .filter(|(version, _)| *version >= min_version)
+            .fold(0, |flags, (_, flag)| flags | flag);
+            // TLS 1.3 is rejected on Windows versions that do not support it
+            if let Err(e) = session.set_secure_protocols(flags) {
+                if min_version == TlsVersion::Tls1_3 {
+                    return Err(e);
+                }
+                session.set_secure_protocols(flags & !WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_3)?;
+            }
+        }
+
+        if options.http_version == HttpVersionPreference::PreferHttp2 {
+            session.enable_http2()?;
+        }
+
         Ok(())
     }
 }
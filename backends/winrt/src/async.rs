@@ -90,6 +90,18 @@ impl AsyncResponse for WinrtAsyncResponse {
         self.inner.get_header(header).into_nyquest_result()
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        self.inner.headers().into_nyquest_result()
+    }
+
+    fn url(&self) -> Option<String> {
+        self.inner.url()
+    }
+
+    fn version(&self) -> Option<nyquest_interface::HttpVersion> {
+        self.inner.version()
+    }
+
     async fn text(mut self: Pin<&mut Self>) -> nyquest_interface::Result<String> {
         let task = self
             .inner
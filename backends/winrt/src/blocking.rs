@@ -85,6 +85,18 @@ impl BlockingResponse for WinrtBlockingResponse {
         self.inner.get_header(header).into_nyquest_result()
     }
 
+    fn headers(&self) -> nyquest_interface::Result<Vec<(String, String)>> {
+        self.inner.headers().into_nyquest_result()
+    }
+
+    fn url(&self) -> Option<String> {
+        self.inner.url()
+    }
+
+    fn version(&self) -> Option<nyquest_interface::HttpVersion> {
+        self.inner.version()
+    }
+
     fn content_length(&self) -> Option<u64> {
         self.inner.content_length
     }
@@ -1,13 +1,15 @@
 use std::io;
 use std::time::Duration;
 
-use nyquest_interface::client::{CachingBehavior, ClientOptions, ProxyOptions};
+use nyquest_interface::client::{
+    CachingBehavior, ClientOptions, HttpVersionPreference, ProxyOptions,
+};
 use windows::core::{h, HSTRING};
 use windows::Security::Cryptography::Certificates::ChainValidationResult;
 use windows::Web::Http::Filters::{
     HttpBaseProtocolFilter, HttpCacheReadBehavior, HttpCacheWriteBehavior, HttpCookieUsageBehavior,
 };
-use windows::Web::Http::HttpClient;
+use windows::Web::Http::{HttpClient, HttpVersion};
 
 use crate::request::is_header_name_content_related;
 
@@ -22,6 +24,54 @@ pub struct WinrtClient {
 
 impl WinrtClient {
     pub fn create(options: ClientOptions) -> io::Result<Self> {
+        if options.client_identity.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "client certificates are not supported by the WinRT backend",
+            ));
+        }
+        if !options.root_certificates.is_empty() || options.only_custom_roots {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "custom root certificates are not supported by the WinRT backend",
+            ));
+        }
+        if !options.pinned_public_keys.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "public key pinning is not supported by the WinRT backend",
+            ));
+        }
+        if !options.dns.overrides.is_empty() || options.dns.resolver.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "DNS overrides are not supported by the WinRT backend",
+            ));
+        }
+        if options.unix_socket.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Unix domain sockets are not supported by the WinRT backend",
+            ));
+        }
+        if options.cookie_file.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cookie files are not supported by the WinRT backend",
+            ));
+        }
+        if options.min_tls_version.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "minimum TLS versions are not supported by the WinRT backend",
+            ));
+        }
+        if options.http_version == HttpVersionPreference::Http2PriorKnowledge {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "HTTP/2 with prior knowledge is not supported by the WinRT backend",
+            ));
+        }
         let base_url = options.base_url.as_ref().map(HSTRING::from);
         let filter = HttpBaseProtocolFilter::new()?;
         filter.SetAutomaticDecompression(true)?;
@@ -47,6 +97,9 @@ impl WinrtClient {
         if !options.follow_redirects {
             filter.SetAllowAutoRedirect(false)?;
         }
+        if options.http_version == HttpVersionPreference::Http1Only {
+            filter.SetMaxVersion(HttpVersion::Http11)?;
+        }
         let client = HttpClient::Create(&filter)?;
         if let Some(user_agent) = &options.user_agent {
             client
@@ -1,7 +1,7 @@
 use std::io;
 
 use windows::core::HSTRING;
-use windows::Web::Http::{HttpResponseMessage, IHttpContent};
+use windows::Web::Http::{HttpResponseMessage, HttpVersion, IHttpContent};
 
 use crate::timer::Timer;
 
@@ -46,6 +46,45 @@ impl WinrtResponse {
         Ok(headers.into_iter().map(|h| h.to_string_lossy()).collect())
     }
 
+    pub(crate) fn url(&self) -> Option<String> {
+        let uri = self.response.RequestMessage().ok()?.RequestUri().ok()?;
+        Some(uri.AbsoluteUri().ok()?.to_string_lossy())
+    }
+
+    pub(crate) fn version(&self) -> Option<nyquest_interface::HttpVersion> {
+        match self.response.Version().ok()? {
+            HttpVersion::Http10 => Some(nyquest_interface::HttpVersion::Http10),
+            HttpVersion::Http11 => Some(nyquest_interface::HttpVersion::Http11),
+            HttpVersion::Http20 => Some(nyquest_interface::HttpVersion::Http2),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn headers(&self) -> io::Result<Vec<(String, String)>> {
+        let mut res = vec![];
+        let iter = self.response.Headers()?.First()?;
+        while iter.HasCurrent()? {
+            let pair = iter.Current()?;
+            res.push((
+                pair.Key()?.to_string_lossy(),
+                pair.Value()?.to_string_lossy(),
+            ));
+            iter.MoveNext()?;
+        }
+        if let Ok(content) = self.content() {
+            let iter = content.Headers()?.First()?;
+            while iter.HasCurrent()? {
+                let pair = iter.Current()?;
+                res.push((
+                    pair.Key()?.to_string_lossy(),
+                    pair.Value()?.to_string_lossy(),
+                ));
+                iter.MoveNext()?;
+            }
+        }
+        Ok(res)
+    }
+
     pub(crate) fn content(&self) -> io::Result<IHttpContent> {
         Ok(self.response.Content()?)
     }
@@ -1,8 +1,23 @@
+mod auth;
 mod cache;
 mod certificate_errors;
+#[cfg(any(feature = "curl", feature = "reqwest"))]
+// other backends are not served by the TLS fixture
+mod client_identity;
+mod cookie_file;
 mod cookies;
+mod dns;
 mod headers;
+mod http_version;
+mod middleware;
+mod oauth2;
+mod pinned_public_keys;
 mod proxy;
 mod redirects;
 mod request_timeout;
 mod response_size;
+mod retry;
+#[cfg(any(feature = "curl", feature = "reqwest"))]
+// other backends are not served by the TLS fixture
+mod root_certificates;
+mod unix_socket;
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use http_body_util::Full;
+    use hyper::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+    use hyper::{body, Request, Response, StatusCode};
+    use nyquest::auth::AwsSigV4;
+    #[cfg(feature = "blocking")]
+    use nyquest::blocking::Body as NyquestBlockingBody;
+    #[cfg(feature = "async")]
+    use nyquest::r#async::Body as NyquestAsyncBody;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    const ECHO_PATH: &str = "client_options/auth/echo";
+    const SIGV4_PATH: &str = "client_options/auth/sigv4";
+    const DIGEST_CHALLENGE: &str =
+        r#"Digest realm="nyquest", nonce="0a1b2c3d", qop="auth", opaque="4e5f""#;
+
+    /// Responds with the `Authorization` header of the request.
+    async fn echo_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let authorization = req
+            .headers()
+            .get(AUTHORIZATION)
+            .map(|v| v.to_str().unwrap().to_owned())
+            .unwrap_or_default();
+        let res = Response::new(Full::new(Bytes::from(authorization)));
+        (res.into(), Ok(()))
+    }
+
+    /// Challenges requests without credentials, and checks the answer to the challenge otherwise.
+    async fn digest_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let Some(authorization) = req.headers().get(AUTHORIZATION) else {
+            let res = Response::builder()
+                .status(StatusCode::UNAUTHORIZED)
+                .header(WWW_AUTHENTICATE, DIGEST_CHALLENGE)
+                .body(Full::new(Bytes::new()))
+                .unwrap();
+            return (res.into(), Ok(()));
+        };
+        let authorization = authorization.to_str().unwrap().to_owned();
+        let uri = format!(r#"uri="{}""#, req.uri().path_and_query().unwrap());
+        let expected = [
+            r#"username="user""#,
+            r#"realm="nyquest""#,
+            r#"nonce="0a1b2c3d""#,
+            &uri,
+            "qop=auth",
+            "nc=00000001",
+            r#"opaque="4e5f""#,
+        ];
+        let res = Response::new(Full::new(Bytes::from_static(b"authorized")));
+        if authorization.starts_with("Digest ")
+            && expected.iter().all(|field| authorization.contains(field))
+        {
+            (res.into(), Ok(()))
+        } else {
+            (res.into(), Err(req))
+        }
+    }
+
+    /// Redirects to the same path, where requests are challenged by [`digest_handler`].
+    async fn digest_redirect_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        if req.uri().query() == Some("redirected=1") {
+            return digest_handler(req).await;
+        }
+        let res = Response::builder()
+            .header("Location", "?redirected=1")
+            .status(StatusCode::FOUND)
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        (res.into(), Ok(()))
+    }
+
+    /// Checks the headers added by the SigV4 signer. The signature itself is covered by the test
+    /// vectors in nyquest.
+    async fn sigv4_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let header = |name: &str| {
+            req.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+        };
+        let authorization = header("authorization");
+        let valid = authorization
+            .starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/")
+            && authorization.contains("/us-east-1/s3/aws4_request, SignedHeaders=host;x-amz-content-sha256;x-amz-date;x-amz-security-token, Signature=")
+            // SHA-256 of "hello"
+            && header("x-amz-content-sha256")
+                == "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+            && header("x-amz-security-token") == "token"
+            && header("x-amz-date").len() == 16;
+        let res = Response::new(Full::new(Bytes::from_static(b"signed")));
+        if valid {
+            (res.into(), Ok(()))
+        } else {
+            (res.into(), Err(req))
+        }
+    }
+
+    /// Requests to send with a client using Basic authentication, and the expected header.
+    fn requests<S>() -> [(NyquestRequest<S>, &'static str); 4] {
+        [
+            (NyquestRequest::get(ECHO_PATH), "Basic dXNlcjpwYXNz"),
+            (
+                NyquestRequest::get(ECHO_PATH).with_bearer_token("token"),
+                "Bearer token",
+            ),
+            (
+                NyquestRequest::get(ECHO_PATH).with_basic_auth("other", "pass"),
+                "Basic b3RoZXI6cGFzcw==",
+            ),
+            (
+                NyquestRequest::get(ECHO_PATH).with_header("authorization", "Custom"),
+                "Custom",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_basic_and_bearer_auth() {
+        let _handle = crate::add_hyper_fixture(ECHO_PATH, echo_handler);
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder.basic_auth("user", "pass").build_blocking().unwrap();
+            for (req, expected) in requests() {
+                let res = client.request(req).unwrap().text().unwrap();
+                assert_eq!(res, expected);
+            }
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            let builder = crate::init_builder().await.unwrap();
+            let client = builder
+                .basic_auth("user", "pass")
+                .build_async()
+                .await
+                .unwrap();
+            for (req, expected) in requests() {
+                let res = client.request(req).await.unwrap().text().await.unwrap();
+                assert_eq!(res, expected);
+            }
+        });
+    }
+
+    #[test]
+    fn test_digest_auth() {
+        const PATH: &str = "client_options/auth/digest";
+        const REDIRECT_PATH: &str = "client_options/auth/digest_redirect";
+        let _handle = crate::add_hyper_fixture(PATH, digest_handler);
+        let _redirect_handle = crate::add_hyper_fixture(REDIRECT_PATH, digest_redirect_handler);
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder
+                .digest_auth("user", "pass")
+                .build_blocking()
+                .unwrap();
+            for path in [PATH, REDIRECT_PATH] {
+                let res = client.request(NyquestRequest::get(path)).unwrap();
+                assert_eq!(res.status(), 200);
+                assert_eq!(res.text().unwrap(), "authorized");
+            }
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            let builder = crate::init_builder().await.unwrap();
+            let client = builder
+                .digest_auth("user", "pass")
+                .build_async()
+                .await
+                .unwrap();
+            for path in [PATH, REDIRECT_PATH] {
+                let res = client.request(NyquestRequest::get(path)).await.unwrap();
+                assert_eq!(res.status(), 200);
+                assert_eq!(res.text().await.unwrap(), "authorized");
+            }
+        });
+    }
+
+    #[test]
+    fn test_aws_sigv4() {
+        let _handle = crate::add_hyper_fixture(SIGV4_PATH, sigv4_handler);
+        // Relative URLs are resolved against the base URL of the client before signing
+        let url = SIGV4_PATH;
+        let signer = || {
+            AwsSigV4::new("AKIDEXAMPLE", "secret", "us-east-1", "s3").with_session_token("token")
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder.with_middleware(signer()).build_blocking().unwrap();
+            let req = NyquestRequest::put(url).with_body(NyquestBlockingBody::plain_text("hello"));
+            let res = client.request(req).unwrap();
+            assert_eq!(res.status(), 200);
+            assert_eq!(res.text().unwrap(), "signed");
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            let builder = crate::init_builder().await.unwrap();
+            let client = builder
+                .with_middleware(signer())
+                .build_async()
+                .await
+                .unwrap();
+            let req = NyquestRequest::put(url).with_body(NyquestAsyncBody::plain_text("hello"));
+            let res = client.request(req).await.unwrap();
+            assert_eq!(res.status(), 200);
+            assert_eq!(res.text().await.unwrap(), "signed");
+        });
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use nyquest::client::Identity;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::tls_fixture::{TlsServer, CERTIFICATES, CLIENT_COMMON_NAME, PKCS12_PASSWORD};
+    use crate::*;
+
+    fn pem_identity() -> Identity {
+        Identity::from_pem(
+            CERTIFICATES.client_cert_pem(),
+            CERTIFICATES.client_key_pem(),
+        )
+    }
+
+    fn pkcs12_identity() -> Identity {
+        Identity::from_pkcs12_der(CERTIFICATES.client_pkcs12_der(), PKCS12_PASSWORD)
+    }
+
+    #[test]
+    fn test_client_identity() {
+        let server = TlsServer::spawn(true).unwrap();
+        let url = server.url();
+
+        #[cfg(feature = "blocking")]
+        {
+            for identity in [pem_identity(), pkcs12_identity()] {
+                let client = crate::init_builder_blocking()
+                    .unwrap()
+                    .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                    .client_identity(identity)
+                    .build_blocking()
+                    .unwrap();
+                let res = client.request(NyquestRequest::get(url.clone())).unwrap();
+                assert_eq!(res.text().unwrap(), CLIENT_COMMON_NAME);
+            }
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let mut texts = vec![];
+                for identity in [pem_identity(), pkcs12_identity()] {
+                    let client = crate::init_builder()
+                        .await
+                        .unwrap()
+                        .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                        .client_identity(identity)
+                        .build_async()
+                        .await
+                        .unwrap();
+                    let res = client.request(NyquestRequest::get(url.clone())).await?;
+                    texts.push(res.text().await?);
+                }
+                nyquest::Result::Ok(texts)
+            });
+            assert_eq!(res.unwrap(), [CLIENT_COMMON_NAME; 2]);
+        }
+    }
+
+    #[test]
+    fn test_client_identity_required() {
+        let server = TlsServer::spawn(true).unwrap();
+        let url = server.url();
+
+        #[cfg(feature = "blocking")]
+        {
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                .build_blocking()
+                .unwrap();
+            client
+                .request(NyquestRequest::get(url.clone()))
+                .unwrap_err();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                    .build_async()
+                    .await
+                    .unwrap();
+                client.request(NyquestRequest::get(url)).await
+            });
+            res.unwrap_err();
+        }
+    }
+}
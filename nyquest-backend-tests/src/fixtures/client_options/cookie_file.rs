@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use http_body_util::Full;
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use hyper::header::{COOKIE, SET_COOKIE};
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use hyper::{body, Request, Response};
+    #[cfg(all(feature = "blocking", any(feature = "curl", feature = "reqwest")))]
+    use nyquest::blocking::Body as NyquestBlockingBody;
+    #[cfg(all(feature = "async", any(feature = "curl", feature = "reqwest")))]
+    use nyquest::r#async::Body as NyquestAsyncBody;
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    const COOKIE_NAME: &str = "TEST_COOKIE_FILE";
+
+    fn temp_cookie_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "nyquest-cookie-file-{}-{name}.txt",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    /// Responds with the cookie in the request, and sets the cookie to the request body if any.
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    async fn cookie_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let request_cookie_value = req
+            .headers()
+            .get(COOKIE)
+            .and_then(|v| {
+                v.to_str()
+                    .ok()?
+                    .strip_prefix(COOKIE_NAME)?
+                    .strip_prefix('=')
+            })
+            .unwrap_or_default()
+            .to_owned();
+
+        let request_body = req.into_body().collect().await.unwrap().to_bytes();
+        let mut res = Response::builder();
+        if !request_body.is_empty() {
+            let request_body = String::from_utf8_lossy(&request_body);
+            res = res.header(SET_COOKIE, format!("{COOKIE_NAME}={request_body}; Path=/"));
+        }
+        let res = res
+            .body(Full::new(Bytes::from(request_cookie_value)))
+            .unwrap();
+        (res.into(), Ok(()))
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    fn assert_saved(path: &Path, value: &str) {
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(
+            content
+                .lines()
+                .any(|line| line.ends_with(&format!("\t{COOKIE_NAME}\t{value}"))),
+            "cookie {value} is not saved: {content}"
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            let mode = std::fs::metadata(path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support cookie files
+    #[test]
+    fn test_cookie_file() {
+        const PATH: &str = "client_options/cookie_file";
+
+        let _handle = crate::add_hyper_fixture(PATH, cookie_handler);
+
+        #[cfg(feature = "blocking")]
+        {
+            let path = temp_cookie_file("blocking");
+            let build = || {
+                let builder = crate::init_builder_blocking().unwrap();
+                builder.cookie_file(&path).build_blocking().unwrap()
+            };
+            let send = |client: &nyquest::BlockingClient, body: &'static str| {
+                let mut request = NyquestRequest::post(PATH);
+                if !body.is_empty() {
+                    request = request.with_body(NyquestBlockingBody::plain_text(body));
+                }
+                client.request(request).unwrap().text().unwrap()
+            };
+
+            let client = build();
+            send(&client, "saved_on_drop");
+            drop(client);
+            assert_saved(&path, "saved_on_drop");
+
+            let client = build();
+            assert_eq!(send(&client, "flushed"), "saved_on_drop");
+            client.flush_cookies().unwrap();
+            assert_saved(&path, "flushed");
+            drop(client);
+
+            assert_eq!(send(&build(), ""), "flushed");
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            let path = temp_cookie_file("async");
+            let build = || async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.cookie_file(&path).build_async().await.unwrap()
+            };
+            let send = |client: nyquest::AsyncClient, body: &'static str| async move {
+                let mut request = NyquestRequest::post(PATH);
+                if !body.is_empty() {
+                    request = request.with_body(NyquestAsyncBody::plain_text(body));
+                }
+                client.request(request).await.unwrap().text().await.unwrap()
+            };
+
+            let client = build().await;
+            send(client.clone(), "saved_on_drop").await;
+            drop(client);
+            assert_saved(&path, "saved_on_drop");
+
+            let client = build().await;
+            assert_eq!(send(client.clone(), "flushed").await, "saved_on_drop");
+            client.flush_cookies().unwrap();
+            assert_saved(&path, "flushed");
+            drop(client);
+
+            assert_eq!(send(build().await, "").await, "flushed");
+            std::fs::remove_file(&path).unwrap();
+        });
+    }
+
+    #[cfg(not(any(feature = "curl", feature = "reqwest")))]
+    #[test]
+    fn test_cookie_file_unsupported() {
+        #[cfg(feature = "blocking")]
+        {
+            let path = temp_cookie_file("unsupported-blocking");
+            let builder = crate::init_builder_blocking().unwrap();
+            builder.cookie_file(path).build_blocking().unwrap_err();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let path = temp_cookie_file("unsupported-async");
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.cookie_file(path).build_async().await
+            });
+            res.unwrap_err();
+        }
+    }
+}
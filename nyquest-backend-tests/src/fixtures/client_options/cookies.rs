@@ -8,6 +8,8 @@ mod tests {
     #[cfg(feature = "async")]
     use nyquest::r#async::Body as NyquestAsyncBody;
     use nyquest::Request as NyquestRequest;
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use nyquest::{Cookie, CookieJar};
 
     use crate::*;
 
@@ -119,4 +121,146 @@ mod tests {
             assertions(response_body);
         }
     }
+
+    /// Runs `scenario` with the cookie jar of a client and a function sending a request to `path`
+    /// with an optional body, which returns the cookie received by the server.
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    fn with_cookie_jar(path: &str, scenario: impl Fn(CookieJar, &dyn Fn(Option<&str>) -> String)) {
+        #[cfg(feature = "blocking")]
+        {
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let send = |body: Option<&str>| {
+                let mut request = NyquestRequest::post(path.to_owned());
+                if let Some(body) = body {
+                    request = request.with_body(NyquestBlockingBody::plain_text(body.to_owned()));
+                }
+                client.request(request).unwrap().text().unwrap()
+            };
+            scenario(client.cookie_jar().unwrap(), &send);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let client = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.build_async().await.unwrap()
+            });
+            let send = |body: Option<&str>| {
+                let mut request = NyquestRequest::post(path.to_owned());
+                if let Some(body) = body {
+                    request = request.with_body(NyquestAsyncBody::plain_text(body.to_owned()));
+                }
+                TOKIO_RT
+                    .block_on(async { client.request(request).await?.text().await })
+                    .unwrap()
+            };
+            scenario(client.cookie_jar().unwrap(), &send);
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not expose cookie jars
+    #[test]
+    fn test_cookie_jar_list() {
+        const PATH: &str = "client_options/cookie_jar_list";
+
+        let _handle = crate::add_hyper_fixture(PATH, cookie_handler);
+
+        with_cookie_jar(PATH, |jar, send| {
+            send(Some(MOCK_COOKIE_VALUE));
+            let cookies = jar.cookies().unwrap();
+            let cookie = cookies
+                .iter()
+                .find(|c| c.name() == COOKIE_NAME)
+                .expect("cookie set by the server is listed");
+            assert_eq!(cookie.value(), MOCK_COOKIE_VALUE);
+            assert_eq!(cookie.path(), "/");
+            assert!(!cookie.includes_subdomains());
+
+            let domain = cookie.domain().to_owned();
+            assert_eq!(
+                jar.cookies_for(&domain, "/client_options").unwrap().len(),
+                1
+            );
+            assert!(jar.cookies_for("example.com", "/").unwrap().is_empty());
+            assert!(jar
+                .cookies_for(&format!("sub.{domain}"), "/")
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not expose cookie jars
+    #[test]
+    fn test_cookie_jar_add() {
+        const PATH: &str = "client_options/cookie_jar_add";
+
+        let _handle = crate::add_hyper_fixture(PATH, cookie_handler);
+
+        with_cookie_jar(PATH, |jar, send| {
+            // Learn the host the cookies of the server are stored for
+            send(Some(MOCK_COOKIE_VALUE));
+            let host = jar.cookies().unwrap()[0].domain().to_owned();
+            jar.clear().unwrap();
+
+            jar.add(Cookie::new(COOKIE_NAME, "seeded", &host)).unwrap();
+            assert_eq!(send(None), "seeded");
+
+            jar.add(Cookie::new(COOKIE_NAME, "replaced", &host))
+                .unwrap();
+            assert_eq!(send(None), "replaced");
+
+            // Not sent to other paths
+            jar.clear().unwrap();
+            jar.add(Cookie::new(COOKIE_NAME, "elsewhere", &host).with_path("/elsewhere"))
+                .unwrap();
+            assert_eq!(send(None), "");
+        });
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not expose cookie jars
+    #[test]
+    fn test_cookie_jar_remove_and_clear() {
+        const PATH: &str = "client_options/cookie_jar_remove_and_clear";
+
+        let _handle = crate::add_hyper_fixture(PATH, cookie_handler);
+
+        with_cookie_jar(PATH, |jar, send| {
+            send(Some(MOCK_COOKIE_VALUE));
+            let cookie = jar
+                .cookies()
+                .unwrap()
+                .into_iter()
+                .find(|c| c.name() == COOKIE_NAME)
+                .unwrap();
+            jar.remove(COOKIE_NAME, cookie.domain(), "/").unwrap();
+            assert!(jar.cookies().unwrap().is_empty());
+            assert_eq!(send(Some(MOCK_COOKIE_VALUE)), "");
+
+            jar.clear().unwrap();
+            assert!(jar.cookies().unwrap().is_empty());
+            assert_eq!(send(None), "");
+        });
+    }
+
+    #[test]
+    fn test_cookie_jar_unavailable() {
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap().no_cookies();
+            let client = builder.build_blocking().unwrap();
+            assert!(client.cookie_jar().is_none());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let client = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap().no_cookies();
+                builder.build_async().await.unwrap()
+            });
+            assert!(client.cookie_jar().is_none());
+        }
+    }
 }
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use http_body_util::Full;
+    use nyquest::{ClientBuilder, Request as NyquestRequest};
+
+    use crate::*;
+
+    const HOST: &str = "nyquest-dns-override.invalid";
+    const LOCALHOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    async fn ok_handler() -> FixtureAssertionResult {
+        let res = Response::new(Full::new(Bytes::from("ok")));
+        (res.into(), Ok(()))
+    }
+
+    fn fetch(
+        path: &str,
+        configure: impl Fn(ClientBuilder, u16) -> ClientBuilder,
+    ) -> Vec<nyquest::Result<String>> {
+        let port = TOKIO_RT.block_on(crate::init_main_service_port()).unwrap();
+        let url = format!("http://{HOST}:{port}/{path}");
+        #[allow(unused_mut)]
+        let mut results = vec![];
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = configure(crate::init_builder_blocking().unwrap(), port).no_proxy();
+            let client = builder.build_blocking().unwrap();
+            results.push(
+                client
+                    .request(NyquestRequest::get(url.clone()))
+                    .and_then(|res| res.text()),
+            );
+        }
+
+        #[cfg(feature = "async")]
+        results.push(TOKIO_RT.block_on(async {
+            let builder = configure(crate::init_builder().await.unwrap(), port).no_proxy();
+            let client = builder.build_async().await.unwrap();
+            client.request(NyquestRequest::get(url)).await?.text().await
+        }));
+
+        results
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support DNS overrides
+    #[test]
+    fn test_resolve_override() {
+        const PATH: &str = "client_options/resolve_override";
+        let _handle = crate::add_hyper_fixture(PATH, |_| ok_handler());
+
+        let results = fetch(PATH, |builder, port| {
+            builder.resolve(HOST, port, [LOCALHOST])
+        });
+        for res in results {
+            assert_eq!(res.unwrap(), "ok");
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support custom resolvers
+    #[test]
+    fn test_dns_resolver() {
+        const PATH: &str = "client_options/dns_resolver";
+        let _handle = crate::add_hyper_fixture(PATH, |_| ok_handler());
+
+        let results = fetch(PATH, |builder, _| {
+            builder.dns_resolver(|host: &str| {
+                assert_eq!(host, HOST);
+                Ok(vec![LOCALHOST])
+            })
+        });
+        for res in results {
+            assert_eq!(res.unwrap(), "ok");
+        }
+
+        let results = fetch(PATH, |builder, _| {
+            builder.dns_resolver(|_: &str| Err(io::Error::other("no such host")))
+        });
+        for res in results {
+            assert!(res.unwrap_err().is_dns());
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support custom resolvers
+    #[test]
+    fn test_dns_resolver_redirect() {
+        const PATH: &str = "client_options/dns_resolver_redirect";
+        const OTHER_HOST: &str = "nyquest-dns-redirect.invalid";
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            if req.uri().query() == Some("redirected=1") {
+                return ok_handler().await;
+            }
+            let host = req.headers()["Host"].to_str().unwrap();
+            let port = host.rsplit_once(':').unwrap().1;
+            let res = Response::builder()
+                .status(302)
+                .header(
+                    "Location",
+                    format!("http://{OTHER_HOST}:{port}/{PATH}?redirected=1"),
+                )
+                .body(Full::new(Bytes::new()))
+                .unwrap();
+            (res.into(), Ok(()))
+        });
+
+        let results = fetch(PATH, |builder, _| {
+            builder.dns_resolver(|host: &str| {
+                assert!(host == HOST || host == OTHER_HOST, "{host}");
+                Ok(vec![LOCALHOST])
+            })
+        });
+        for res in results {
+            assert_eq!(res.unwrap(), "ok");
+        }
+    }
+
+    #[cfg(not(any(feature = "curl", feature = "reqwest")))]
+    #[test]
+    fn test_resolve_override_unsupported() {
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            builder
+                .resolve(HOST, 80, [LOCALHOST])
+                .build_blocking()
+                .unwrap_err();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.resolve(HOST, 80, [LOCALHOST]).build_async().await
+            });
+            res.unwrap_err();
+        }
+    }
+}
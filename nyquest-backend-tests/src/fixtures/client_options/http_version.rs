@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use http_body_util::Full;
+    use nyquest::client::HttpVersionPreference;
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use nyquest::client::TlsVersion;
+    use nyquest::{HttpVersion, Request as NyquestRequest};
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    use crate::tls_fixture::{TlsServer, CERTIFICATES};
+    use crate::*;
+
+    async fn ok_handler() -> FixtureAssertionResult {
+        let res = Response::new(Full::new(Bytes::from("ok")));
+        (res.into(), Ok(()))
+    }
+
+    fn assert_http11(version: Option<HttpVersion>) {
+        if cfg!(any(feature = "curl", feature = "reqwest")) {
+            assert_eq!(version, Some(HttpVersion::Http11));
+        } else {
+            // Other backends may not be able to tell the version of a response
+            assert!(
+                matches!(version, None | Some(HttpVersion::Http11)),
+                "{version:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_http_version_fallback() {
+        const PATH: &str = "client_options/http_version_fallback";
+
+        // The fixture server only speaks HTTP/1.1
+        let _handle = crate::add_hyper_fixture(PATH, |_| ok_handler());
+        let preferences = [
+            HttpVersionPreference::Http1Only,
+            HttpVersionPreference::PreferHttp2,
+        ];
+
+        #[cfg(feature = "blocking")]
+        for preference in preferences {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .http_version(preference);
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assert_http11(res.version());
+            assert_eq!(res.text().unwrap(), "ok");
+        }
+
+        #[cfg(feature = "async")]
+        for preference in preferences {
+            let (version, text) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .http_version(preference);
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (res.version(), res.text().await.unwrap())
+            });
+            assert_http11(version);
+            assert_eq!(text, "ok");
+        }
+    }
+
+    /// Spawns a server speaking only HTTP/2 over cleartext, returning its URL.
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    fn spawn_h2c_server() -> String {
+        use hyper::service::service_fn;
+        use hyper_util::rt::{TokioExecutor, TokioIo};
+
+        let listener = TOKIO_RT
+            .block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))
+            .unwrap();
+        let port = listener.local_addr().unwrap().port();
+        TOKIO_RT.spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.expect("accept failed");
+                tokio::spawn(
+                    hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+                        .serve_connection(
+                            TokioIo::new(stream),
+                            service_fn(|_| async {
+                                Ok::<_, std::convert::Infallible>(Response::new(Full::new(
+                                    Bytes::from("ok"),
+                                )))
+                            }),
+                        ),
+                );
+            }
+        });
+        format!("http://127.0.0.1:{port}/")
+    }
+
+    /// Returns whether building a client with HTTP/2 failed because the backend lacks HTTP/2
+    /// support, which is only acceptable for libcurl built without it.
+    #[cfg(any(feature = "curl", feature = "reqwest"))]
+    fn http2_unsupported(res: &nyquest::Result<impl Sized>) -> bool {
+        match res {
+            Err(nyquest::Error::Io(e)) if cfg!(feature = "curl") => {
+                e.kind() == std::io::ErrorKind::Unsupported
+            }
+            _ => false,
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support h2c
+    #[test]
+    fn test_http2_prior_knowledge() {
+        let url = spawn_h2c_server();
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .no_proxy()
+                .http_version(HttpVersionPreference::Http2PriorKnowledge);
+            let client = builder.build_blocking();
+            if !http2_unsupported(&client) {
+                let client = client.unwrap();
+                let res = client.request(NyquestRequest::get(url.clone())).unwrap();
+                assert_eq!(res.version(), Some(HttpVersion::Http2));
+                assert_eq!(res.text().unwrap(), "ok");
+            }
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            let builder = crate::init_builder()
+                .await
+                .unwrap()
+                .no_proxy()
+                .http_version(HttpVersionPreference::Http2PriorKnowledge);
+            let client = builder.build_async().await;
+            if !http2_unsupported(&client) {
+                let client = client.unwrap();
+                let res = client.request(NyquestRequest::get(url)).await.unwrap();
+                assert_eq!(res.version(), Some(HttpVersion::Http2));
+                assert_eq!(res.text().await.unwrap(), "ok");
+            }
+        });
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends do not support custom roots
+    #[test]
+    fn test_min_tls_version() {
+        let server = TlsServer::spawn(false).unwrap();
+        let url = server.url();
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                .min_tls_version(TlsVersion::Tls1_2);
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(url.clone())).unwrap();
+            assert_eq!(res.text().unwrap(), "anonymous");
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let text = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                    .min_tls_version(TlsVersion::Tls1_2);
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(url)).await.unwrap();
+                res.text().await.unwrap()
+            });
+            assert_eq!(text, "anonymous");
+        }
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use http_body_util::Full;
+    use nyquest::middleware::Middleware;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    struct InjectHeader;
+
+    impl Middleware for InjectHeader {
+        #[cfg(feature = "async")]
+        fn handle_async<'a>(
+            &'a self,
+            req: nyquest::r#async::Request,
+            next: nyquest::r#async::Next<'a>,
+        ) -> nyquest::middleware::BoxFuture<'a, nyquest::Result<nyquest::r#async::Response>>
+        {
+            next.run(req.with_header("x-middleware", "injected"))
+        }
+
+        #[cfg(feature = "blocking")]
+        fn handle_blocking(
+            &self,
+            req: nyquest::blocking::Request,
+            next: nyquest::blocking::Next<'_>,
+        ) -> nyquest::Result<nyquest::blocking::Response> {
+            next.run(req.with_header("x-middleware", "injected"))
+        }
+    }
+
+    struct Reject;
+
+    impl Middleware for Reject {
+        #[cfg(feature = "async")]
+        fn handle_async<'a>(
+            &'a self,
+            _req: nyquest::r#async::Request,
+            _next: nyquest::r#async::Next<'a>,
+        ) -> nyquest::middleware::BoxFuture<'a, nyquest::Result<nyquest::r#async::Response>>
+        {
+            Box::pin(async { Err(io::Error::other("rejected by middleware").into()) })
+        }
+
+        #[cfg(feature = "blocking")]
+        fn handle_blocking(
+            &self,
+            _req: nyquest::blocking::Request,
+            _next: nyquest::blocking::Next<'_>,
+        ) -> nyquest::Result<nyquest::blocking::Response> {
+            Err(io::Error::other("rejected by middleware").into())
+        }
+    }
+
+    struct ReissueOnServerError;
+
+    impl Middleware for ReissueOnServerError {
+        #[cfg(feature = "async")]
+        fn handle_async<'a>(
+            &'a self,
+            req: nyquest::r#async::Request,
+            next: nyquest::r#async::Next<'a>,
+        ) -> nyquest::middleware::BoxFuture<'a, nyquest::Result<nyquest::r#async::Response>>
+        {
+            Box::pin(async move {
+                let retry = req.try_clone().expect("request should be cloneable");
+                let res = next.run(req).await?;
+                if res.status().is_server_error() {
+                    next.run(retry).await
+                } else {
+                    Ok(res)
+                }
+            })
+        }
+
+        #[cfg(feature = "blocking")]
+        fn handle_blocking(
+            &self,
+            req: nyquest::blocking::Request,
+            next: nyquest::blocking::Next<'_>,
+        ) -> nyquest::Result<nyquest::blocking::Response> {
+            let retry = req.try_clone().expect("request should be cloneable");
+            let res = next.run(req)?;
+            if res.status().is_server_error() {
+                next.run(retry)
+            } else {
+                Ok(res)
+            }
+        }
+    }
+
+    #[test]
+    fn test_middleware_modifies_request() {
+        const PATH: &str = "client_options/middleware/modifies_request";
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            let value = req
+                .headers()
+                .get("x-middleware")
+                .map(|v| v.to_str().unwrap().to_owned())
+                .unwrap_or_default();
+            (Response::new(Full::new(Bytes::from(value))), Ok(()))
+        });
+
+        let assertions = |body: String| {
+            assert_eq!(body, "injected");
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .with_middleware(InjectHeader);
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.text().unwrap());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let body = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .with_middleware(InjectHeader);
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                res.text().await.unwrap()
+            });
+            assertions(body);
+        }
+    }
+
+    #[test]
+    fn test_middleware_short_circuit() {
+        const PATH: &str = "client_options/middleware/short_circuit";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(PATH, {
+            let hits = hits.clone();
+            move |_req| {
+                hits.fetch_add(1, Ordering::SeqCst);
+                async move { (Response::new(Full::new(Bytes::new())), Ok(())) }
+            }
+        });
+
+        let assertions = |res: nyquest::Result<()>| {
+            let err = res.unwrap_err();
+            assert!(matches!(err, nyquest::Error::Io(_)));
+            assert_eq!(hits.load(Ordering::SeqCst), 0);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .with_middleware(Reject)
+                .with_middleware(InjectHeader);
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).map(|_| ());
+            assertions(res);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .with_middleware(Reject)
+                    .with_middleware(InjectHeader);
+                let client = builder.build_async().await.unwrap();
+                client.request(NyquestRequest::get(PATH)).await.map(|_| ())
+            });
+            assertions(res);
+        }
+    }
+
+    #[test]
+    fn test_middleware_reissue() {
+        const PATH: &str = "client_options/middleware/reissue";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(PATH, {
+            let hits = hits.clone();
+            move |_req| {
+                let attempt = hits.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    let res = if attempt % 2 == 0 {
+                        Response::builder()
+                            .status(503)
+                            .body(Full::new(Bytes::from("unavailable")))
+                            .unwrap()
+                    } else {
+                        Response::new(Full::new(Bytes::from("ok")))
+                    };
+                    (res, Ok(()))
+                }
+            }
+        });
+
+        let assertions = |status: u16, body: String| {
+            assert_eq!(status, 200);
+            assert_eq!(body, "ok");
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .with_middleware(ReissueOnServerError);
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.status().into(), res.text().unwrap());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (status, body) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .with_middleware(ReissueOnServerError);
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (res.status().into(), res.text().await.unwrap())
+            });
+            assertions(status, body);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use http_body_util::Full;
+    use hyper::header::{AUTHORIZATION, CONTENT_TYPE};
+    use hyper::StatusCode;
+    use nyquest::auth::OAuth2ClientCredentials;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    const TOKEN_PATH: &str = "client_options/oauth2/token";
+    const RESOURCE_PATH: &str = "client_options/oauth2/resource";
+
+    #[derive(Default)]
+    struct TokenState {
+        /// The number of tokens issued, with token `n` being `token-n`.
+        issued: AtomicUsize,
+        /// Tokens issued before this one are considered revoked.
+        min_valid: AtomicUsize,
+    }
+
+    impl TokenState {
+        fn reset(&self) {
+            self.issued.store(0, Ordering::SeqCst);
+            self.min_valid.store(1, Ordering::SeqCst);
+        }
+    }
+
+    fn middleware() -> OAuth2ClientCredentials {
+        OAuth2ClientCredentials::new(TOKEN_PATH, "my-client", "secret").with_scope("read")
+    }
+
+    #[test]
+    fn test_oauth2_client_credentials() {
+        let state = Arc::new(TokenState::default());
+        let token_state = state.clone();
+        let _token_handle = crate::add_hyper_fixture(TOKEN_PATH, move |req| {
+            let state = token_state.clone();
+            async move {
+                let authorization = req.headers().get(AUTHORIZATION).cloned();
+                if authorization.as_ref().and_then(|v| v.to_str().ok())
+                    != Some("Basic bXktY2xpZW50OnNlY3JldA==")
+                {
+                    return (Response::new(Full::default()), Err(req));
+                }
+                let body = req.into_body().collect().await.unwrap().to_bytes();
+                let mut fields = body.split(|&b| b == b'&');
+                let status = if fields.any(|f| f == b"grant_type=client_credentials") {
+                    StatusCode::OK
+                } else {
+                    StatusCode::BAD_REQUEST
+                };
+                let n = state.issued.fetch_add(1, Ordering::SeqCst) + 1;
+                let token = format!(
+                    r#"{{"access_token":"token-{n}","token_type":"Bearer","expires_in":3600}}"#
+                );
+                let res = Response::builder()
+                    .status(status)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Full::new(Bytes::from(token)))
+                    .unwrap();
+                (res, Ok(()))
+            }
+        });
+        let resource_state = state.clone();
+        let _resource_handle = crate::add_hyper_fixture(RESOURCE_PATH, move |req| {
+            let state = resource_state.clone();
+            async move {
+                let token = req
+                    .headers()
+                    .get(AUTHORIZATION)
+                    .and_then(|v| v.to_str().ok()?.strip_prefix("Bearer "))
+                    .unwrap_or_default()
+                    .to_owned();
+                let valid = token
+                    .strip_prefix("token-")
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .is_some_and(|n| n >= state.min_valid.load(Ordering::SeqCst));
+                let res = Response::builder()
+                    .status(if valid {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::UNAUTHORIZED
+                    })
+                    .body(Full::new(Bytes::from(token)))
+                    .unwrap();
+                (res, Ok(()))
+            }
+        });
+
+        #[cfg(feature = "blocking")]
+        {
+            state.reset();
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder
+                .with_middleware(middleware())
+                .build_blocking()
+                .unwrap();
+            let send = || {
+                let res = client.request(NyquestRequest::get(RESOURCE_PATH)).unwrap();
+                assert_eq!(res.status(), 200);
+                res.text().unwrap()
+            };
+
+            assert_eq!(send(), "token-1");
+            assert_eq!(send(), "token-1");
+            assert_eq!(state.issued.load(Ordering::SeqCst), 1);
+
+            state.min_valid.store(2, Ordering::SeqCst);
+            assert_eq!(send(), "token-2");
+            assert_eq!(send(), "token-2");
+            assert_eq!(state.issued.load(Ordering::SeqCst), 2);
+        }
+
+        #[cfg(feature = "async")]
+        TOKIO_RT.block_on(async {
+            state.reset();
+            let builder = crate::init_builder().await.unwrap();
+            let client = builder
+                .with_middleware(middleware())
+                .build_async()
+                .await
+                .unwrap();
+            let send = || async {
+                let res = client
+                    .request(NyquestRequest::get(RESOURCE_PATH))
+                    .await
+                    .unwrap();
+                assert_eq!(res.status(), 200);
+                res.text().await.unwrap()
+            };
+
+            assert_eq!(send().await, "token-1");
+            assert_eq!(send().await, "token-1");
+            assert_eq!(state.issued.load(Ordering::SeqCst), 1);
+
+            state.min_valid.store(2, Ordering::SeqCst);
+            assert_eq!(send().await, "token-2");
+            assert_eq!(send().await, "token-2");
+            assert_eq!(state.issued.load(Ordering::SeqCst), 2);
+        });
+    }
+}
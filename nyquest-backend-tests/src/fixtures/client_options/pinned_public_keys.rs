@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "curl", feature = "reqwest-rustls"))]
+    use nyquest::Request as NyquestRequest;
+
+    #[cfg(any(feature = "curl", feature = "reqwest-rustls"))]
+    use crate::tls_fixture::{TlsServer, CERTIFICATES};
+    use crate::*;
+
+    const UNKNOWN_PIN: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
+
+    #[cfg(any(feature = "curl", feature = "reqwest-rustls"))] // other backends do not support public key pinning
+    #[test]
+    fn test_pinned_public_keys() {
+        let server = TlsServer::spawn(false).unwrap();
+        let url = server.url();
+        let cases = [
+            (vec![CERTIFICATES.server_public_key_pin()], true),
+            (
+                vec![UNKNOWN_PIN.into(), CERTIFICATES.server_public_key_pin()],
+                true,
+            ),
+            (vec![UNKNOWN_PIN.into()], false),
+        ];
+
+        #[cfg(feature = "blocking")]
+        for (pins, matched) in &cases {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .add_root_certificate(CERTIFICATES.ca_cert_pem());
+            let client = pins
+                .iter()
+                .fold(builder, |builder, pin| builder.pin_public_key(pin))
+                .build_blocking()
+                .unwrap();
+            let res = client.request(NyquestRequest::get(url.clone()));
+            match res {
+                Ok(res) => {
+                    assert!(matched, "unpinned key accepted: {pins:?}");
+                    assert_eq!(res.text().unwrap(), "anonymous");
+                }
+                Err(e) => assert!(!matched && e.is_certificate(), "{pins:?}: {e:?}"),
+            }
+        }
+
+        #[cfg(feature = "async")]
+        for (pins, matched) in cases {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .add_root_certificate(CERTIFICATES.ca_cert_pem());
+                let client = pins
+                    .iter()
+                    .fold(builder, |builder, pin| builder.pin_public_key(pin))
+                    .build_async()
+                    .await
+                    .unwrap();
+                client
+                    .request(NyquestRequest::get(url.clone()))
+                    .await?
+                    .text()
+                    .await
+            });
+            match res {
+                Ok(text) => {
+                    assert!(matched, "unpinned key accepted: {pins:?}");
+                    assert_eq!(text, "anonymous");
+                }
+                Err(e) => assert!(!matched && e.is_certificate(), "{pins:?}: {e:?}"),
+            }
+        }
+    }
+
+    #[cfg(any(feature = "curl", feature = "reqwest-rustls"))]
+    #[test]
+    fn test_pinned_public_keys_without_custom_roots() {
+        // The pin alone does not make the certificate trusted, so the platform roots are used
+        let server = TlsServer::spawn(false).unwrap();
+        let url = server.url();
+        let pin = CERTIFICATES.server_public_key_pin();
+
+        #[cfg(feature = "blocking")]
+        {
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .pin_public_key(&pin)
+                .build_blocking()
+                .unwrap();
+            let err = client
+                .request(NyquestRequest::get(url.clone()))
+                .unwrap_err();
+            assert!(err.is_certificate(), "{err:?}");
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .pin_public_key(&pin)
+                    .build_async()
+                    .await
+                    .unwrap();
+                client.request(NyquestRequest::get(url.clone())).await
+            });
+            let err = res.unwrap_err();
+            assert!(err.is_certificate(), "{err:?}");
+        }
+    }
+
+    #[cfg(not(any(feature = "curl", feature = "reqwest-rustls")))]
+    #[test]
+    fn test_pinned_public_keys_unsupported() {
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            builder
+                .pin_public_key(UNKNOWN_PIN)
+                .build_blocking()
+                .unwrap_err();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.pin_public_key(UNKNOWN_PIN).build_async().await
+            });
+            res.unwrap_err();
+        }
+    }
+}
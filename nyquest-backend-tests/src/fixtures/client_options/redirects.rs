@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use http_body_util::Full;
+    use nyquest::redirect::RedirectPolicy;
     use nyquest::Request as NyquestRequest;
 
     use crate::*;
@@ -84,4 +85,418 @@ mod tests {
             assertions(status, body);
         }
     }
+
+    #[test]
+    fn test_redirects_overridden_per_request() {
+        const PATH: &str = "client_options/redirects_overridden_per_request";
+
+        let _handle = crate::add_hyper_fixture(PATH, redirect_handler);
+
+        let assertions = |results: [(u16, String); 3]| {
+            let [not_followed, followed, not_followed_by_policy] = results;
+            assert_eq!(not_followed, (302, "initial".into()));
+            assert_eq!(followed, (200, "redirected".into()));
+            assert_eq!(not_followed_by_policy, (302, "initial".into()));
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let send = |builder: nyquest::ClientBuilder, follow| {
+                let client = builder.build_blocking().unwrap();
+                let res = client
+                    .request(NyquestRequest::get(PATH).with_redirects(follow))
+                    .unwrap();
+                (res.status().into(), res.text().unwrap())
+            };
+            let builder = || crate::init_builder_blocking().unwrap();
+            assertions([
+                send(builder(), false),
+                send(builder().no_redirects(), true),
+                send(
+                    builder().redirect_policy(RedirectPolicy::default().same_origin_only()),
+                    false,
+                ),
+            ]);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let results = TOKIO_RT.block_on(async {
+                async fn send(builder: nyquest::ClientBuilder, follow: bool) -> (u16, String) {
+                    let client = builder.build_async().await.unwrap();
+                    let res = client
+                        .request(NyquestRequest::get(PATH).with_redirects(follow))
+                        .await
+                        .unwrap();
+                    (res.status().into(), res.text().await.unwrap())
+                }
+                [
+                    send(crate::init_builder().await.unwrap(), false).await,
+                    send(crate::init_builder().await.unwrap().no_redirects(), true).await,
+                    send(
+                        crate::init_builder()
+                            .await
+                            .unwrap()
+                            .redirect_policy(RedirectPolicy::default().same_origin_only()),
+                        false,
+                    )
+                    .await,
+                ]
+            });
+            assertions(results);
+        }
+    }
+
+    async fn redirect_chain_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let res = match req.uri().query().unwrap_or_default() {
+            "" => Response::builder()
+                .header("Location", "?step=1")
+                .status(301)
+                .body(Full::new(Bytes::new()))
+                .unwrap(),
+            "step=1" => Response::builder()
+                .header("Location", "?step=2")
+                .status(302)
+                .body(Full::new(Bytes::new()))
+                .unwrap(),
+            _ => Response::new(Full::new(Bytes::from("done"))),
+        };
+        (res.into(), Ok(()))
+    }
+
+    #[test]
+    fn test_redirect_url_and_history() {
+        const PATH: &str = "client_options/redirect_history";
+        let _handle = crate::add_hyper_fixture(PATH, redirect_chain_handler);
+
+        let assertions = |url: Option<String>, history: Vec<nyquest::redirect::RedirectHop>| {
+            let url = url.expect("effective URL should be known");
+            assert!(
+                url.ends_with("/client_options/redirect_history?step=2"),
+                "unexpected url {url}"
+            );
+            let history: Vec<_> = history
+                .iter()
+                .map(|hop| (u16::from(hop.status()), hop.location().map(str::to_owned)))
+                .collect();
+            let expected = [
+                (301, Some("?step=1".to_string())),
+                (302, Some("?step=2".to_string())),
+            ];
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "curl")] {
+                    assert_eq!(history, expected);
+                } else if #[cfg(feature = "reqwest")] {
+                    // reqwest only reports the URL the Location header resolves to
+                    assert_eq!(history.len(), expected.len(), "{history:?}");
+                    for ((status, location), (expected_status, expected_location)) in
+                        history.iter().zip(&expected)
+                    {
+                        let location = location.as_deref().unwrap();
+                        assert_eq!(status, expected_status);
+                        assert!(location.starts_with("http://"), "{location}");
+                        assert!(location.ends_with(&format!("/{PATH}{}", expected_location.as_deref().unwrap())), "{location}");
+                    }
+                } else {
+                    // Not every backend is able to report intermediate responses.
+                    assert!(history.is_empty() || history == expected);
+                }
+            }
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.url(), res.redirect_history());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (url, history) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (res.url(), res.redirect_history())
+            });
+            assertions(url, history);
+        }
+    }
+
+    async fn endless_redirect_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let step: u32 = req
+            .uri()
+            .query()
+            .and_then(|q| q.strip_prefix("step="))
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default();
+        let res = Response::builder()
+            .header("Location", format!("?step={}", step + 1))
+            .status(302)
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        (res.into(), Ok(()))
+    }
+
+    #[test]
+    fn test_too_many_redirects() {
+        const PATH: &str = "client_options/too_many_redirects";
+        let _handle = crate::add_hyper_fixture(PATH, endless_redirect_handler);
+
+        let assertions = |res: nyquest::Result<()>| {
+            assert!(
+                matches!(res, Err(nyquest::Error::TooManyRedirects)),
+                "unexpected result {res:?}"
+            );
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .redirect_policy(RedirectPolicy::limited(3));
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).map(|_| ());
+            assertions(res);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .redirect_policy(RedirectPolicy::limited(3));
+                let client = builder.build_async().await.unwrap();
+                client.request(NyquestRequest::get(PATH)).await.map(|_| ())
+            });
+            assertions(res);
+        }
+    }
+
+    #[test]
+    fn test_redirect_decider() {
+        const PATH: &str = "client_options/redirect_decider";
+        let _handle = crate::add_hyper_fixture(PATH, endless_redirect_handler);
+
+        let policy = || {
+            RedirectPolicy::limited(10)
+                .same_origin_only()
+                .decide(|attempt| !attempt.location().ends_with("?step=3"))
+        };
+        let assertions = |status: u16, location: Vec<String>, hops: usize| {
+            assert_eq!(status, 302);
+            assert_eq!(location, ["?step=3"]);
+            assert_eq!(hops, 2);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .redirect_policy(policy());
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(
+                res.status().into(),
+                res.get_header("location").unwrap(),
+                res.redirect_history().len(),
+            );
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (status, location, hops) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .redirect_policy(policy());
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (
+                    res.status().into(),
+                    res.get_header("location").unwrap(),
+                    res.redirect_history().len(),
+                )
+            });
+            assertions(status, location, hops);
+        }
+    }
+
+    /// Redirects to the same path on another host name of the loopback interface, and reports
+    /// which sensitive headers are received after the redirect.
+    async fn cross_origin_handler(req: Request<body::Incoming>) -> FixtureAssertionResult {
+        let host = req
+            .headers()
+            .get("host")
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or_default()
+            .to_owned();
+        let res = if req.uri().query() != Some("redirected=1") {
+            let (hostname, port) = host.rsplit_once(':').unwrap_or((&host, "80"));
+            let other = if hostname == "127.0.0.1" {
+                "localhost"
+            } else {
+                "127.0.0.1"
+            };
+            Response::builder()
+                .header(
+                    "Location",
+                    format!("http://{other}:{port}{}?redirected=1", req.uri().path()),
+                )
+                .status(302)
+                .body(Full::new(Bytes::new()))
+                .unwrap()
+        } else {
+            let received: Vec<_> = ["authorization", "cookie", "x-custom"]
+                .into_iter()
+                .filter(|name| req.headers().contains_key(*name))
+                .collect();
+            Response::new(Full::new(Bytes::from(received.join(","))))
+        };
+        (res.into(), Ok(()))
+    }
+
+    #[test]
+    fn test_cross_origin_redirect_strips_credentials() {
+        const PATH: &str = "client_options/cross_origin_redirect";
+        let _handle = crate::add_hyper_fixture(PATH, cross_origin_handler);
+
+        fn request<S>() -> NyquestRequest<S> {
+            NyquestRequest::get(PATH)
+                .with_header("Authorization", "Bearer secret")
+                .with_header("Cookie", "session=secret")
+                .with_header("X-Custom", "1")
+        }
+        // Let nyquest follow redirects by itself as well as the backend.
+        let policies = || [None, Some(RedirectPolicy::default().decide(|_| true))];
+        let assertions = |status: u16, body: String| {
+            assert_eq!(status, 200);
+            assert_eq!(body, "x-custom");
+        };
+
+        #[cfg(feature = "blocking")]
+        for policy in policies() {
+            let mut builder = crate::init_builder_blocking().unwrap().no_cookies();
+            if let Some(policy) = policy {
+                builder = builder.redirect_policy(policy);
+            }
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(request()).unwrap();
+            assertions(res.status().into(), res.text().unwrap());
+        }
+
+        #[cfg(feature = "async")]
+        for policy in policies() {
+            let (status, body) = TOKIO_RT.block_on(async {
+                let mut builder = crate::init_builder().await.unwrap().no_cookies();
+                if let Some(policy) = policy {
+                    builder = builder.redirect_policy(policy);
+                }
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(request()).await.unwrap();
+                (res.status().into(), res.text().await.unwrap())
+            });
+            assertions(status, body);
+        }
+    }
+
+    #[test]
+    fn test_cross_origin_redirect_strips_default_credentials() {
+        const PATH: &str = "client_options/cross_origin_redirect_default_headers";
+        let _handle = crate::add_hyper_fixture(PATH, cross_origin_handler);
+
+        fn configure(builder: ClientBuilder, policy: Option<RedirectPolicy>) -> ClientBuilder {
+            let builder = builder
+                .no_cookies()
+                .with_header("authorization", "Bearer secret")
+                .with_header("cookie", "session=secret")
+                .with_header("x-custom", "1");
+            match policy {
+                Some(policy) => builder.redirect_policy(policy),
+                None => builder,
+            }
+        }
+        // Let nyquest follow redirects by itself as well as the backend.
+        let policies = || [None, Some(RedirectPolicy::default().decide(|_| true))];
+        let assertions = |status: u16, body: String| {
+            assert_eq!(status, 200);
+            assert_eq!(body, "x-custom");
+        };
+
+        #[cfg(feature = "blocking")]
+        for policy in policies() {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = configure(builder, policy).build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.status().into(), res.text().unwrap());
+        }
+
+        #[cfg(feature = "async")]
+        for policy in policies() {
+            let (status, body) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                let client = configure(builder, policy).build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (res.status().into(), res.text().await.unwrap())
+            });
+            assertions(status, body);
+        }
+    }
+
+    #[test]
+    fn test_cross_origin_redirect_strips_auth() {
+        const PATH: &str = "client_options/cross_origin_redirect_auth";
+        let _handle = crate::add_hyper_fixture(PATH, cross_origin_handler);
+
+        // Credentials of both the client and the request, with the backend following other
+        // redirects.
+        fn requests<S>() -> [NyquestRequest<S>; 2] {
+            [
+                NyquestRequest::get(PATH).with_header("X-Custom", "1"),
+                NyquestRequest::get(PATH)
+                    .with_header("X-Custom", "1")
+                    .with_bearer_token("secret"),
+            ]
+        }
+        let assertions = |status: u16, body: String| {
+            assert_eq!(status, 200);
+            assert_eq!(body, "x-custom");
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder
+                .basic_auth("user", "secret")
+                .build_blocking()
+                .unwrap();
+            for req in requests() {
+                let res = client.request(req).unwrap();
+                assertions(res.status().into(), res.text().unwrap());
+            }
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let results = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                let client = builder
+                    .basic_auth("user", "secret")
+                    .build_async()
+                    .await
+                    .unwrap();
+                let mut results = vec![];
+                for req in requests() {
+                    let res = client.request(req).await.unwrap();
+                    results.push((res.status().into(), res.text().await.unwrap()));
+                }
+                results
+            });
+            for (status, body) in results {
+                assertions(status, body);
+            }
+        }
+    }
 }
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use http_body_util::Full;
+    use std::time::Duration;
+
+    use futures::stream;
+    use http_body_util::{BodyExt, Full};
     use nyquest::Error;
     use nyquest::Request as NyquestRequest;
 
@@ -90,4 +93,202 @@ mod tests {
             });
         }
     }
+
+    /// Sends `chunks` chunks of the body with `interval` in between, then stalls for `stall`.
+    fn trickling_handler(
+        chunks: usize,
+        interval: Duration,
+        stall: Duration,
+    ) -> impl Fn(Request<body::Incoming>) -> std::future::Ready<FixtureAssertionResult>
+           + Send
+           + Sync
+           + 'static {
+        move |_req| {
+            let frames = stream::unfold(0, move |i| async move {
+                if i == chunks {
+                    return None;
+                }
+                if i > 0 {
+                    tokio::time::sleep(interval).await;
+                }
+                let frame = hyper::body::Frame::data(Bytes::from(BODY));
+                Some((Ok::<_, hyper::Error>(frame), i + 1))
+            });
+            let stalled = stream::once(async move {
+                tokio::time::sleep(stall).await;
+                Ok(hyper::body::Frame::data(Bytes::new()))
+            });
+            let body = http_body_util::StreamBody::new(futures::StreamExt::chain(frames, stalled));
+            std::future::ready((Response::new(body.boxed()).into(), Ok(())))
+        }
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        const PATH: &str = "client_options/read_timeout";
+
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            trickling_handler(2, Duration::ZERO, Duration::from_secs(30)),
+        );
+
+        #[cfg(feature = "blocking")]
+        {
+            let time_start = std::time::Instant::now();
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .read_timeout(Duration::from_secs(1));
+            let client = builder.build_blocking().unwrap();
+            let err = client
+                .request(NyquestRequest::get(PATH))
+                .and_then(|r| r.text())
+                .unwrap_err();
+            assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let time_start = std::time::Instant::now();
+            TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .read_timeout(Duration::from_secs(1));
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                let err = res.text().await.unwrap_err();
+                assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+            });
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+    }
+
+    #[test]
+    fn test_read_timeout_allows_slow_transfer() {
+        const PATH: &str = "client_options/read_timeout_slow_transfer";
+
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            trickling_handler(6, Duration::from_millis(500), Duration::ZERO),
+        );
+        let expected = BODY.repeat(6);
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .read_timeout(Duration::from_secs(2));
+            let client = builder.build_blocking().unwrap();
+            let res = client
+                .request(NyquestRequest::get(PATH))
+                .unwrap()
+                .text()
+                .unwrap();
+            assert_eq!(res, expected);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .read_timeout(Duration::from_secs(2));
+                let client = builder.build_async().await.unwrap();
+                let res = client
+                    .request(NyquestRequest::get(PATH))
+                    .await
+                    .unwrap()
+                    .text()
+                    .await
+                    .unwrap();
+                assert_eq!(res, expected);
+            });
+        }
+    }
+
+    #[test]
+    fn test_request_timeout_override() {
+        const PATH: &str = "client_options/request_timeout_override";
+
+        let _handle = crate::add_hyper_fixture(PATH, |_| delayed_response_handler(30));
+
+        #[cfg(feature = "blocking")]
+        {
+            let time_start = std::time::Instant::now();
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .request_timeout(Duration::from_secs(60));
+            let client = builder.build_blocking().unwrap();
+            let err = client
+                .request(NyquestRequest::get(PATH).with_timeout(Duration::from_secs(1)))
+                .and_then(|r| r.text())
+                .unwrap_err();
+            assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let time_start = std::time::Instant::now();
+            TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .request_timeout(Duration::from_secs(60));
+                let client = builder.build_async().await.unwrap();
+                let res = client
+                    .request(NyquestRequest::get(PATH).with_timeout(Duration::from_secs(1)))
+                    .await;
+                assert!(matches!(res.unwrap_err(), Error::RequestTimeout));
+            });
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+    }
+    #[cfg(any(feature = "curl", feature = "reqwest"))] // other backends ignore the override
+    #[test]
+    fn test_read_timeout_override() {
+        const PATH: &str = "client_options/read_timeout_override";
+
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            trickling_handler(2, Duration::ZERO, Duration::from_secs(30)),
+        );
+
+        #[cfg(feature = "blocking")]
+        {
+            let time_start = std::time::Instant::now();
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let err = client
+                .request(NyquestRequest::get(PATH).with_read_timeout(Duration::from_secs(1)))
+                .and_then(|r| r.text())
+                .unwrap_err();
+            assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let time_start = std::time::Instant::now();
+            TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .build_async()
+                    .await
+                    .unwrap();
+                let res = client
+                    .request(NyquestRequest::get(PATH).with_read_timeout(Duration::from_secs(1)))
+                    .await
+                    .unwrap();
+                let err = res.text().await.unwrap_err();
+                assert!(matches!(err, Error::RequestTimeout), "{err:?}");
+            });
+            assert!(time_start.elapsed() < Duration::from_secs(10));
+        }
+    }
 }
@@ -90,4 +90,48 @@ mod tests {
             assert!(matches!(res.unwrap_err(), Error::ResponseTooLarge));
         }
     }
+
+    #[test]
+    fn test_response_limit_overridden_per_request() {
+        const PATH: &str = "client_options/response_limit_overridden_per_request";
+
+        let _handle = crate::add_hyper_fixture(PATH, |_| static_response_handler());
+
+        let assertions = |raised: Result<String, Error>, lowered: Result<String, Error>| {
+            assert_eq!(raised.unwrap(), BODY);
+            assert!(matches!(lowered.unwrap_err(), Error::ResponseTooLarge));
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .max_response_buffer_size(9);
+            let client = builder.build_blocking().unwrap();
+            let send = |limit| {
+                client
+                    .request(NyquestRequest::get(PATH).with_max_response_buffer_size(limit))
+                    .and_then(|r| r.text())
+            };
+            assertions(send(10), send(5));
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (raised, lowered) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .max_response_buffer_size(9);
+                let client = builder.build_async().await.unwrap();
+                let send = |limit| {
+                    let req = NyquestRequest::get(PATH).with_max_response_buffer_size(limit);
+                    let client = &client;
+                    async move { client.request(req).await?.text().await }
+                };
+                (send(10).await, send(5).await)
+            });
+            assertions(raised, lowered);
+        }
+    }
 }
@@ -0,0 +1,263 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use http_body_util::Full;
+    use nyquest::client::RetryPolicy;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts).backoff(Duration::from_millis(1), Duration::from_millis(10))
+    }
+
+    /// Fails the first `failures` requests out of every `failures + 1` with the given response.
+    fn flaky_handler(
+        hits: Arc<AtomicUsize>,
+        failures: usize,
+        failure: impl Fn() -> Response<Full<Bytes>> + Send + Sync + 'static,
+    ) -> impl Fn(Request<body::Incoming>) -> std::future::Ready<FixtureAssertionResult>
+           + Send
+           + Sync
+           + 'static {
+        move |_req| {
+            let attempt = hits.fetch_add(1, Ordering::SeqCst);
+            let res = if attempt % (failures + 1) < failures {
+                failure()
+            } else {
+                Response::new(Full::new(Bytes::from("ok")))
+            };
+            std::future::ready((res.into(), Ok(())))
+        }
+    }
+
+    #[test]
+    fn test_retry_on_status_with_retry_after() {
+        const PATH: &str = "client_options/retry/status_retry_after";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            flaky_handler(hits.clone(), 2, || {
+                Response::builder()
+                    .status(503)
+                    .header("Retry-After", "0")
+                    .body(Full::new(Bytes::from("unavailable")))
+                    .unwrap()
+            }),
+        );
+
+        let assertions = |status: u16, body: String| {
+            assert_eq!(status, 200);
+            assert_eq!(body, "ok");
+            assert_eq!(hits.swap(0, Ordering::SeqCst), 3);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .retry_policy(RetryPolicy::new(3));
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.status().into(), res.text().unwrap());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (status, body) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .retry_policy(RetryPolicy::new(3));
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                (res.status().into(), res.text().await.unwrap())
+            });
+            assertions(status, body);
+        }
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_max_attempts() {
+        const PATH: &str = "client_options/retry/max_attempts";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            flaky_handler(hits.clone(), 5, || {
+                Response::builder()
+                    .status(429)
+                    .header("Retry-After", "Thu, 01 Jan 1970 00:00:00 GMT")
+                    .body(Full::new(Bytes::new()))
+                    .unwrap()
+            }),
+        );
+
+        let assertions = |status: u16| {
+            assert_eq!(status, 429);
+            assert_eq!(hits.swap(0, Ordering::SeqCst), 2);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .retry_policy(fast_policy(2));
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.status().into());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let status = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .retry_policy(fast_policy(2));
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                res.status().into()
+            });
+            assertions(status);
+        }
+    }
+
+    #[test]
+    fn test_no_retry_for_non_idempotent_or_overridden() {
+        const PATH: &str = "client_options/retry/non_idempotent";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(
+            PATH,
+            flaky_handler(hits.clone(), usize::MAX - 1, || {
+                Response::builder()
+                    .status(502)
+                    .body(Full::new(Bytes::new()))
+                    .unwrap()
+            }),
+        );
+
+        let assertions = |post_status: u16, get_status: u16| {
+            assert_eq!(post_status, 502);
+            assert_eq!(get_status, 502);
+            assert_eq!(hits.swap(0, Ordering::SeqCst), 2);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .retry_policy(fast_policy(3));
+            let client = builder.build_blocking().unwrap();
+            let post = client
+                .request(
+                    NyquestRequest::post(PATH)
+                        .with_body(nyquest::blocking::Body::plain_text("body")),
+                )
+                .unwrap();
+            let get = client
+                .request(NyquestRequest::get(PATH).with_retry_policy(RetryPolicy::never()))
+                .unwrap();
+            assertions(post.status().into(), get.status().into());
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (post_status, get_status) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .retry_policy(fast_policy(3));
+                let client = builder.build_async().await.unwrap();
+                let post = client
+                    .request(
+                        NyquestRequest::post(PATH)
+                            .with_body(nyquest::r#async::Body::plain_text("body")),
+                    )
+                    .await
+                    .unwrap();
+                let get = client
+                    .request(NyquestRequest::get(PATH).with_retry_policy(RetryPolicy::never()))
+                    .await
+                    .unwrap();
+                (post.status().into(), get.status().into())
+            });
+            assertions(post_status, get_status);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "blocking-stream", feature = "async-stream"))]
+    fn test_retry_replays_sized_stream() {
+        const PATH: &str = "client_options/retry/sized_stream";
+        const CONTENTS: &str = "1234567890";
+        let hits = Arc::new(AtomicUsize::new(0));
+        let _handle = crate::add_hyper_fixture(PATH, {
+            let hits = hits.clone();
+            move |req: Request<body::Incoming>| {
+                let attempt = hits.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    let body = req.into_body().collect().await.unwrap().to_bytes();
+                    let status = if body != CONTENTS.as_bytes() {
+                        400
+                    } else if attempt % 2 == 0 {
+                        503
+                    } else {
+                        200
+                    };
+                    let res = Response::builder()
+                        .status(status)
+                        .body(Full::new(Bytes::new()))
+                        .unwrap();
+                    (res, Ok(()))
+                }
+            }
+        });
+
+        let assertions = |status: u16| {
+            assert_eq!(status, 200);
+            assert_eq!(hits.swap(0, Ordering::SeqCst), 2);
+        };
+
+        #[cfg(feature = "blocking-stream")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .retry_policy(fast_policy(3));
+            let client = builder.build_blocking().unwrap();
+            let body = nyquest::blocking::Body::stream(
+                std::io::Cursor::new(CONTENTS),
+                "text/plain",
+                CONTENTS.len() as u64,
+            );
+            let res = client
+                .request(NyquestRequest::put(PATH).with_body(body))
+                .unwrap();
+            assertions(res.status().into());
+        }
+
+        #[cfg(feature = "async-stream")]
+        {
+            let status = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .retry_policy(fast_policy(3));
+                let client = builder.build_async().await.unwrap();
+                let body = nyquest::r#async::Body::stream(
+                    futures_util::io::Cursor::new(CONTENTS),
+                    "text/plain",
+                    CONTENTS.len() as u64,
+                );
+                let res = client
+                    .request(NyquestRequest::put(PATH).with_body(body))
+                    .await
+                    .unwrap();
+                res.status().into()
+            });
+            assertions(status);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use nyquest::{ClientBuilder, Request as NyquestRequest};
+
+    use crate::tls_fixture::{TlsServer, CERTIFICATES};
+    use crate::*;
+
+    fn assert_trust(configure: impl Fn(ClientBuilder) -> ClientBuilder, trusted: bool) {
+        let server = TlsServer::spawn(false).unwrap();
+        let url = server.url();
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = configure(crate::init_builder_blocking().unwrap());
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(url.clone()));
+            match res {
+                Ok(res) if trusted => assert_eq!(res.text().unwrap(), "anonymous"),
+                Err(_) if !trusted => {}
+                res => panic!("unexpected result: {:?}", res.map(|res| res.status())),
+            }
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = configure(crate::init_builder().await.unwrap());
+                let client = builder.build_async().await.unwrap();
+                client.request(NyquestRequest::get(url)).await?.text().await
+            });
+            match res {
+                Ok(text) if trusted => assert_eq!(text, "anonymous"),
+                Err(_) if !trusted => {}
+                res => panic!("unexpected result: {res:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_root_rejected() {
+        assert_trust(|builder| builder, false);
+    }
+
+    #[test]
+    fn test_custom_root() {
+        assert_trust(
+            |builder| builder.add_root_certificate(CERTIFICATES.ca_cert_pem()),
+            true,
+        );
+    }
+
+    #[test]
+    fn test_only_custom_roots() {
+        assert_trust(
+            |builder| {
+                builder
+                    .add_root_certificate(CERTIFICATES.ca_cert_pem())
+                    .only_custom_roots()
+            },
+            true,
+        );
+        assert_trust(|builder| builder.only_custom_roots(), false);
+    }
+}
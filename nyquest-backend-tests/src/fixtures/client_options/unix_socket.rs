@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use http_body_util::Full;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    #[cfg(all(unix, any(feature = "curl", feature = "reqwest")))] // other backends do not support Unix domain sockets
+    #[test]
+    fn test_unix_socket() {
+        const PATH: &str = "client_options/unix_socket";
+        // Nothing listens on this port, so the request can only reach the socket
+        const URL: &str = "http://localhost.:9/client_options/unix_socket";
+
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            let host = req.headers().get("host").cloned();
+            let res = Response::new(Full::new(Bytes::from(
+                host.as_ref()
+                    .and_then(|h| h.to_str().ok())
+                    .unwrap_or_default()
+                    .to_owned(),
+            )));
+            (res, Ok(()))
+        });
+        let socket = TOKIO_RT
+            .block_on(crate::init_main_service_unix_socket())
+            .unwrap();
+
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking()
+                .unwrap()
+                .unix_socket(socket.clone());
+            let client = builder.build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(URL)).unwrap();
+            assert_eq!(res.text().unwrap(), "localhost.:9");
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let text = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap().unix_socket(socket);
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(URL)).await.unwrap();
+                res.text().await.unwrap()
+            });
+            assert_eq!(text, "localhost.:9");
+        }
+    }
+
+    #[cfg(not(any(feature = "curl", feature = "reqwest")))]
+    #[test]
+    fn test_unix_socket_unsupported() {
+        #[cfg(feature = "blocking")]
+        {
+            let builder = crate::init_builder_blocking().unwrap();
+            builder
+                .unix_socket("/nonexistent.sock")
+                .build_blocking()
+                .unwrap_err();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let res = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                builder.unix_socket("/nonexistent.sock").build_async().await
+            });
+            res.unwrap_err();
+        }
+    }
+}
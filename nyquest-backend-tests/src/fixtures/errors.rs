@@ -1,2 +1,5 @@
+mod connect;
+mod context;
+mod error_body;
 mod invalid_json;
 mod unsuccessful_status;
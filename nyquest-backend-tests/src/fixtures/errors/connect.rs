@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use nyquest::{ErrorKind, Request as NyquestRequest};
+
+    use crate::*;
+
+    /// Returns the URL of a local port that nothing listens on.
+    fn refused_url() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+        format!("http://127.0.0.1:{port}/errors/connection_refused")
+    }
+
+    #[test]
+    fn test_connection_refused() {
+        let url = refused_url();
+        let builder = crate::init_builder_blocking().unwrap().no_proxy();
+
+        let assertions = |err: nyquest::Error| {
+            assert!(err.is_connect(), "expected connect error, got: {err:?}");
+            assert_eq!(err.kind(), Some(ErrorKind::Connect));
+            assert!(!err.is_timeout());
+            assert!(std::error::Error::source(&err).is_some());
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let client = builder.clone().build_blocking().unwrap();
+            let err = client
+                .request(NyquestRequest::get(url.clone()))
+                .unwrap_err();
+            assertions(err);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let err = TOKIO_RT.block_on(async {
+                let client = builder.build_async().await.unwrap();
+                client.request(NyquestRequest::get(url)).await.unwrap_err()
+            });
+            assertions(err);
+        }
+    }
+    #[cfg(feature = "reqwest")] // other backends do not tell proxy connection failures apart
+    #[test]
+    fn test_proxy_refused() {
+        let proxy_url = refused_url();
+        let builder = crate::init_builder_blocking()
+            .unwrap()
+            .custom_proxy(nyquest::client::CustomProxy::http(proxy_url));
+        let url = "http://nyquest-proxy-target.invalid/errors/proxy_refused";
+
+        let assertions = |err: nyquest::Error| {
+            assert!(err.is_proxy(), "expected proxy error, got: {err:?}");
+            assert!(!err.is_dns());
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let client = builder.clone().build_blocking().unwrap();
+            let err = client.request(NyquestRequest::get(url)).unwrap_err();
+            assertions(err);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let err = TOKIO_RT.block_on(async {
+                let client = builder.build_async().await.unwrap();
+                client.request(NyquestRequest::get(url)).await.unwrap_err()
+            });
+            assertions(err);
+        }
+    }
+}
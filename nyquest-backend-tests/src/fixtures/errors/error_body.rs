@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use http_body_util::Full;
+    use hyper::header::CONTENT_TYPE;
+    use hyper::{Method, StatusCode};
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    const PROBLEM: &str = r#"{"title":"Invalid input","status":422}"#;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Problem {
+        title: String,
+        status: u16,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Created {
+        id: u32,
+    }
+
+    #[test]
+    fn test_error_response_body() {
+        const PATH: &str = "errors/error_response_body";
+
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            let res = if req.method() == Method::PUT {
+                Response::builder()
+                    .status(StatusCode::CREATED)
+                    .body(Full::new(Bytes::from(r#"{"id":1}"#)))
+            } else {
+                Response::builder()
+                    .status(StatusCode::UNPROCESSABLE_ENTITY)
+                    .header(CONTENT_TYPE, "application/problem+json")
+                    .body(Full::new(Bytes::from(PROBLEM)))
+            };
+            (res.unwrap(), Ok(()))
+        });
+
+        let builder = crate::init_builder_blocking().unwrap();
+
+        let assertions = |full: nyquest::Error,
+                          truncated: nyquest::Error,
+                          created: Result<Created, Problem>,
+                          problem: Result<Created, Problem>| {
+            let res = full.response().expect("error should keep the response");
+            assert_eq!(res.status(), 422);
+            assert_eq!(full.status(), Some(422.into()));
+            assert_eq!(
+                res.headers().get("content-type"),
+                Some("application/problem+json")
+            );
+            assert!(!res.is_truncated());
+            assert_eq!(
+                res.json::<Problem>().unwrap(),
+                Problem {
+                    title: "Invalid input".into(),
+                    status: 422
+                }
+            );
+            let res = truncated.response().unwrap();
+            assert!(res.is_truncated());
+            assert_eq!(res.text(), &PROBLEM[..10]);
+            assert_eq!(created, Ok(Created { id: 1 }));
+            assert_eq!(problem.unwrap_err().status, 422);
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let client = builder.clone().build_blocking().unwrap();
+            let error_for_status = |limit| {
+                client
+                    .request(NyquestRequest::get(PATH))
+                    .unwrap()
+                    .error_for_status_with_body(limit)
+                    .unwrap_err()
+            };
+            let (full, truncated) = (error_for_status(1024), error_for_status(10));
+            let json_or_error = |req| {
+                client
+                    .request(req)
+                    .unwrap()
+                    .json_or_error::<Created, Problem>()
+                    .unwrap()
+            };
+            let created = json_or_error(NyquestRequest::put(PATH));
+            let problem = json_or_error(NyquestRequest::get(PATH));
+            assertions(full, truncated, created, problem);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let (full, truncated, created, problem) = TOKIO_RT.block_on(async {
+                let client = builder.build_async().await.unwrap();
+                let mut errors = vec![];
+                for limit in [1024, 10] {
+                    let err = client
+                        .request(NyquestRequest::get(PATH))
+                        .await
+                        .unwrap()
+                        .error_for_status_with_body(limit)
+                        .await
+                        .unwrap_err();
+                    errors.push(err);
+                }
+                let mut results = vec![];
+                for req in [NyquestRequest::put(PATH), NyquestRequest::get(PATH)] {
+                    let res = client
+                        .request(req)
+                        .await
+                        .unwrap()
+                        .json_or_error::<Created, Problem>()
+                        .await
+                        .unwrap();
+                    results.push(res);
+                }
+                let truncated = errors.pop().unwrap();
+                let problem = results.pop().unwrap();
+                (
+                    errors.pop().unwrap(),
+                    truncated,
+                    results.pop().unwrap(),
+                    problem,
+                )
+            });
+            assertions(full, truncated, created, problem);
+        }
+    }
+}
@@ -205,6 +205,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_all_headers() {
+        const PATH: &str = "responses/get_all_headers";
+        let _handle = crate::add_hyper_fixture(PATH, |_req| async move {
+            let mut res = Response::<Full<Bytes>>::default();
+            res.headers_mut().append("X-Multi-A", "1".parse().unwrap());
+            res.headers_mut().append("X-Multi-B", "2".parse().unwrap());
+            res.headers_mut().append("X-Multi-A", "3".parse().unwrap());
+            (res, Ok(()))
+        });
+        let builder = crate::init_builder_blocking().unwrap();
+        let assertions = |headers: nyquest::header::HeaderMap| {
+            assert_eq!(headers.get("x-multi-b"), Some("2"));
+            assert!(headers.contains_key("X-MULTI-A"));
+            assert!(!headers.contains_key("x-multi-c"));
+            cfg_if::cfg_if! {
+                if #[cfg(any(feature = "winrt", feature = "nsurlsession"))] {
+                    // See test_get_header for why duplicated headers are merged.
+                    assert_eq!(headers.get_all("x-multi-a").collect::<Vec<_>>(), ["1, 3"]);
+                } else {
+                    assert_eq!(headers.get_all("x-multi-a").collect::<Vec<_>>(), ["1", "3"]);
+                    let multi: Vec<_> = headers
+                        .iter()
+                        .filter(|(k, _)| k.to_ascii_lowercase().starts_with("x-multi-"))
+                        .map(|(k, v)| (k.to_ascii_lowercase(), v))
+                        .collect();
+                    // hyper writes all values of a header name together.
+                    assert_eq!(
+                        multi,
+                        [
+                            ("x-multi-a".to_string(), "1"),
+                            ("x-multi-a".to_string(), "3"),
+                            ("x-multi-b".to_string(), "2"),
+                        ]
+                    );
+                }
+            }
+        };
+        #[cfg(feature = "blocking")]
+        {
+            let client = builder.clone().build_blocking().unwrap();
+            let res = client.request(NyquestRequest::get(PATH)).unwrap();
+            assertions(res.headers().unwrap());
+        }
+        #[cfg(feature = "async")]
+        {
+            let headers = TOKIO_RT.block_on(async {
+                let client = builder.build_async().await.unwrap();
+                let res = client.request(NyquestRequest::get(PATH)).await.unwrap();
+                res.headers().unwrap()
+            });
+            assertions(headers);
+        }
+    }
+
     #[test]
     #[cfg(any(feature = "blocking-stream", feature = "async-stream"))]
     fn test_stream_download() {
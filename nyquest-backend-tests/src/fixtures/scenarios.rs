@@ -1,3 +1,7 @@
+mod cancellation;
 mod chunked_encoding;
+mod download;
 mod early_drop;
+mod partial_content;
+mod progress;
 mod request_header_override;
@@ -0,0 +1,132 @@
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use futures::stream;
+    use http_body_util::BodyExt;
+    use hyper::Response;
+    use nyquest::{CancellationToken, Request as NyquestRequest};
+
+    use crate::hyper_fixture_collection::{HyperFixtureCollection, HyperFixtureHandle};
+    use crate::*;
+
+    const STALL: Duration = Duration::from_secs(5);
+    const CANCEL_AFTER: Duration = Duration::from_millis(200);
+
+    fn add_fixture(path: &'static str) -> HyperFixtureHandle<&'static HyperFixtureCollection> {
+        crate::add_hyper_fixture(path, |req| async move {
+            let stall_headers = req.uri().query() == Some("stall=headers");
+            if stall_headers {
+                tokio::time::sleep(STALL).await;
+            }
+            let first = stream::iter([Ok::<_, hyper::Error>(hyper::body::Frame::data(
+                Bytes::from_static(b"first"),
+            ))]);
+            let stalled = stream::once(async {
+                tokio::time::sleep(STALL).await;
+                Ok(hyper::body::Frame::data(Bytes::from_static(b"second")))
+            });
+            let body =
+                http_body_util::StreamBody::new(futures::StreamExt::chain(first, stalled)).boxed();
+            (Response::new(body), Ok(()))
+        })
+    }
+
+    fn cancel_later(token: &CancellationToken) -> std::thread::JoinHandle<()> {
+        let token = token.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(CANCEL_AFTER);
+            token.cancel();
+        })
+    }
+
+    #[test]
+    fn test_cancel_before_response() {
+        const PATH: &str = "scenarios/cancellation/before_response";
+        let _handle = add_fixture(PATH);
+
+        let client = crate::init_builder_blocking()
+            .unwrap()
+            .build_blocking()
+            .unwrap();
+        let token = CancellationToken::new();
+        let canceller = cancel_later(&token);
+        let start = Instant::now();
+        let err = client
+            .request(NyquestRequest::get(format!("{PATH}?stall=headers")).with_cancellation(&token))
+            .unwrap_err();
+        canceller.join().unwrap();
+        assert!(err.is_cancelled(), "{err:?}");
+        assert!(start.elapsed() < STALL, "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn test_cancel_while_reading_body() {
+        const PATH: &str = "scenarios/cancellation/reading_body";
+        let _handle = add_fixture(PATH);
+
+        let client = crate::init_builder_blocking()
+            .unwrap()
+            .build_blocking()
+            .unwrap();
+        let token = CancellationToken::new();
+        let res = client
+            .request(NyquestRequest::get(PATH).with_cancellation(&token))
+            .unwrap();
+        let canceller = cancel_later(&token);
+        let start = Instant::now();
+        let err = res.bytes().unwrap_err();
+        canceller.join().unwrap();
+        assert!(err.is_cancelled(), "{err:?}");
+        assert!(start.elapsed() < STALL, "{:?}", start.elapsed());
+    }
+
+    #[test]
+    #[cfg(feature = "blocking-stream")]
+    fn test_cancel_while_streaming_body() {
+        use std::io::Read;
+
+        const PATH: &str = "scenarios/cancellation/streaming_body";
+        let _handle = add_fixture(PATH);
+
+        let client = crate::init_builder_blocking()
+            .unwrap()
+            .build_blocking()
+            .unwrap();
+        let token = CancellationToken::new();
+        let res = client
+            .request(NyquestRequest::get(PATH).with_cancellation(&token))
+            .unwrap();
+        let mut stream = res.into_read();
+        let mut buf = [0; 5];
+        stream.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"first");
+
+        let canceller = cancel_later(&token);
+        let start = Instant::now();
+        let err = stream.read(&mut buf).unwrap_err();
+        canceller.join().unwrap();
+        let inner = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<nyquest::Error>());
+        assert!(inner.is_some_and(|e| e.is_cancelled()), "{err:?}");
+        assert!(start.elapsed() < STALL, "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn test_cancelled_before_sending() {
+        const PATH: &str = "scenarios/cancellation/before_sending";
+        let _handle = add_fixture(PATH);
+
+        let client = crate::init_builder_blocking()
+            .unwrap()
+            .build_blocking()
+            .unwrap();
+        let token = CancellationToken::new();
+        token.cancel();
+        let err = client
+            .request(NyquestRequest::get(PATH).with_cancellation(&token))
+            .unwrap_err();
+        assert!(err.is_cancelled(), "{err:?}");
+    }
+}
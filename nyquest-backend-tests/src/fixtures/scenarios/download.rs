@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use http_body_util::Full;
+    use hyper::Response;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::*;
+
+    const BODY_SIZE: usize = 256 * 1024;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("nyquest-download-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn dir_entries(dir: &Path) -> Vec<String> {
+        let mut entries: Vec<_> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn test_download_to() {
+        const PATH: &str = "scenarios/download";
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            let res = match req.uri().query() {
+                Some("missing") => Response::builder()
+                    .status(404)
+                    .body(Full::new(Bytes::from_static(b"not found")))
+                    .unwrap(),
+                _ => Response::builder()
+                    .header("content-type", "application/octet-stream")
+                    .body(Full::new(Bytes::from(vec![b'x'; BODY_SIZE])))
+                    .unwrap(),
+            };
+            (res, Ok(()))
+        });
+
+        let assertions = |dir: &Path,
+                          download: nyquest::Result<nyquest::Download>,
+                          too_large: nyquest::Result<nyquest::Download>,
+                          missing: nyquest::Result<nyquest::Download>| {
+            let download = download.unwrap();
+            assert_eq!(download.status(), 200);
+            assert_eq!(download.size(), BODY_SIZE as u64);
+            assert_eq!(download.content_type(), Some("application/octet-stream"));
+            let contents = std::fs::read(dir.join("file.bin")).unwrap();
+            assert_eq!(contents, vec![b'x'; BODY_SIZE]);
+
+            assert!(matches!(too_large, Err(nyquest::Error::ResponseTooLarge)));
+            assert!(missing.unwrap_err().is_status());
+            // Neither the failed downloads nor temporary files are left behind
+            assert_eq!(dir_entries(dir), ["file.bin", "old.bin"]);
+            assert_eq!(std::fs::read(dir.join("old.bin")).unwrap(), b"old");
+            std::fs::remove_dir_all(dir).unwrap();
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let dir = temp_dir("blocking");
+            std::fs::write(dir.join("old.bin"), "old").unwrap();
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let download = client.download_to(NyquestRequest::get(PATH), dir.join("file.bin"));
+            let limited = crate::init_builder_blocking()
+                .unwrap()
+                .max_download_size(1024)
+                .build_blocking()
+                .unwrap();
+            let too_large = limited.download_to(NyquestRequest::get(PATH), dir.join("old.bin"));
+            let missing = client.download_to(
+                NyquestRequest::get(format!("{PATH}?missing")),
+                dir.join("missing.bin"),
+            );
+            assertions(&dir, download, too_large, missing);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let dir = temp_dir("async");
+            std::fs::write(dir.join("old.bin"), "old").unwrap();
+            let (download, too_large, missing) = TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .build_async()
+                    .await
+                    .unwrap();
+                let download = client
+                    .download_to(NyquestRequest::get(PATH), dir.join("file.bin"))
+                    .await;
+                let limited = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .max_download_size(1024)
+                    .build_async()
+                    .await
+                    .unwrap();
+                let too_large = limited
+                    .download_to(NyquestRequest::get(PATH), dir.join("old.bin"))
+                    .await;
+                let missing = client
+                    .download_to(
+                        NyquestRequest::get(format!("{PATH}?missing")),
+                        dir.join("missing.bin"),
+                    )
+                    .await;
+                (download, too_large, missing)
+            });
+            assertions(&dir, download, too_large, missing);
+        }
+    }
+}
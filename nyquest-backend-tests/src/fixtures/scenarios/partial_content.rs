@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use http_body_util::Full;
+    use hyper::Response;
+    use nyquest::Request as NyquestRequest;
+
+    use crate::hyper_fixture_collection::{HyperFixtureCollection, HyperFixtureHandle};
+    use crate::*;
+
+    const ETAG: &str = "\"v1\"";
+
+    fn contents() -> Vec<u8> {
+        (0..64 * 1024).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "nyquest-partial-content-{}-{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_partial(dir: &Path, name: &str, body: &[u8], validator: &str) {
+        std::fs::write(dir.join(format!("{name}.part")), body).unwrap();
+        std::fs::write(dir.join(format!("{name}.part.meta")), validator).unwrap();
+    }
+
+    /// Serves `contents()` with support for `Range` requests validated by `If-Range`.
+    ///
+    /// With the `bad-range` query, partial responses start at the wrong position.
+    fn add_fixture(path: &'static str) -> HyperFixtureHandle<&'static HyperFixtureCollection> {
+        crate::add_hyper_fixture(path, |req| async move {
+            let contents = contents();
+            let header = |name| {
+                req.headers()
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_owned)
+            };
+            let start = header("range")
+                .filter(|_| header("if-range").as_deref() == Some(ETAG))
+                .and_then(|range| {
+                    range
+                        .strip_prefix("bytes=")?
+                        .strip_suffix('-')?
+                        .parse()
+                        .ok()
+                });
+            let res = Response::builder().header("etag", ETAG);
+            let res = match start {
+                Some(start) => {
+                    let start = if req.uri().query() == Some("bad-range") {
+                        0
+                    } else {
+                        start
+                    };
+                    res.status(206)
+                        .header(
+                            "content-range",
+                            format!("bytes {start}-{}/{}", contents.len() - 1, contents.len()),
+                        )
+                        .body(Full::new(Bytes::copy_from_slice(&contents[start..])))
+                }
+                None => res.body(Full::new(Bytes::from(contents))),
+            };
+            (res.unwrap(), Ok(()))
+        })
+    }
+
+    fn assert_complete(dir: &Path, name: &str, download: nyquest::Download, status: u16) {
+        assert_eq!(download.status(), status);
+        assert_eq!(download.size(), contents().len() as u64);
+        assert_eq!(std::fs::read(dir.join(name)).unwrap(), contents());
+        assert!(!dir.join(format!("{name}.part")).exists());
+        assert!(!dir.join(format!("{name}.part.meta")).exists());
+    }
+
+    #[test]
+    fn test_resume_download() {
+        const PATH: &str = "scenarios/partial_content/resume";
+        let _handle = add_fixture(PATH);
+        let prefix = &contents()[..1000];
+
+        #[cfg(feature = "blocking")]
+        {
+            let dir = temp_dir("resume-blocking");
+            write_partial(&dir, "resumed.bin", prefix, ETAG);
+            write_partial(&dir, "stale.bin", b"stale", "\"v0\"");
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let resumed = client
+                .resume_download_to(NyquestRequest::get(PATH), dir.join("resumed.bin"))
+                .unwrap();
+            assert_complete(&dir, "resumed.bin", resumed, 206);
+            let stale = client
+                .resume_download_to(NyquestRequest::get(PATH), dir.join("stale.bin"))
+                .unwrap();
+            assert_complete(&dir, "stale.bin", stale, 200);
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let dir = temp_dir("resume-async");
+            write_partial(&dir, "resumed.bin", prefix, ETAG);
+            write_partial(&dir, "stale.bin", b"stale", "\"v0\"");
+            let (resumed, stale) = TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .build_async()
+                    .await
+                    .unwrap();
+                let resumed = client
+                    .resume_download_to(NyquestRequest::get(PATH), dir.join("resumed.bin"))
+                    .await
+                    .unwrap();
+                let stale = client
+                    .resume_download_to(NyquestRequest::get(PATH), dir.join("stale.bin"))
+                    .await
+                    .unwrap();
+                (resumed, stale)
+            });
+            assert_complete(&dir, "resumed.bin", resumed, 206);
+            assert_complete(&dir, "stale.bin", stale, 200);
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_resume_after_interruption() {
+        const PATH: &str = "scenarios/partial_content/interrupted";
+        let _handle = add_fixture(PATH);
+
+        let assertions = |dir: &Path, interrupted: nyquest::Result<nyquest::Download>| {
+            assert!(matches!(interrupted, Err(nyquest::Error::ResponseTooLarge)));
+            let partial = std::fs::read(dir.join("file.bin.part")).unwrap();
+            assert!(partial.len() <= 16 * 1024);
+            assert!(contents().starts_with(&partial));
+            let meta = std::fs::read_to_string(dir.join("file.bin.part.meta")).unwrap();
+            assert_eq!(meta, ETAG);
+            assert!(!dir.join("file.bin").exists());
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let dir = temp_dir("interrupted-blocking");
+            let limited = crate::init_builder_blocking()
+                .unwrap()
+                .max_download_size(16 * 1024)
+                .build_blocking()
+                .unwrap();
+            let interrupted =
+                limited.resume_download_to(NyquestRequest::get(PATH), dir.join("file.bin"));
+            assertions(&dir, interrupted);
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let download = client
+                .resume_download_to(NyquestRequest::get(PATH), dir.join("file.bin"))
+                .unwrap();
+            assert_complete(&dir, "file.bin", download.clone(), download.status().into());
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let dir = temp_dir("interrupted-async");
+            let download = TOKIO_RT.block_on(async {
+                let limited = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .max_download_size(16 * 1024)
+                    .build_async()
+                    .await
+                    .unwrap();
+                let interrupted = limited
+                    .resume_download_to(NyquestRequest::get(PATH), dir.join("file.bin"))
+                    .await;
+                assertions(&dir, interrupted);
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .build_async()
+                    .await
+                    .unwrap();
+                client
+                    .resume_download_to(NyquestRequest::get(PATH), dir.join("file.bin"))
+                    .await
+                    .unwrap()
+            });
+            assert_complete(&dir, "file.bin", download.clone(), download.status().into());
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_resume_with_mismatched_content_range() {
+        const PATH: &str = "scenarios/partial_content/bad_range";
+        let _handle = add_fixture(PATH);
+        let url = format!("{PATH}?bad-range");
+        let prefix = &contents()[..1000];
+
+        #[cfg(feature = "blocking")]
+        {
+            let dir = temp_dir("bad-range-blocking");
+            write_partial(&dir, "file.bin", prefix, ETAG);
+            let client = crate::init_builder_blocking()
+                .unwrap()
+                .build_blocking()
+                .unwrap();
+            let download = client
+                .resume_download_to(NyquestRequest::get(url.clone()), dir.join("file.bin"))
+                .unwrap();
+            assert_complete(&dir, "file.bin", download, 200);
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let dir = temp_dir("bad-range-async");
+            write_partial(&dir, "file.bin", prefix, ETAG);
+            let download = TOKIO_RT.block_on(async {
+                let client = crate::init_builder()
+                    .await
+                    .unwrap()
+                    .build_async()
+                    .await
+                    .unwrap();
+                client
+                    .resume_download_to(NyquestRequest::get(url.clone()), dir.join("file.bin"))
+                    .await
+                    .unwrap()
+            });
+            assert_complete(&dir, "file.bin", download, 200);
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
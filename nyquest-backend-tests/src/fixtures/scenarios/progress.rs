@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use http_body_util::{BodyExt, Full};
+    use hyper::Response;
+    use nyquest::{Progress, Request as NyquestRequest};
+
+    use crate::*;
+
+    const UPLOAD_SIZE: usize = 32 * 1024;
+    const DOWNLOAD_SIZE: usize = 64 * 1024;
+
+    fn track<S>(req: NyquestRequest<S>, reports: &Arc<Mutex<Vec<Progress>>>) -> NyquestRequest<S> {
+        let reports = reports.clone();
+        req.with_progress(move |progress| reports.lock().unwrap().push(progress))
+    }
+
+    #[test]
+    fn test_progress() {
+        const PATH: &str = "scenarios/progress";
+
+        let _handle = crate::add_hyper_fixture(PATH, |req| async move {
+            let body = req.into_body().collect().await.unwrap().to_bytes();
+            let status = if body.len() == UPLOAD_SIZE { 200 } else { 400 };
+            let res = Response::builder()
+                .status(status)
+                .body(Full::new(Bytes::from(vec![b'a'; DOWNLOAD_SIZE])))
+                .unwrap();
+            (res, Ok(()))
+        });
+
+        let assertions = |status: u16, len: usize, reports: &Arc<Mutex<Vec<Progress>>>| {
+            let reports = std::mem::take(&mut *reports.lock().unwrap());
+            assert_eq!(status, 200);
+            assert_eq!(len, DOWNLOAD_SIZE);
+            assert!(!reports.is_empty());
+            let max_uploaded = reports.iter().map(|p| p.uploaded()).max();
+            let max_downloaded = reports.iter().map(|p| p.downloaded()).max();
+            assert_eq!(max_uploaded, Some(UPLOAD_SIZE as u64));
+            assert_eq!(max_downloaded, Some(DOWNLOAD_SIZE as u64));
+            let last = reports.last().unwrap();
+            assert_eq!(last.download_total(), Some(DOWNLOAD_SIZE as u64));
+            assert!(reports
+                .windows(2)
+                .all(|w| w[0].downloaded() <= w[1].downloaded()));
+        };
+
+        #[cfg(feature = "blocking")]
+        {
+            let reports = Default::default();
+            let builder = crate::init_builder_blocking().unwrap();
+            let client = builder.build_blocking().unwrap();
+            let req = NyquestRequest::post(PATH).with_body(nyquest::blocking::Body::binary_bytes(
+                vec![b'b'; UPLOAD_SIZE],
+            ));
+            let res = client.request(track(req, &reports)).unwrap();
+            let status = res.status().into();
+            let len = res.bytes().unwrap().len();
+            assertions(status, len, &reports);
+        }
+
+        #[cfg(feature = "async")]
+        {
+            let reports = Default::default();
+            let (status, len) = TOKIO_RT.block_on(async {
+                let builder = crate::init_builder().await.unwrap();
+                let client = builder.build_async().await.unwrap();
+                let req = NyquestRequest::post(PATH).with_body(
+                    nyquest::r#async::Body::binary_bytes(vec![b'b'; UPLOAD_SIZE]),
+                );
+                let res = client.request(track(req, &reports)).await.unwrap();
+                let status = res.status().into();
+                (status, res.bytes().await.unwrap().len())
+            });
+            assertions(status, len, &reports);
+        }
+    }
+}
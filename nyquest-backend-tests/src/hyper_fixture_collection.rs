@@ -147,24 +147,53 @@ pub(crate) async fn spawn_service(
     tokio::spawn(async move {
         loop {
             let (stream, _) = listener.accept().await.expect("accept failed");
-            let io = TokioIo::new(stream);
-            let collection = collection.clone();
-
-            tokio::task::spawn(async move {
-                if let Err(err) = http1::Builder::new()
-                    // `service_fn` converts our function in a `Service`
-                    .serve_connection(
-                        io,
-                        service_fn(move |req| handle_service(collection.clone(), req)),
-                    )
-                    .with_upgrades()
-                    .await
-                {
-                    eprintln!("Error serving connection: {err:?}");
-                }
-            });
+            serve_connection(stream, collection.clone());
         }
     });
 
     Ok(port)
 }
+
+/// Serves the fixtures over a Unix domain socket bound at `path`.
+#[cfg(unix)]
+#[cfg_attr(not(any(feature = "curl", feature = "reqwest")), allow(dead_code))]
+pub(crate) async fn spawn_unix_service(
+    collection: impl Deref<Target = HyperFixtureCollection> + Clone + Send + 'static,
+    path: &std::path::Path,
+) -> Result<(), io::Error> {
+    // A stale socket file from a previous run prevents binding
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    let listener = tokio::net::UnixListener::bind(path)?;
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.expect("accept failed");
+            serve_connection(stream, collection.clone());
+        }
+    });
+
+    Ok(())
+}
+
+fn serve_connection(
+    stream: impl tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
+    collection: impl Deref<Target = HyperFixtureCollection> + Clone + Send + 'static,
+) {
+    let io = TokioIo::new(stream);
+    tokio::task::spawn(async move {
+        if let Err(err) = http1::Builder::new()
+            // `service_fn` converts our function in a `Service`
+            .serve_connection(
+                io,
+                service_fn(move |req| handle_service(collection.clone(), req)),
+            )
+            .with_upgrades()
+            .await
+        {
+            eprintln!("Error serving connection: {err:?}");
+        }
+    });
+}
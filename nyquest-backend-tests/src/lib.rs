@@ -17,6 +17,8 @@ use tokio::sync::OnceCell;
 mod fixtures;
 mod hyper_fixture_collection;
 mod request_ext;
+#[cfg(any(feature = "curl", feature = "reqwest"))]
+mod tls_fixture;
 
 use hyper_fixture_collection::FixtureAssertionResult;
 pub use request_ext::RequestExt;
@@ -47,6 +49,26 @@ async fn init_main_service_port() -> io::Result<u16> {
     }
 }
 
+/// Returns the path of a Unix domain socket serving the same fixtures as the main service.
+#[cfg(unix)]
+#[cfg_attr(not(any(feature = "curl", feature = "reqwest")), allow(dead_code))]
+async fn init_main_service_unix_socket() -> io::Result<std::path::PathBuf> {
+    static HYPER_SERVICE_INIT: OnceCell<io::Result<std::path::PathBuf>> = OnceCell::const_new();
+    match HYPER_SERVICE_INIT
+        .get_or_init(|| async {
+            let path = std::env::temp_dir()
+                .join(format!("nyquest-backend-tests-{}.sock", std::process::id()));
+            hyper_fixture_collection::spawn_unix_service(&MAIN_HYPER_FIXTURE_COLLECTION, &path)
+                .await?;
+            Ok(path)
+        })
+        .await
+    {
+        Ok(path) => Ok(path.clone()),
+        Err(err) => Err(io::Error::new(err.kind(), err.to_string())),
+    }
+}
+
 async fn init_builder() -> io::Result<ClientBuilder> {
     static BACKEND_INIT: Once = Once::new();
     BACKEND_INIT.call_once(init_backend);
@@ -101,6 +123,9 @@ macro_rules! declare_backends {
 }
 
 fn init_backend() {
+    // rustls is used by reqwest without a crypto provider of its own
+    #[cfg(feature = "reqwest-rustls")]
+    let _ = rustls::crypto::ring::default_provider().install_default();
     declare_backends!(
         ("curl", nyquest_backend_curl),
         ("nsurlsession", nyquest_backend_nsurlsession),
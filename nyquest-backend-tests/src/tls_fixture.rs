@@ -0,0 +1,215 @@
+//! A minimal HTTPS server with certificates generated on the fly, for TLS related fixtures.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use openssl::asn1::Asn1Time;
+use openssl::bn::{BigNum, MsbOption};
+use openssl::ec::{EcGroup, EcKey};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::{PKey, Private};
+use openssl::ssl::{SslAcceptor, SslMethod, SslStream, SslVerifyMode};
+use openssl::x509::extension::{BasicConstraints, SubjectAlternativeName};
+use openssl::x509::{X509Name, X509};
+
+pub(crate) const CLIENT_COMMON_NAME: &str = "nyquest-client";
+pub(crate) const PKCS12_PASSWORD: &str = "nyquest";
+
+/// Certificates of a private CA, a server certificate for `127.0.0.1` and a client certificate.
+pub(crate) struct TlsCertificates {
+    ca_cert: X509,
+    server_cert: X509,
+    server_key: PKey<Private>,
+    client_cert: X509,
+    client_key: PKey<Private>,
+}
+
+impl TlsCertificates {
+    pub(crate) fn ca_cert_pem(&self) -> Vec<u8> {
+        self.ca_cert.to_pem().unwrap()
+    }
+
+    #[cfg_attr(
+        not(any(feature = "curl", feature = "reqwest-rustls")),
+        allow(dead_code)
+    )]
+    pub(crate) fn server_public_key_pin(&self) -> String {
+        public_key_pin(&self.server_cert)
+    }
+
+    pub(crate) fn client_cert_pem(&self) -> Vec<u8> {
+        self.client_cert.to_pem().unwrap()
+    }
+
+    pub(crate) fn client_key_pem(&self) -> Vec<u8> {
+        self.client_key.private_key_to_pem_pkcs8().unwrap()
+    }
+
+    pub(crate) fn client_pkcs12_der(&self) -> Vec<u8> {
+        Pkcs12::builder()
+            .name(CLIENT_COMMON_NAME)
+            .pkey(&self.client_key)
+            .cert(&self.client_cert)
+            .build2(PKCS12_PASSWORD)
+            .unwrap()
+            .to_der()
+            .unwrap()
+    }
+}
+
+pub(crate) static CERTIFICATES: LazyLock<TlsCertificates> = LazyLock::new(|| {
+    let (ca_cert, ca_key) = issue_cert("nyquest-test-ca", None, true);
+    let (server_cert, server_key) = issue_cert("127.0.0.1", Some((&ca_cert, &ca_key)), false);
+    let (client_cert, client_key) =
+        issue_cert(CLIENT_COMMON_NAME, Some((&ca_cert, &ca_key)), false);
+    TlsCertificates {
+        ca_cert,
+        server_cert,
+        server_key,
+        client_cert,
+        client_key,
+    }
+});
+
+/// Returns the base64 encoded SHA-256 hash of the SubjectPublicKeyInfo of `cert`.
+#[cfg_attr(
+    not(any(feature = "curl", feature = "reqwest-rustls")),
+    allow(dead_code)
+)]
+fn public_key_pin(cert: &X509) -> String {
+    let spki = cert.public_key().unwrap().public_key_to_der().unwrap();
+    openssl::base64::encode_block(&openssl::sha::sha256(&spki))
+}
+
+fn issue_cert(
+    common_name: &str,
+    issuer: Option<(&X509, &PKey<Private>)>,
+    is_ca: bool,
+) -> (X509, PKey<Private>) {
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+    let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+    let mut name = X509Name::builder().unwrap();
+    name.append_entry_by_nid(Nid::COMMONNAME, common_name)
+        .unwrap();
+    let name = name.build();
+
+    let mut builder = X509::builder().unwrap();
+    builder.set_version(2).unwrap();
+    let mut serial = BigNum::new().unwrap();
+    serial.rand(64, MsbOption::MAYBE_ZERO, false).unwrap();
+    builder
+        .set_serial_number(&serial.to_asn1_integer().unwrap())
+        .unwrap();
+    builder.set_subject_name(&name).unwrap();
+    builder
+        .set_issuer_name(issuer.map_or(&*name, |(cert, _)| cert.subject_name()))
+        .unwrap();
+    builder.set_pubkey(&key).unwrap();
+    builder
+        .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+        .unwrap();
+    builder
+        .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+        .unwrap();
+    if is_ca {
+        let constraints = BasicConstraints::new().critical().ca().build().unwrap();
+        builder.append_extension(constraints).unwrap();
+    } else {
+        let san = SubjectAlternativeName::new()
+            .ip("127.0.0.1")
+            .build(&builder.x509v3_context(issuer.map(|(cert, _)| &**cert), None))
+            .unwrap();
+        builder.append_extension(san).unwrap();
+    }
+    builder
+        .sign(issuer.map_or(&key, |(_, key)| key), MessageDigest::sha256())
+        .unwrap();
+    (builder.build(), key)
+}
+
+/// An HTTPS server on `127.0.0.1` responding to every request with the common name of the client
+/// certificate, or `anonymous` without one.
+pub(crate) struct TlsServer {
+    port: u16,
+    stopped: Arc<AtomicBool>,
+}
+
+impl TlsServer {
+    pub(crate) fn spawn(require_client_cert: bool) -> io::Result<Self> {
+        let certs = &*CERTIFICATES;
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls_server())?;
+        acceptor.set_private_key(&certs.server_key)?;
+        acceptor.set_certificate(&certs.server_cert)?;
+        acceptor.cert_store_mut().add_cert(certs.ca_cert.clone())?;
+        if require_client_cert {
+            acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        }
+        let acceptor = Arc::new(acceptor.build());
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+        let stopped = Arc::new(AtomicBool::new(false));
+        std::thread::spawn({
+            let stopped = stopped.clone();
+            move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let Ok(stream) = stream else {
+                        continue;
+                    };
+                    let acceptor = acceptor.clone();
+                    std::thread::spawn(move || {
+                        // Handshake failures are expected when testing rejected connections
+                        if let Ok(stream) = acceptor.accept(stream) {
+                            let _ = serve(stream);
+                        }
+                    });
+                }
+            }
+        });
+        Ok(Self { port, stopped })
+    }
+
+    pub(crate) fn url(&self) -> String {
+        format!("https://127.0.0.1:{}/", self.port)
+    }
+}
+
+impl Drop for TlsServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake up the accepting thread so that it notices the flag
+        let _ = TcpStream::connect(("127.0.0.1", self.port));
+    }
+}
+
+fn serve(stream: SslStream<TcpStream>) -> io::Result<()> {
+    let body = stream
+        .ssl()
+        .peer_certificate()
+        .and_then(|cert| {
+            let entry = cert.subject_name().entries_by_nid(Nid::COMMONNAME).next()?;
+            Some(entry.data().as_utf8().ok()?.to_string())
+        })
+        .unwrap_or_else(|| "anonymous".into());
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+    let mut stream = reader.into_inner();
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    let _ = stream.shutdown();
+    stream.get_ref().shutdown(Shutdown::Both)
+}
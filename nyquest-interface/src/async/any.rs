@@ -9,6 +9,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::fs::File;
 use std::pin::Pin;
 use std::sync::Arc;
 
@@ -17,7 +18,7 @@ use futures_core::future::BoxFuture;
 use super::backend::AsyncResponse;
 use super::Request;
 use crate::client::ClientOptions;
-use crate::Result;
+use crate::{ProgressCallback, Result};
 
 /// Trait for type-erased async backend implementations.
 ///
@@ -38,6 +39,16 @@ pub trait AnyAsyncClient: Any + Send + Sync + 'static {
     fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
     /// Sends an HTTP request and returns the response.
     fn request(&self, req: Request) -> BoxFuture<'_, Result<Pin<Box<dyn AnyAsyncResponse>>>>;
+    /// Sends an HTTP request, reporting the transfer progress to the given callback.
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: ProgressCallback,
+    ) -> BoxFuture<'_, Result<Pin<Box<dyn AnyAsyncResponse>>>>;
+    /// Returns a handle to the cookie jar of this client, if exposed by the backend.
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>>;
+    /// Saves the cookies to the cookie file of this client, if any.
+    fn flush_cookies(&self) -> Result<()>;
 }
 
 /// Trait for type-erased async HTTP responses.
@@ -52,10 +63,24 @@ pub trait AnyAsyncResponse: super::MaybeAsyncRead + Any + Send + Sync + 'static
     fn content_length(&self) -> Option<u64>;
     /// Gets all values for the specified header.
     fn get_header(&self, header: &str) -> Result<Vec<String>>;
+    /// Gets all headers as name-value pairs, in the order they were received.
+    fn headers(&self) -> Result<Vec<(String, String)>>;
+    /// Gets the effective URL of the response after following redirects, if known by the backend.
+    fn url(&self) -> Option<String>;
+    /// Gets the HTTP version of this response, if known by the backend.
+    fn version(&self) -> Option<crate::HttpVersion>;
+    /// Gets the redirects followed before receiving this response, in order.
+    fn redirect_history(&self) -> Vec<crate::RedirectHop>;
     /// Reads the response body as text.
     fn text(self: Pin<&mut Self>) -> BoxFuture<'_, Result<String>>;
     /// Reads the response body as bytes.
     fn bytes(self: Pin<&mut Self>) -> BoxFuture<'_, Result<Vec<u8>>>;
+    /// Writes the response body to the given file, returning the number of bytes written.
+    fn write_to_file<'a>(
+        self: Pin<&'a mut Self>,
+        file: &'a mut File,
+        limit: Option<u64>,
+    ) -> BoxFuture<'a, Result<u64>>;
 }
 
 // These implementations allow backend types implementing the base traits
@@ -77,6 +102,22 @@ where
         AsyncResponse::get_header(self, header)
     }
 
+    fn headers(&self) -> Result<Vec<(String, String)>> {
+        AsyncResponse::headers(self)
+    }
+
+    fn url(&self) -> Option<String> {
+        AsyncResponse::url(self)
+    }
+
+    fn version(&self) -> Option<crate::HttpVersion> {
+        AsyncResponse::version(self)
+    }
+
+    fn redirect_history(&self) -> Vec<crate::RedirectHop> {
+        AsyncResponse::redirect_history(self)
+    }
+
     fn text(self: Pin<&mut Self>) -> BoxFuture<'_, Result<String>> {
         Box::pin(AsyncResponse::text(self))
     }
@@ -85,6 +126,14 @@ where
         Box::pin(AsyncResponse::bytes(self))
     }
 
+    fn write_to_file<'a>(
+        self: Pin<&'a mut Self>,
+        file: &'a mut File,
+        limit: Option<u64>,
+    ) -> BoxFuture<'a, Result<u64>> {
+        Box::pin(AsyncResponse::write_to_file(self, file, limit))
+    }
+
     fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         AsyncResponse::describe(self, f)
     }
@@ -122,4 +171,21 @@ where
                 .map(|res| Box::pin(res) as Pin<Box<dyn AnyAsyncResponse>>)
         }) as _
     }
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: ProgressCallback,
+    ) -> BoxFuture<'_, Result<Pin<Box<dyn AnyAsyncResponse>>>> {
+        Box::pin(async {
+            super::backend::AsyncClient::request_with_progress(self, req, progress)
+                .await
+                .map(|res| Box::pin(res) as Pin<Box<dyn AnyAsyncResponse>>)
+        }) as _
+    }
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>> {
+        super::backend::AsyncClient::cookie_jar(self)
+    }
+    fn flush_cookies(&self) -> Result<()> {
+        super::backend::AsyncClient::flush_cookies(self)
+    }
 }
@@ -7,12 +7,14 @@
 //! along with a custom `AsyncResponse` type.
 
 use std::fmt;
+use std::fs::File;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 
 use super::Request as AsyncRequest;
 use crate::client::ClientOptions;
-use crate::Result;
+use crate::{ProgressCallback, Result};
 
 /// Trait for asynchronous HTTP clients.
 ///
@@ -29,8 +31,34 @@ pub trait AsyncClient: Clone + Send + Sync + 'static {
 
     /// Sends an HTTP request and returns a future that resolves to the response.
     fn request(&self, req: AsyncRequest) -> impl Future<Output = Result<Self::Response>> + Send;
-    // TODO: fn request_with_progress
-    // TODO: fn request_file
+
+    /// Sends an HTTP request, reporting the transfer progress to the given callback.
+    ///
+    /// Progress is reported until the response body is fully read. Backends not able to track
+    /// progress may fall back to [`AsyncClient::request`], which is the default.
+    fn request_with_progress(
+        &self,
+        req: AsyncRequest,
+        progress: ProgressCallback,
+    ) -> impl Future<Output = Result<Self::Response>> + Send {
+        let _ = progress;
+        self.request(req)
+    }
+
+    /// Returns a handle to the cookie jar of this client.
+    ///
+    /// Returns `None` if cookies are disabled, or if the backend does not expose its cookie jar,
+    /// which is the default.
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>> {
+        None
+    }
+
+    /// Saves the cookies to [`crate::client::ClientOptions::cookie_file`] immediately.
+    ///
+    /// Does nothing if no cookie file is set, which is the default.
+    fn flush_cookies(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 /// Trait for asynchronous HTTP backend implementations.
@@ -75,9 +103,79 @@ pub trait AsyncResponse: super::MaybeAsyncRead + Send + Sync + 'static {
     /// Gets all values for the specified header.
     fn get_header(&self, header: &str) -> Result<Vec<String>>;
 
+    /// Gets all headers as name-value pairs, in the order they were received.
+    ///
+    /// Headers appearing multiple times are returned as separate pairs.
+    fn headers(&self) -> Result<Vec<(String, String)>>;
+
+    /// Gets the effective URL of the response after following redirects, if known by the backend.
+    fn url(&self) -> Option<String> {
+        None
+    }
+
+    /// Gets the HTTP version of this response, if known by the backend.
+    fn version(&self) -> Option<crate::HttpVersion> {
+        None
+    }
+
+    /// Gets the redirects followed before receiving this response, in order.
+    ///
+    /// Backends not able to track redirects return an empty list.
+    fn redirect_history(&self) -> Vec<crate::RedirectHop> {
+        vec![]
+    }
+
     /// Reads the response body as text.
     fn text(self: Pin<&mut Self>) -> impl Future<Output = Result<String>> + Send;
 
     /// Reads the response body as bytes.
     fn bytes(self: Pin<&mut Self>) -> impl Future<Output = Result<Vec<u8>>> + Send;
+
+    /// Writes the response body to the given file, returning the number of bytes written.
+    ///
+    /// Fails with [`crate::Error::ResponseTooLarge`] if the body is larger than `limit`.
+    ///
+    /// The default implementation reads the body from the `AsyncRead` stream if the
+    /// `async-stream` feature is enabled, or buffers it with [`AsyncResponse::bytes`] otherwise.
+    /// Writes to the file are blocking. Backends able to download to files natively may override
+    /// it.
+    fn write_to_file(
+        mut self: Pin<&mut Self>,
+        file: &mut File,
+        limit: Option<u64>,
+    ) -> impl Future<Output = Result<u64>> + Send {
+        async move {
+            #[cfg(feature = "async-stream")]
+            let written = {
+                let mut buf = vec![0; 64 * 1024];
+                let mut written = 0u64;
+                loop {
+                    let read = std::future::poll_fn(|cx| {
+                        futures_io::AsyncRead::poll_read(self.as_mut(), cx, &mut buf)
+                    })
+                    .await?;
+                    if read == 0 {
+                        break written;
+                    }
+                    written += read as u64;
+                    if limit.is_some_and(|limit| written > limit) {
+                        break written;
+                    }
+                    std::io::Write::write_all(file, &buf[..read])?;
+                }
+            };
+            #[cfg(not(feature = "async-stream"))]
+            let written = {
+                let bytes = self.as_mut().bytes().await?;
+                if !limit.is_some_and(|limit| bytes.len() as u64 > limit) {
+                    std::io::Write::write_all(file, &bytes)?;
+                }
+                bytes.len() as u64
+            };
+            if limit.is_some_and(|limit| written > limit) {
+                return Err(crate::Error::ResponseTooLarge);
+            }
+            Ok(written)
+        }
+    }
 }
@@ -9,6 +9,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::fs::File;
 use std::sync::Arc;
 
 use super::backend::BlockingResponse;
@@ -32,6 +33,16 @@ pub trait AnyBlockingClient: Any + Send + Sync + 'static {
     fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
     /// Sends an HTTP request and returns the response.
     fn request(&self, req: Request) -> crate::Result<Box<dyn AnyBlockingResponse>>;
+    /// Sends an HTTP request, reporting the transfer progress to the given callback.
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: crate::ProgressCallback,
+    ) -> crate::Result<Box<dyn AnyBlockingResponse>>;
+    /// Returns a handle to the cookie jar of this client, if exposed by the backend.
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>>;
+    /// Saves the cookies to the cookie file of this client, if any.
+    fn flush_cookies(&self) -> Result<()>;
 }
 
 /// Trait for type-erased blocking HTTP responses.
@@ -47,10 +58,20 @@ pub trait AnyBlockingResponse: super::MaybeRead + Any + Send + Sync + 'static {
     fn content_length(&self) -> Option<u64>;
     /// Gets all values for the specified header.
     fn get_header(&self, header: &str) -> crate::Result<Vec<String>>;
+    /// Gets all headers as name-value pairs, in the order they were received.
+    fn headers(&self) -> crate::Result<Vec<(String, String)>>;
+    /// Gets the effective URL of the response after following redirects, if known by the backend.
+    fn url(&self) -> Option<String>;
+    /// Gets the HTTP version of this response, if known by the backend.
+    fn version(&self) -> Option<crate::HttpVersion>;
+    /// Gets the redirects followed before receiving this response, in order.
+    fn redirect_history(&self) -> Vec<crate::RedirectHop>;
     /// Reads the response body as text.
     fn text(&mut self) -> crate::Result<String>;
     /// Reads the response body as bytes.
     fn bytes(&mut self) -> crate::Result<Vec<u8>>;
+    /// Writes the response body to the given file, returning the number of bytes written.
+    fn write_to_file(&mut self, file: &mut File, limit: Option<u64>) -> crate::Result<u64>;
 }
 
 // These implementations allow backend types implementing the base traits
@@ -85,6 +106,22 @@ where
         BlockingResponse::get_header(self, header)
     }
 
+    fn headers(&self) -> crate::Result<Vec<(String, String)>> {
+        BlockingResponse::headers(self)
+    }
+
+    fn url(&self) -> Option<String> {
+        BlockingResponse::url(self)
+    }
+
+    fn version(&self) -> Option<crate::HttpVersion> {
+        BlockingResponse::version(self)
+    }
+
+    fn redirect_history(&self) -> Vec<crate::RedirectHop> {
+        BlockingResponse::redirect_history(self)
+    }
+
     fn text(&mut self) -> crate::Result<String> {
         BlockingResponse::text(self)
     }
@@ -92,6 +129,10 @@ where
     fn bytes(&mut self) -> crate::Result<Vec<u8>> {
         BlockingResponse::bytes(self)
     }
+
+    fn write_to_file(&mut self, file: &mut File, limit: Option<u64>) -> crate::Result<u64> {
+        BlockingResponse::write_to_file(self, file, limit)
+    }
 }
 
 impl<B> AnyBlockingClient for B
@@ -104,4 +145,19 @@ where
     fn request(&self, req: Request) -> crate::Result<Box<dyn AnyBlockingResponse>> {
         Ok(Box::new(self.request(req)?))
     }
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: crate::ProgressCallback,
+    ) -> crate::Result<Box<dyn AnyBlockingResponse>> {
+        Ok(Box::new(
+            super::backend::BlockingClient::request_with_progress(self, req, progress)?,
+        ))
+    }
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>> {
+        super::backend::BlockingClient::cookie_jar(self)
+    }
+    fn flush_cookies(&self) -> Result<()> {
+        super::backend::BlockingClient::flush_cookies(self)
+    }
 }
@@ -7,6 +7,8 @@
 //! along with a custom `BlockingResponse` type.
 
 use std::fmt;
+use std::fs::File;
+use std::sync::Arc;
 
 use super::Request;
 use crate::client::ClientOptions;
@@ -27,6 +29,34 @@ pub trait BlockingClient: Clone + Send + Sync + 'static {
 
     /// Sends an HTTP request and returns the response.
     fn request(&self, req: Request) -> crate::Result<Self::Response>;
+
+    /// Sends an HTTP request, reporting the transfer progress to the given callback.
+    ///
+    /// Progress is reported until the response body is fully read. Backends not able to track
+    /// progress may fall back to [`BlockingClient::request`], which is the default.
+    fn request_with_progress(
+        &self,
+        req: Request,
+        progress: crate::ProgressCallback,
+    ) -> crate::Result<Self::Response> {
+        let _ = progress;
+        self.request(req)
+    }
+
+    /// Returns a handle to the cookie jar of this client.
+    ///
+    /// Returns `None` if cookies are disabled, or if the backend does not expose its cookie jar,
+    /// which is the default.
+    fn cookie_jar(&self) -> Option<Arc<dyn crate::CookieJar>> {
+        None
+    }
+
+    /// Saves the cookies to [`crate::client::ClientOptions::cookie_file`] immediately.
+    ///
+    /// Does nothing if no cookie file is set, which is the default.
+    fn flush_cookies(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 /// Trait for blocking HTTP backend implementations.
@@ -69,9 +99,64 @@ pub trait BlockingResponse: super::MaybeRead + Send + Sync + 'static {
     /// Gets all values for the specified header.
     fn get_header(&self, header: &str) -> crate::Result<Vec<String>>;
 
+    /// Gets all headers as name-value pairs, in the order they were received.
+    ///
+    /// Headers appearing multiple times are returned as separate pairs.
+    fn headers(&self) -> crate::Result<Vec<(String, String)>>;
+
+    /// Gets the effective URL of the response after following redirects, if known by the backend.
+    fn url(&self) -> Option<String> {
+        None
+    }
+
+    /// Gets the HTTP version of this response, if known by the backend.
+    fn version(&self) -> Option<crate::HttpVersion> {
+        None
+    }
+
+    /// Gets the redirects followed before receiving this response, in order.
+    ///
+    /// Backends not able to track redirects return an empty list.
+    fn redirect_history(&self) -> Vec<crate::RedirectHop> {
+        vec![]
+    }
+
     /// Reads the response body as text.
     fn text(&mut self) -> crate::Result<String>;
 
     /// Reads the response body as bytes.
     fn bytes(&mut self) -> crate::Result<Vec<u8>>;
+
+    /// Writes the response body to the given file, returning the number of bytes written.
+    ///
+    /// Fails with [`crate::Error::ResponseTooLarge`] if the body is larger than `limit`.
+    ///
+    /// The default implementation copies the body from the [`std::io::Read`] stream if the
+    /// `blocking-stream` feature is enabled, or buffers it with [`BlockingResponse::bytes`]
+    /// otherwise. Backends able to download to files natively may override it.
+    fn write_to_file(&mut self, file: &mut File, limit: Option<u64>) -> crate::Result<u64> {
+        #[cfg(feature = "blocking-stream")]
+        let written = {
+            let max = limit.unwrap_or(u64::MAX);
+            let written = std::io::copy(&mut std::io::Read::take(&mut *self, max), file)?;
+            // Read past the limit without writing to the file, to tell whether the body exceeds it
+            let mut rest = vec![];
+            if written == max {
+                std::io::Read::read_to_end(&mut std::io::Read::take(&mut *self, 1), &mut rest)?;
+            }
+            written + rest.len() as u64
+        };
+        #[cfg(not(feature = "blocking-stream"))]
+        let written = {
+            let bytes = self.bytes()?;
+            if !limit.is_some_and(|limit| bytes.len() as u64 > limit) {
+                std::io::Write::write_all(file, &bytes)?;
+            }
+            bytes.len() as u64
+        };
+        if limit.is_some_and(|limit| written > limit) {
+            return Err(crate::Error::ResponseTooLarge);
+        }
+        Ok(written)
+    }
 }
@@ -0,0 +1,126 @@
+//! Cancellation of in-flight requests.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// A handle to cancel requests from any thread.
+///
+/// Clones of a token share the same state. Once cancelled, a token stays cancelled.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    callbacks: Mutex<Callbacks>,
+}
+
+#[derive(Default)]
+struct Callbacks {
+    next_id: u64,
+    entries: Vec<(u64, Box<dyn FnOnce() + Send>)>,
+}
+
+/// A callback registered with [`CancellationToken::on_cancel`], which is unregistered when
+/// dropped.
+#[must_use = "the callback is unregistered when dropped"]
+pub struct CancelRegistration {
+    inner: Arc<Inner>,
+    id: u64,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token, invoking all registered callbacks on the current thread.
+    pub fn cancel(&self) {
+        if self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let entries = std::mem::take(&mut self.inner.lock().entries);
+        for (_, callback) in entries {
+            callback();
+        }
+    }
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Registers a callback to be invoked once the token is cancelled.
+    ///
+    /// The callback is invoked immediately if the token is already cancelled.
+    pub fn on_cancel(&self, callback: impl FnOnce() + Send + 'static) -> CancelRegistration {
+        let mut callbacks = self.inner.lock();
+        let id = callbacks.next_id;
+        callbacks.next_id += 1;
+        if self.is_cancelled() {
+            drop(callbacks);
+            callback();
+        } else {
+            callbacks.entries.push((id, Box::new(callback)));
+        }
+        CancelRegistration {
+            inner: self.inner.clone(),
+            id,
+        }
+    }
+
+    /// Returns a future that resolves once the token is cancelled.
+    pub fn cancelled(&self) -> impl Future<Output = ()> + Send + '_ {
+        let waker = Arc::new(Mutex::new(None::<Waker>));
+        let mut registration = None;
+        std::future::poll_fn(move |cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            *waker.lock().unwrap() = Some(cx.waker().clone());
+            if registration.is_none() {
+                let waker = waker.clone();
+                registration = Some(self.on_cancel(move || {
+                    if let Some(waker) = waker.lock().unwrap().take() {
+                        waker.wake();
+                    }
+                }));
+            }
+            Poll::Pending
+        })
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Callbacks> {
+        self.callbacks.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for CancelRegistration {
+    fn drop(&mut self) {
+        self.inner.lock().entries.retain(|(id, _)| *id != self.id);
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl fmt::Debug for CancelRegistration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelRegistration")
+            .field("id", &self.id)
+            .finish()
+    }
+}
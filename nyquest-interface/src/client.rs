@@ -5,4 +5,7 @@
 
 mod options;
 
-pub use options::{CachingBehavior, ClientOptions, ProxyOptions};
+pub use options::{
+    CachingBehavior, ClientIdentity, ClientOptions, DnsOptions, DnsResolver, HttpVersionPreference,
+    ProxyOptions, RedirectAttempt, RedirectDecider, RedirectPolicy, ResolveOverride, TlsVersion,
+};
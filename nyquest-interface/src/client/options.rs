@@ -1,6 +1,6 @@
 //! Configuration options for HTTP clients.
 
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, fmt, io, net::IpAddr, path::PathBuf, sync::Arc, time::Duration};
 
 /// Defines how the HTTP client should handle response caching.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -12,6 +12,33 @@ pub enum CachingBehavior {
     BestEffort,
 }
 
+/// A version of the TLS protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// TLS 1.0
+    Tls1_0,
+    /// TLS 1.1
+    Tls1_1,
+    /// TLS 1.2
+    Tls1_2,
+    /// TLS 1.3
+    Tls1_3,
+}
+
+/// Defines which HTTP versions the HTTP client should use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HttpVersionPreference {
+    /// Use the default of the backend.
+    #[default]
+    Default,
+    /// Only use HTTP/1.x.
+    Http1Only,
+    /// Use HTTP/2 if the server supports it, falling back to HTTP/1.1 otherwise.
+    PreferHttp2,
+    /// Use HTTP/2 without negotiation, assuming the server supports it.
+    Http2PriorKnowledge,
+}
+
 /// Configuration options for proxy settings.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum ProxyOptions {
@@ -31,6 +58,116 @@ pub enum ProxyOptions {
     },
 }
 
+/// A static override of the addresses a host and port resolve to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolveOverride {
+    /// The host name to override.
+    pub host: String,
+    /// The port the override applies to.
+    ///
+    /// Backends not able to override a single port apply the override to all ports of the host,
+    /// connecting to this port instead.
+    pub port: u16,
+    /// The addresses to connect to, in order of preference.
+    pub addrs: Vec<IpAddr>,
+}
+
+/// A callback resolving a host name to its addresses.
+pub type DnsResolver = Arc<dyn Fn(&str) -> io::Result<Vec<IpAddr>> + Send + Sync>;
+
+/// Configuration options for resolving host names.
+#[derive(Clone, Default)]
+pub struct DnsOptions {
+    /// Static overrides, taking precedence over [`Self::resolver`].
+    pub overrides: Vec<ResolveOverride>,
+    /// Optional callback replacing the resolver of the backend.
+    pub resolver: Option<DnsResolver>,
+}
+
+impl fmt::Debug for DnsOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DnsOptions")
+            .field("overrides", &self.overrides)
+            .field("resolver", &self.resolver.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// A redirect about to be followed, passed to [`RedirectPolicy::decider`].
+#[derive(Debug, Clone, Copy)]
+pub struct RedirectAttempt<'a> {
+    /// The status code of the redirect response.
+    pub status: u16,
+    /// The URL that responded with the redirect.
+    pub url: &'a str,
+    /// The absolute URL the redirect points to, resolved from the `Location` header.
+    pub location: &'a str,
+    /// The number of redirects already followed before this one.
+    pub previous_hops: u32,
+}
+
+/// A callback deciding whether a redirect should be followed.
+pub type RedirectDecider = Arc<dyn Fn(&RedirectAttempt<'_>) -> bool + Send + Sync>;
+
+/// Configuration options for following redirects.
+///
+/// Backends are only required to honor [`Self::max_redirects`]. When either
+/// [`Self::same_origin_only`] or [`Self::decider`] is set, the nyquest facade
+/// follows redirects by itself and turns off
+/// [`ClientOptions::follow_redirects`] for the backend. The facade also follows
+/// the redirects of requests with credentials by itself, turning off
+/// [`crate::RequestOptions::follow_redirects`] for them.
+#[derive(Clone, Default)]
+pub struct RedirectPolicy {
+    /// Maximum number of redirects to follow, or `None` for the backend default.
+    ///
+    /// Exceeding the limit results in [`crate::Error::TooManyRedirects`].
+    pub max_redirects: Option<u32>,
+    /// Whether to only follow redirects to the same origin (scheme, host and port).
+    pub same_origin_only: bool,
+    /// Optional callback to veto individual redirects.
+    pub decider: Option<RedirectDecider>,
+}
+
+impl fmt::Debug for RedirectPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedirectPolicy")
+            .field("max_redirects", &self.max_redirects)
+            .field("same_origin_only", &self.same_origin_only)
+            .field("decider", &self.decider.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// A client certificate with its private key, presented for TLS client authentication.
+#[derive(Clone, PartialEq, Eq)]
+pub enum ClientIdentity {
+    /// A PEM encoded certificate chain and a PEM encoded PKCS#8 private key.
+    Pem {
+        /// The certificate chain, starting with the client certificate.
+        cert: Vec<u8>,
+        /// The private key of the client certificate.
+        key: Vec<u8>,
+    },
+    /// A DER encoded PKCS#12 archive containing the certificate chain and the private key.
+    Pkcs12 {
+        /// The contents of the archive.
+        der: Vec<u8>,
+        /// The password protecting the archive.
+        password: String,
+    },
+}
+
+impl fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the private key or the password
+        match self {
+            Self::Pem { .. } => f.write_str("ClientIdentity::Pem"),
+            Self::Pkcs12 { .. } => f.write_str("ClientIdentity::Pkcs12"),
+        }
+    }
+}
+
 /// Configuration options for creating a nyquest HTTP client.
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
@@ -44,18 +181,58 @@ pub struct ClientOptions {
     pub caching_behavior: CachingBehavior,
     /// Configuration options for proxy settings.
     pub proxy_options: ProxyOptions,
+    /// Optional path to a Unix domain socket to send all requests over.
+    ///
+    /// The URL of a request still supplies the `Host` header and the path. Backends not able to
+    /// connect to Unix domain sockets must fail to create the client.
+    pub unix_socket: Option<PathBuf>,
+    /// Configuration options for resolving host names.
+    ///
+    /// Backends not able to honor the options must fail to create the client.
+    pub dns: DnsOptions,
     /// Whether to enable cookie handling.
     pub use_cookies: bool,
+    /// Path to a cookie file in Netscape format to persist cookies in, when cookies are enabled.
+    ///
+    /// Cookies are loaded from the file, if it exists, when the client is created, and saved to
+    /// it when the client is dropped or its cookies are flushed explicitly. Backends not able to
+    /// persist cookies must fail to create the client.
+    pub cookie_file: Option<PathBuf>,
     /// Whether to automatically follow redirect responses.
     pub follow_redirects: bool,
+    /// Configuration for following redirects when [`Self::follow_redirects`] is enabled.
+    pub redirect_policy: RedirectPolicy,
     /// Optional maximum buffer size for response bodies.
     pub max_response_buffer_size: Option<u64>,
     /// Optional timeout duration for requests.
     pub request_timeout: Option<Duration>,
+    /// Optional timeout for establishing a connection, including the TLS handshake if any.
+    pub connect_timeout: Option<Duration>,
+    /// Optional timeout for receiving no data while a request is in progress.
+    pub read_timeout: Option<Duration>,
     /// Whether to ignore SSL certificate errors.
     pub ignore_certificate_errors: bool,
-    // TODO: auth
-    // TODO: redirects
+    /// PEM encoded root certificates to trust in addition to the system store.
+    ///
+    /// Each entry may contain multiple certificates.
+    pub root_certificates: Vec<Vec<u8>>,
+    /// Whether to trust only [`Self::root_certificates`], ignoring the system store.
+    pub only_custom_roots: bool,
+    /// Base64 encoded SHA-256 hashes of the DER encoded SubjectPublicKeyInfo of pinned server
+    /// public keys.
+    ///
+    /// When not empty, connections fail unless the server certificate matches one of the pins.
+    pub pinned_public_keys: Vec<String>,
+    /// The minimum TLS version to accept, or the backend default if `None`.
+    ///
+    /// Backends not able to enforce the minimum version must fail to create the client.
+    pub min_tls_version: Option<TlsVersion>,
+    /// The HTTP versions to use.
+    pub http_version: HttpVersionPreference,
+    /// Optional client certificate for TLS client authentication.
+    ///
+    /// Backends not able to present a client certificate must fail to create the client.
+    pub client_identity: Option<ClientIdentity>,
 }
 
 impl Default for ClientOptions {
@@ -67,11 +244,23 @@ impl Default for ClientOptions {
             default_headers: vec![],
             caching_behavior: CachingBehavior::default(),
             proxy_options: ProxyOptions::default(),
+            unix_socket: None,
+            dns: DnsOptions::default(),
             use_cookies: true,
+            cookie_file: None,
             follow_redirects: true,
+            redirect_policy: RedirectPolicy::default(),
             max_response_buffer_size: None,
             request_timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
             ignore_certificate_errors: false,
+            root_certificates: vec![],
+            only_custom_roots: false,
+            pinned_public_keys: vec![],
+            min_tls_version: None,
+            http_version: HttpVersionPreference::Default,
+            client_identity: None,
         }
     }
 }
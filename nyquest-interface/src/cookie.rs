@@ -0,0 +1,144 @@
+//! Cookie jar access for nyquest HTTP clients.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Result;
+
+/// A cookie stored in the cookie jar of a client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    /// The name of the cookie.
+    pub name: String,
+    /// The value of the cookie.
+    pub value: String,
+    /// The domain of the cookie, without a leading dot.
+    pub domain: String,
+    /// Whether the cookie is also sent to subdomains of [`Self::domain`], as set by the `Domain`
+    /// attribute. Otherwise the cookie is only sent to the exact host.
+    pub include_subdomains: bool,
+    /// The path of the cookie.
+    pub path: String,
+    /// Whether the cookie is only sent over secure connections.
+    pub secure: bool,
+    /// Whether the cookie is hidden from scripts. Only informational for HTTP clients.
+    pub http_only: bool,
+    /// The time the cookie expires, or `None` for session cookies.
+    pub expires: Option<SystemTime>,
+}
+
+impl Cookie {
+    /// Parses a line of the Netscape cookie file format, as written by libcurl and browsers.
+    ///
+    /// Returns `None` for comments and lines that cannot be parsed.
+    pub fn from_netscape_line(line: &str) -> Option<Self> {
+        let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let mut fields = line.splitn(7, '\t');
+        let domain = fields.next()?;
+        let include_subdomains = fields.next()? == "TRUE";
+        let path = fields.next()?;
+        let secure = fields.next()? == "TRUE";
+        let expires: u64 = fields.next()?.parse().ok()?;
+        let name = fields.next()?;
+        let value = fields.next().unwrap_or_default();
+        Some(Self {
+            name: name.into(),
+            value: value.into(),
+            domain: domain.trim_start_matches('.').into(),
+            include_subdomains,
+            path: path.into(),
+            secure,
+            http_only,
+            expires: (expires != 0).then(|| UNIX_EPOCH + Duration::from_secs(expires)),
+        })
+    }
+
+    /// Formats the cookie as a line of the Netscape cookie file format.
+    pub fn to_netscape_line(&self) -> String {
+        let bool_str = |b| if b { "TRUE" } else { "FALSE" };
+        let expires = self.expires.map_or(0, |t| {
+            t.duration_since(UNIX_EPOCH)
+                .map_or(1, |d| d.as_secs().max(1))
+        });
+        format!(
+            "{}{}{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            if self.http_only { "#HttpOnly_" } else { "" },
+            if self.include_subdomains { "." } else { "" },
+            self.domain,
+            bool_str(self.include_subdomains),
+            self.path,
+            bool_str(self.secure),
+            expires,
+            self.name,
+            self.value,
+        )
+    }
+}
+
+/// Writes lines of the Netscape cookie file format to `path`, replacing the file if it exists.
+///
+/// The lines are written to a temporary file next to `path`, which then replaces the file, so that
+/// the cookies are not lost if writing fails halfway. On Unix, the file is only accessible by its
+/// owner.
+pub fn write_netscape_cookie_file(
+    path: &Path,
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+) -> io::Result<()> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut file_name = path.file_name().unwrap_or_default().to_owned();
+    file_name.push(format!(
+        ".{}-{}.tmp",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp_path = path.with_file_name(file_name);
+
+    let result = create_private_file(&temp_path).and_then(|file| {
+        let mut writer = io::BufWriter::new(file);
+        writer.write_all(b"# Netscape HTTP Cookie File\n")?;
+        for line in lines {
+            writer.write_all(line.as_ref().as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        writer
+            .into_inner()
+            .map_err(|e| e.into_error())?
+            .sync_all()?;
+        fs::rename(&temp_path, path)
+    });
+    if result.is_err() {
+        fs::remove_file(&temp_path).ok();
+    }
+    result
+}
+
+fn create_private_file(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+/// A handle to the cookie jar of a client.
+///
+/// Changes are visible to requests made afterwards with the client owning the jar.
+pub trait CookieJar: Send + Sync + 'static {
+    /// Returns all cookies in the jar that have not expired.
+    fn cookies(&self) -> Result<Vec<Cookie>>;
+
+    /// Adds a cookie, replacing the one with the same name, domain and path if any.
+    fn add(&self, cookie: Cookie) -> Result<()>;
+
+    /// Removes the cookie with the given name, domain and path if any.
+    fn remove(&self, name: &str, domain: &str, path: &str) -> Result<()>;
+
+    /// Removes all cookies.
+    fn clear(&self) -> Result<()>;
+}
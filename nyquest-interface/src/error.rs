@@ -1,7 +1,52 @@
 //! Error types for nyquest HTTP operations.
 
+use std::fmt;
+
 use thiserror::Error;
 
+/// The category of a backend failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The host name could not be resolved.
+    Dns,
+    /// The connection to the server could not be established.
+    Connect,
+    /// The TLS handshake or session failed.
+    Tls,
+    /// The server certificate is invalid or not trusted.
+    Certificate,
+    /// The proxy could not be resolved, connected to, or refused to tunnel the request.
+    Proxy,
+    /// Receiving the response failed, e.g. the connection was closed prematurely.
+    BodyRead,
+    /// Sending the request failed, e.g. the request body could not be read.
+    BodyWrite,
+    /// The response body could not be decoded according to its content encoding.
+    Decode,
+    /// The number of redirects exceeds the configured limit.
+    TooManyRedirects,
+    /// A failure not covered by the other categories.
+    Other,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dns => "DNS resolution failed",
+            Self::Connect => "Connection failed",
+            Self::Tls => "TLS error",
+            Self::Certificate => "Invalid server certificate",
+            Self::Proxy => "Proxy error",
+            Self::BodyRead => "Failed to receive response",
+            Self::BodyWrite => "Failed to send request",
+            Self::Decode => "Failed to decode response body",
+            Self::TooManyRedirects => "Too many redirects",
+            Self::Other => "Backend error",
+        })
+    }
+}
+
 /// Common error types that can occur in nyquest HTTP operations.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -17,6 +62,34 @@ pub enum Error {
     /// The request timed out before completion.
     #[error("Request is not finished within timeout")]
     RequestTimeout,
+    /// The number of redirects exceeds the configured limit.
+    #[error("Too many redirects")]
+    TooManyRedirects,
+    /// The request is cancelled with its [`crate::CancellationToken`].
+    #[error("Request is cancelled")]
+    Cancelled,
+    /// A backend failure of a known category.
+    #[error("{kind}")]
+    Backend {
+        /// The category of the failure.
+        kind: ErrorKind,
+        /// The original error reported by the backend.
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl Error {
+    /// Creates a [`Error::Backend`] error of the given kind.
+    pub fn backend(
+        kind: ErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::Backend {
+            kind,
+            source: source.into(),
+        }
+    }
 }
 
 /// Result type for nyquest HTTP operations.
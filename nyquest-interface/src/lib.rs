@@ -29,16 +29,24 @@ pub mod r#async;
 #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
 pub mod blocking;
 pub mod body;
+mod cancel;
 pub mod client;
+mod cookie;
 mod error;
+mod progress;
 #[doc(hidden)] // For nyquest facade only
 pub mod register;
 mod request;
+mod response;
 
 pub use body::Body;
 #[cfg(feature = "multipart")]
 #[cfg_attr(docsrs, doc(cfg(feature = "multipart")))]
 pub use body::{Part, PartBody};
-pub use error::{Error, Result};
+pub use cancel::{CancelRegistration, CancellationToken};
+pub use cookie::{write_netscape_cookie_file, Cookie, CookieJar};
+pub use error::{Error, ErrorKind, Result};
+pub use progress::{Progress, ProgressCallback};
 pub use register::register_backend;
-pub use request::{Method, Request};
+pub use request::{Method, Request, RequestOptions};
+pub use response::{HttpVersion, RedirectHop};
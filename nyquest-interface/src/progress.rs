@@ -0,0 +1,22 @@
+//! Transfer progress reporting for nyquest HTTP clients.
+
+use std::sync::Arc;
+
+/// A snapshot of the transfer progress of a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of request body bytes sent so far.
+    pub uploaded: u64,
+    /// Total size of the request body, if known.
+    pub upload_total: Option<u64>,
+    /// Number of response body bytes received so far.
+    pub downloaded: u64,
+    /// Total size of the response body, if known.
+    pub download_total: Option<u64>,
+}
+
+/// A callback receiving progress updates of a request.
+///
+/// The callback is invoked from whichever thread drives the transfer, and keeps being invoked
+/// while the response body is read.
+pub type ProgressCallback = Arc<dyn Fn(Progress) + Send + Sync>;
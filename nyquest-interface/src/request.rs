@@ -3,9 +3,10 @@
 //! This module provides the core request types used to construct and send
 //! HTTP requests through nyquest backends.
 
-use std::{borrow::Cow, fmt::Debug};
+use std::{borrow::Cow, fmt::Debug, time::Duration};
 
 use crate::body::Body;
+use crate::client::CachingBehavior;
 
 /// HTTP request methods supported by nyquest.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -36,6 +37,32 @@ pub struct Request<S> {
     pub additional_headers: Vec<(Cow<'static, str>, Cow<'static, str>)>,
     /// Optional request body
     pub body: Option<Body<S>>,
+    /// Token to cancel this request, including reading the response body
+    ///
+    /// Backends not able to interrupt a transfer may ignore it.
+    pub cancellation: Option<crate::CancellationToken>,
+    /// Options overriding the client defaults for this request
+    pub options: RequestOptions,
+}
+
+/// Per-request overrides of [`crate::client::ClientOptions`].
+///
+/// Each field left as `None` falls back to the corresponding client option. Backends not able to
+/// apply an option to a single request may ignore the override.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    /// Overrides [`crate::client::ClientOptions::request_timeout`].
+    pub request_timeout: Option<Duration>,
+    /// Overrides [`crate::client::ClientOptions::connect_timeout`].
+    pub connect_timeout: Option<Duration>,
+    /// Overrides [`crate::client::ClientOptions::read_timeout`].
+    pub read_timeout: Option<Duration>,
+    /// Overrides [`crate::client::ClientOptions::follow_redirects`].
+    pub follow_redirects: Option<bool>,
+    /// Overrides [`crate::client::ClientOptions::caching_behavior`].
+    pub caching_behavior: Option<CachingBehavior>,
+    /// Overrides [`crate::client::ClientOptions::max_response_buffer_size`].
+    pub max_response_buffer_size: Option<u64>,
 }
 
 impl<S> Debug for Request<S>
@@ -48,6 +75,8 @@ where
             .field("relative_uri", &self.relative_uri)
             .field("additional_headers", &self.additional_headers)
             .field("body", &self.body)
+            .field("cancellation", &self.cancellation)
+            .field("options", &self.options)
             .finish()
     }
 }
@@ -62,6 +91,8 @@ where
             relative_uri: self.relative_uri.clone(),
             additional_headers: self.additional_headers.clone(),
             body: self.body.clone(),
+            cancellation: self.cancellation.clone(),
+            options: self.options.clone(),
         }
     }
 }
@@ -0,0 +1,27 @@
+//! Response metadata shared by async and blocking backends.
+
+/// A redirect response that was followed before receiving the final response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectHop {
+    /// The status code of the redirect response.
+    pub status: u16,
+    /// The value of the `Location` header of the redirect response, if present.
+    ///
+    /// Some backends report the absolute URL the header resolves to instead.
+    pub location: Option<String>,
+}
+
+/// The HTTP version of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpVersion {
+    /// HTTP/0.9
+    Http09,
+    /// HTTP/1.0
+    Http10,
+    /// HTTP/1.1
+    Http11,
+    /// HTTP/2
+    Http2,
+    /// HTTP/3
+    Http3,
+}
@@ -7,8 +7,13 @@ use nyquest_interface::r#async::{BoxedStream, SizedBodyStream, UnsizedBodyStream
 
 #[cfg(feature = "async-stream")]
 mod async_read_stream;
+mod auth;
 pub(crate) mod client;
+mod next;
+mod redirect;
 mod response;
+mod retry;
+mod timer;
 
 #[cfg(not(feature = "async-stream"))]
 type BoxedStream = std::convert::Infallible;
@@ -25,6 +30,7 @@ pub type Part = crate::body::Part<BoxedStream>;
 pub type PartBody = crate::body::PartBody<BoxedStream>;
 #[cfg(feature = "async-stream")]
 pub use async_read_stream::AsyncReadStream;
+pub use next::Next;
 pub use response::Response;
 
 #[cfg(feature = "async-stream")]
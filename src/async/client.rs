@@ -1,10 +1,17 @@
 use std::fmt::Debug;
+use std::io;
+use std::path::Path;
 use std::sync::Arc;
 
 use nyquest_interface::{r#async::AnyAsyncClient, register::BACKEND};
 
-use super::response::Response;
-use crate::ClientBuilder;
+use super::{next::Next, response::Response};
+use crate::auth::Auth;
+use crate::download::{PartFile, PartialDownload, Resume};
+use crate::error::{attach_context, ContextConfig};
+use crate::header::CONTENT_RANGE;
+use crate::redirect::RedirectConfig;
+use crate::{client::RetryPolicy, middleware::Middleware, ClientBuilder, CookieJar, Download};
 
 /// A async HTTP client to make Requests with.
 ///
@@ -16,6 +23,13 @@ use crate::ClientBuilder;
 #[derive(Clone)]
 pub struct AsyncClient {
     pub(super) client: Arc<dyn AnyAsyncClient>,
+    middlewares: Arc<[Arc<dyn Middleware>]>,
+    retry_policy: Option<RetryPolicy>,
+    redirects: RedirectConfig,
+    base_url: Option<Arc<str>>,
+    error_context: Option<ContextConfig>,
+    max_download_size: Option<u64>,
+    auth: Option<Auth>,
 }
 
 impl ClientBuilder {
@@ -24,22 +38,156 @@ impl ClientBuilder {
     /// # Panic
     ///
     /// Panics if no backend is registered.
-    pub async fn build_async(self) -> crate::Result<AsyncClient> {
+    pub async fn build_async(mut self) -> crate::Result<AsyncClient> {
+        let error_context =
+            ContextConfig::new(self.error_context, self.options.base_url.as_deref());
+        let base_url = self.options.base_url.as_deref().map(Arc::from);
+        let (options, redirects) = self.backend_options();
         Ok(AsyncClient {
             client: BACKEND
                 .get()
                 .expect("No backend registered. Please find a backend crate (e.g. nyquest-preset) and call the `register` method at program startup.")
-                .create_async_client(self.options)
-                .await?
+                .create_async_client(options)
+                .await?,
+            middlewares: self.middlewares.into_shared(),
+            retry_policy: self.retry_policy,
+            redirects,
+            base_url,
+            error_context,
+            max_download_size: self.max_download_size,
+            auth: self.auth,
         })
     }
 }
 
 impl AsyncClient {
     /// Sends a request to the server and returns the response.
-    pub async fn request(&self, req: super::Request) -> crate::Result<Response> {
-        let res = self.client.request(req.inner).await?;
-        Ok(res.into())
+    pub async fn request(&self, mut req: super::Request) -> crate::Result<Response> {
+        if req.auth.is_none() {
+            req.auth = self.auth.clone();
+        }
+        self.redirects.add_sensitive_headers(&mut req);
+        let context = self.error_context.as_ref().map(|c| c.capture(&req));
+        let next = Next::new(
+            &self.client,
+            &self.middlewares,
+            self.retry_policy.as_ref(),
+            &self.redirects,
+            self.base_url.as_deref(),
+        );
+        match next.run(req).await {
+            Ok(res) => Ok(res.with_context(context)),
+            Err(e) => Err(attach_context(context.as_deref(), e)),
+        }
+    }
+
+    /// Sends a request and writes the response body to the file at `path` without buffering it
+    /// in memory.
+    ///
+    /// The body is written to a temporary file in the same directory, which replaces `path` once
+    /// the body is fully received. If the download fails, the temporary file is removed and
+    /// `path` is left untouched. The size of the body is limited by the
+    /// [`crate::ClientBuilder::max_download_size`] option.
+    ///
+    /// If the status code does not indicate success, [`crate::Error::NonSuccessfulStatusCode`] is
+    /// returned without creating any file.
+    ///
+    /// Writes to the file are blocking, which is usually acceptable for local disks.
+    pub async fn download_to(
+        &self,
+        req: super::Request,
+        path: impl AsRef<Path>,
+    ) -> crate::Result<Download> {
+        let mut res = self.request(req).await?.with_successful_status()?;
+        let mut download = res.download_metadata();
+        let mut part = PartFile::create(path.as_ref()).map_err(|e| res.attach_context(e.into()))?;
+        download.size = res
+            .write_to_file(part.file(), self.max_download_size)
+            .await?;
+        part.persist(path.as_ref())
+            .map_err(|e| res.attach_context(e.into()))?;
+        Ok(download)
+    }
+
+    /// Sends a request and writes the response body to the file at `path`, resuming a previously
+    /// interrupted download if possible.
+    ///
+    /// The body is received into `<path>.part`, which is kept if the download fails, along with
+    /// the `ETag` or `Last-Modified` validator of the response in `<path>.part.meta`. The next
+    /// call then requests the rest of the body with the `Range` and `If-Range` headers, and
+    /// appends it to the partial file if the server responds with `206 Partial Content` and a
+    /// matching `Content-Range`. If the resource has changed in the meantime or the server does
+    /// not support ranges, the full body is downloaded again. The partial file is renamed to
+    /// `path` once complete.
+    ///
+    /// The size of the whole file is limited by the [`crate::ClientBuilder::max_download_size`]
+    /// option. If the status code does not indicate success,
+    /// [`crate::Error::NonSuccessfulStatusCode`] is returned and the partial file is kept.
+    ///
+    /// Writes to the file are blocking, which is usually acceptable for local disks.
+    pub async fn resume_download_to(
+        &self,
+        req: super::Request,
+        path: impl AsRef<Path>,
+    ) -> crate::Result<Download> {
+        let path = path.as_ref();
+        let mut partial = PartialDownload::open(path)?;
+        let retry = req.try_clone();
+        let mut res = self.request(partial.range_request(req)).await?;
+        let content_range = res.first_header(CONTENT_RANGE);
+        match partial.check(res.status(), content_range.as_deref()) {
+            Resume::Append => {}
+            Resume::Restart => {
+                res = res.with_successful_status()?;
+                partial
+                    .restart(res.validator())
+                    .map_err(|e| res.attach_context(e.into()))?;
+            }
+            Resume::Retry => {
+                // The partial file is useless regardless of whether the request can be retried
+                partial.restart(None)?;
+                let Some(req) = retry else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "partial response does not match the partial file",
+                    )
+                    .into());
+                };
+                res = self.request(req).await?.with_successful_status()?;
+                partial
+                    .restart(res.validator())
+                    .map_err(|e| res.attach_context(e.into()))?;
+            }
+        }
+        let mut download = res.download_metadata();
+        let limit = self
+            .max_download_size
+            .map(|max| max.saturating_sub(partial.offset()));
+        let mut file = partial.file().map_err(|e| res.attach_context(e.into()))?;
+        res.write_to_file(&mut file, limit).await?;
+        download.size = partial
+            .finish(file, path)
+            .map_err(|e| res.attach_context(e.into()))?;
+        Ok(download)
+    }
+
+    /// Returns a handle to the cookies kept by this client, to inspect or modify them between
+    /// requests.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` if cookies are disabled with [`ClientBuilder::no_cookies`], or if the
+    /// backend does not expose its cookie jar.
+    pub fn cookie_jar(&self) -> Option<CookieJar> {
+        self.client.cookie_jar().map(CookieJar::new)
+    }
+
+    /// Saves the cookies to the file set with [`ClientBuilder::cookie_file`] immediately, rather
+    /// than when the client is dropped.
+    ///
+    /// Does nothing if no cookie file is set.
+    pub fn flush_cookies(&self) -> crate::Result<()> {
+        Ok(self.client.flush_cookies()?)
     }
 }
 
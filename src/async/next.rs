@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use nyquest_interface::r#async::AnyAsyncClient;
+
+use super::{Request, Response};
+use crate::client::RetryPolicy;
+use crate::middleware::{BoxFuture, Middleware};
+use crate::redirect::RedirectConfig;
+
+/// The remaining part of a middleware chain for async requests.
+///
+/// See the [`middleware`](crate::middleware) module for more details.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a Arc<dyn AnyAsyncClient>,
+    middlewares: &'a [Arc<dyn Middleware>],
+    retry_policy: Option<&'a RetryPolicy>,
+    redirects: &'a RedirectConfig,
+    base_url: Option<&'a str>,
+}
+
+impl<'a> Next<'a> {
+    pub(super) fn new(
+        client: &'a Arc<dyn AnyAsyncClient>,
+        middlewares: &'a [Arc<dyn Middleware>],
+        retry_policy: Option<&'a RetryPolicy>,
+        redirects: &'a RedirectConfig,
+        base_url: Option<&'a str>,
+    ) -> Self {
+        Self {
+            client,
+            middlewares,
+            retry_policy,
+            redirects,
+            base_url,
+        }
+    }
+
+    /// Returns the [`crate::ClientBuilder::base_url`] option of the client, which relative URLs
+    /// of requests are resolved against.
+    pub fn base_url(&self) -> Option<&'a str> {
+        self.base_url
+    }
+
+    /// Passes the request on to the next middleware, or to the backend if this is the end of the
+    /// chain.
+    ///
+    /// At the end of the chain, the request is retried according to its [`RetryPolicy`].
+    pub fn run(self, req: Request) -> BoxFuture<'a, crate::Result<Response>> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle_async(
+                req,
+                Next {
+                    client: self.client,
+                    middlewares: rest,
+                    retry_policy: self.retry_policy,
+                    redirects: self.redirects,
+                    base_url: self.base_url,
+                },
+            ),
+            None => Box::pin(super::retry::send_with_retry(
+                self.client,
+                req,
+                self.retry_policy,
+                self.redirects,
+            )),
+        }
+    }
+}
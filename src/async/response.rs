@@ -1,17 +1,28 @@
 use std::fmt::Debug;
+use std::fs::File;
 use std::pin::Pin;
+use std::sync::Arc;
 
 #[cfg(all(doc, feature = "async-stream"))]
 use nyquest_interface::r#async::futures_io;
 use nyquest_interface::r#async::AnyAsyncResponse;
+#[cfg(not(feature = "async-stream"))]
+use nyquest_interface::Error as ErrorImpl;
+use nyquest_interface::RedirectHop as RedirectHopImpl;
 
 #[cfg(feature = "async-stream")]
 use super::AsyncReadStream;
+use crate::download::{validator, Download};
+use crate::error::{attach_context, RequestContext};
+use crate::header::{CONTENT_TYPE, ETAG, LAST_MODIFIED};
+use crate::ErrorResponse;
 use crate::StatusCode;
 
 /// An async HTTP response.
 pub struct Response {
     inner: Pin<Box<dyn AnyAsyncResponse>>,
+    redirect_history: Vec<RedirectHopImpl>,
+    context: Option<Arc<RequestContext>>,
 }
 
 impl Response {
@@ -28,10 +39,35 @@ impl Response {
         if status.is_successful() {
             Ok(self)
         } else {
-            Err(crate::Error::NonSuccessfulStatusCode(status))
+            Err(self.attach_context(crate::Error::NonSuccessfulStatusCode(status)))
         }
     }
 
+    /// Return the response as-is, or [`crate::Error::UnsuccessfulResponse`] if the status code
+    /// does not indicate success.
+    ///
+    /// Unlike [`Self::with_successful_status`], the headers and at most `limit` bytes of the body
+    /// are kept in the error so that the error payload can be inspected.
+    ///
+    /// Only with the `async-stream` feature does reading stop after `limit` bytes. Otherwise the whole
+    /// body is buffered first, up to [`crate::ClientBuilder::max_response_buffer_size`], and an
+    /// empty truncated body is kept if it exceeds that size.
+    pub async fn error_for_status_with_body(mut self, limit: usize) -> crate::Result<Self> {
+        let status = self.status();
+        if status.is_successful() {
+            return Ok(self);
+        }
+        let headers = self.inner.headers().unwrap_or_default().into();
+        let (body, truncated) = self.read_prefix(limit).await;
+        let res = ErrorResponse {
+            status,
+            headers,
+            body,
+            truncated,
+        };
+        Err(self.attach_context(crate::Error::UnsuccessfulResponse(Box::new(res))))
+    }
+
     /// Get the `content-length` of this response, if known by the backend.
     pub fn content_length(&self) -> Option<u64> {
         self.inner.content_length()
@@ -42,7 +78,118 @@ impl Response {
     /// Multiple values may be returned if the header is present multiple times, depending on the
     /// backend implementation.
     pub fn get_header(&self, header: &str) -> crate::Result<Vec<String>> {
-        Ok(self.inner.get_header(header)?)
+        self.inner
+            .get_header(header)
+            .map_err(|e| self.attach_context(e.into()))
+    }
+
+    /// Get all response headers as an ordered, case-insensitive multimap.
+    pub fn headers(&self) -> crate::Result<crate::header::HeaderMap> {
+        match self.inner.headers() {
+            Ok(headers) => Ok(headers.into()),
+            Err(e) => Err(self.attach_context(e.into())),
+        }
+    }
+
+    /// Get the effective URL of this response after following redirects, if known by the
+    /// backend.
+    pub fn url(&self) -> Option<String> {
+        self.inner.url()
+    }
+
+    /// Get the HTTP protocol version of this response, if known by the backend.
+    pub fn version(&self) -> Option<crate::HttpVersion> {
+        self.inner.version().map(Into::into)
+    }
+
+    /// Get the redirects followed before receiving this response, in order.
+    ///
+    /// An empty list is returned if no redirect was followed, or if the backend is not able to
+    /// track redirects.
+    pub fn redirect_history(&self) -> Vec<crate::redirect::RedirectHop> {
+        self.redirect_history
+            .iter()
+            .cloned()
+            .chain(self.inner.redirect_history())
+            .map(Into::into)
+            .collect()
+    }
+
+    pub(super) fn with_redirect_history(mut self, history: Vec<RedirectHopImpl>) -> Self {
+        self.redirect_history = history;
+        self
+    }
+
+    pub(super) fn with_context(mut self, context: Option<Arc<RequestContext>>) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub(super) fn attach_context(&self, e: crate::Error) -> crate::Error {
+        attach_context(self.context.as_deref(), e)
+    }
+
+    /// Collects the metadata of a download of this response, with the size left to be filled.
+    pub(super) fn download_metadata(&self) -> Download {
+        Download {
+            status: self.status(),
+            size: 0,
+            content_type: self.first_header(CONTENT_TYPE),
+            url: self.inner.url(),
+        }
+    }
+
+    /// Get the validator to resume a download of this response with.
+    pub(super) fn validator(&self) -> Option<String> {
+        validator(self.first_header(ETAG), self.first_header(LAST_MODIFIED))
+    }
+
+    pub(super) fn first_header(&self, header: &str) -> Option<String> {
+        let values = self.inner.get_header(header).ok()?;
+        values.into_iter().next()
+    }
+
+    /// Writes the body to the end of `file`, returning the number of bytes written.
+    pub(super) async fn write_to_file(
+        &mut self,
+        file: &mut File,
+        limit: Option<u64>,
+    ) -> crate::Result<u64> {
+        let res = self.inner.as_mut().write_to_file(file, limit).await;
+        res.map_err(|e| self.attach_context(e.into()))
+    }
+
+    /// Reads at most `limit` bytes of the body, ignoring errors.
+    #[cfg(feature = "async-stream")]
+    async fn read_prefix(&mut self, limit: usize) -> (Vec<u8>, bool) {
+        let mut body = vec![];
+        let mut buf = [0; 8192];
+        while body.len() <= limit {
+            let read = std::future::poll_fn(|cx| self.inner.as_mut().poll_read(cx, &mut buf)).await;
+            match read {
+                Ok(0) | Err(_) => break,
+                Ok(n) => body.extend_from_slice(&buf[..n]),
+            }
+        }
+        let truncated = body.len() > limit;
+        body.truncate(limit);
+        (body, truncated)
+    }
+
+    /// Reads at most `limit` bytes of the body, ignoring errors.
+    ///
+    /// Without streaming the backends can only read the body as a whole, so it is buffered up to
+    /// the response buffer size of the client before being cut to `limit`.
+    #[cfg(not(feature = "async-stream"))]
+    async fn read_prefix(&mut self, limit: usize) -> (Vec<u8>, bool) {
+        match self.inner.as_mut().bytes().await {
+            Ok(mut body) => {
+                let truncated = body.len() > limit;
+                body.truncate(limit);
+                (body, truncated)
+            }
+            Err(e) => (vec![], matches!(e, ErrorImpl::ResponseTooLarge)),
+        }
     }
 
     /// Get the full response text.
@@ -55,7 +202,8 @@ impl Response {
     /// receive the response body within the limit, [`crate::Error::ResponseTooLarge`] will be
     /// returned.
     pub async fn text(mut self) -> crate::Result<String> {
-        Ok(self.inner.as_mut().text().await?)
+        let res = self.inner.as_mut().text().await;
+        res.map_err(|e| self.attach_context(e.into()))
     }
 
     /// Get the full response bytes.
@@ -65,7 +213,8 @@ impl Response {
     /// receive the response body within the limit, [`crate::Error::ResponseTooLarge`] will be
     /// returned.
     pub async fn bytes(mut self) -> crate::Result<Vec<u8>> {
-        Ok(self.inner.as_mut().bytes().await?)
+        let res = self.inner.as_mut().bytes().await;
+        res.map_err(|e| self.attach_context(e.into()))
     }
 
     /// Get the full response bytes and deserialize into the given type.
@@ -77,7 +226,28 @@ impl Response {
     #[cfg(feature = "json")]
     #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
     pub async fn json<T: serde::de::DeserializeOwned>(self) -> crate::Result<T> {
-        Ok(serde_json::from_slice(&self.bytes().await?)?)
+        let context = self.context.clone();
+        let bytes = self.bytes().await?;
+        serde_json::from_slice(&bytes).map_err(|e| attach_context(context.as_deref(), e.into()))
+    }
+
+    /// Deserialize the full response body into `T` if the status code indicates success, or into
+    /// `E` otherwise, e.g. a problem details payload.
+    ///
+    /// The maximum size of the response is limited by the
+    /// [`crate::ClientBuilder::max_response_buffer_size`] option.
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub async fn json_or_error<T, E>(self) -> crate::Result<Result<T, E>>
+    where
+        T: serde::de::DeserializeOwned,
+        E: serde::de::DeserializeOwned,
+    {
+        if self.status().is_successful() {
+            Ok(Ok(self.json().await?))
+        } else {
+            Ok(Err(self.json().await?))
+        }
     }
 
     /// Turn the response body into a [`futures_io::AsyncRead`] stream.
@@ -90,7 +260,11 @@ impl Response {
 
 impl From<Pin<Box<dyn AnyAsyncResponse>>> for Response {
     fn from(inner: Pin<Box<dyn AnyAsyncResponse>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            redirect_history: vec![],
+            context: None,
+        }
     }
 }
 
@@ -0,0 +1,223 @@
+#[cfg(feature = "async-stream")]
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(feature = "async-stream")]
+use std::sync::Mutex;
+#[cfg(feature = "async-stream")]
+use std::task::{Context, Poll};
+
+use nyquest_interface::r#async::AnyAsyncClient;
+#[cfg(feature = "async-stream")]
+use nyquest_interface::{r#async::SizedBodyStream, Body as BodyImpl};
+
+use super::redirect::send;
+use super::timer::Delay;
+use super::{Request, Response};
+use crate::client::{RetryOutcome, RetryPolicy};
+use crate::redirect::RedirectConfig;
+
+pub(super) async fn send_with_retry(
+    client: &Arc<dyn AnyAsyncClient>,
+    mut req: Request,
+    default_policy: Option<&RetryPolicy>,
+    redirects: &RedirectConfig,
+) -> crate::Result<Response> {
+    let policy = req.retry_policy.take().or_else(|| default_policy.cloned());
+    let Some(policy) =
+        policy.filter(|p| p.max_attempts() > 1 && p.allows_method(&req.inner.method))
+    else {
+        return send(client, req, redirects).await;
+    };
+    let mut replay = match Replay::new(req).await {
+        Ok(replay) => replay,
+        Err(req) => return send(client, *req, redirects).await,
+    };
+
+    let mut attempt = 1;
+    loop {
+        if attempt > 1 {
+            replay.rewind().await?;
+        }
+        let res = send(client, replay.next_request(), redirects).await;
+        if attempt >= policy.max_attempts() {
+            return res;
+        }
+        let (retry, retry_after) = match &res {
+            Ok(res) => (
+                policy.should_retry(RetryOutcome::Status(res.status())),
+                res.get_header("retry-after")
+                    .ok()
+                    .and_then(|v| v.into_iter().next()),
+            ),
+            Err(e) => (policy.should_retry(RetryOutcome::Error(e)), None),
+        };
+        if !retry {
+            return res;
+        }
+        let Some(delay) = policy.delay(attempt, retry_after.as_deref()) else {
+            return res;
+        };
+        drop(res);
+        Delay::new(delay).await;
+        attempt += 1;
+    }
+}
+
+/// A request that can be sent multiple times.
+enum Replay {
+    Clone(Request),
+    #[cfg(feature = "async-stream")]
+    Stream {
+        template: Request,
+        stream: ReplayStream,
+    },
+}
+
+impl Replay {
+    async fn new(req: Request) -> Result<Self, Box<Request>> {
+        if let Some(template) = req.try_clone() {
+            return Ok(Self::Clone(template));
+        }
+        #[cfg(feature = "async-stream")]
+        {
+            ReplayStream::extract(req)
+                .await
+                .map(|(template, stream)| Self::Stream { template, stream })
+        }
+        #[cfg(not(feature = "async-stream"))]
+        Err(Box::new(req))
+    }
+
+    async fn rewind(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Clone(_) => Ok(()),
+            #[cfg(feature = "async-stream")]
+            Self::Stream { stream, .. } => stream.rewind().await,
+        }
+    }
+
+    fn next_request(&self) -> Request {
+        match self {
+            Self::Clone(template) => template
+                .try_clone()
+                .expect("replay template must be cloneable"),
+            #[cfg(feature = "async-stream")]
+            Self::Stream { template, stream } => {
+                let mut req = template
+                    .try_clone()
+                    .expect("replay template must be cloneable");
+                req.inner.body = Some(stream.to_body());
+                req
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async-stream")]
+struct ReplayStream {
+    stream: SharedStream,
+    start: u64,
+    content_length: u64,
+    content_type: std::borrow::Cow<'static, str>,
+}
+
+#[cfg(feature = "async-stream")]
+impl ReplayStream {
+    async fn extract(mut req: Request) -> Result<(Request, Self), Box<Request>> {
+        use nyquest_interface::r#async::BoxedStream;
+
+        let Some(BodyImpl::Stream {
+            stream:
+                BoxedStream::Sized {
+                    stream,
+                    content_length,
+                },
+            content_type,
+        }) = req.inner.body.take()
+        else {
+            return Err(Box::new(req));
+        };
+        let stream = SharedStream(Arc::new(Mutex::new(stream)));
+        let Ok(start) = stream.seek(std::io::SeekFrom::Current(0)).await else {
+            let stream = Arc::into_inner(stream.0)
+                .expect("stream must not be shared yet")
+                .into_inner()
+                .unwrap_or_else(|e| e.into_inner());
+            req.inner.body = Some(BodyImpl::Stream {
+                stream: BoxedStream::Sized {
+                    stream,
+                    content_length,
+                },
+                content_type,
+            });
+            return Err(Box::new(req));
+        };
+        let template = req
+            .try_clone()
+            .expect("request without body must be cloneable");
+        Ok((
+            template,
+            Self {
+                stream,
+                start,
+                content_length,
+                content_type,
+            },
+        ))
+    }
+
+    async fn rewind(&self) -> std::io::Result<()> {
+        self.stream
+            .seek(std::io::SeekFrom::Start(self.start))
+            .await?;
+        Ok(())
+    }
+
+    fn to_body(&self) -> BodyImpl<nyquest_interface::r#async::BoxedStream> {
+        BodyImpl::Stream {
+            stream: nyquest_interface::r#async::BoxedStream::Sized {
+                stream: Box::pin(self.stream.clone()),
+                content_length: self.content_length,
+            },
+            content_type: self.content_type.clone(),
+        }
+    }
+}
+
+/// A stream shared between attempts so that it can be rewound after being handed to the backend.
+#[cfg(feature = "async-stream")]
+#[derive(Clone)]
+struct SharedStream(Arc<Mutex<Pin<Box<dyn SizedBodyStream>>>>);
+
+#[cfg(feature = "async-stream")]
+impl SharedStream {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Pin<Box<dyn SizedBodyStream>>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn seek(&self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        std::future::poll_fn(|cx| self.lock().as_mut().poll_seek(cx, pos)).await
+    }
+}
+
+#[cfg(feature = "async-stream")]
+impl nyquest_interface::r#async::futures_io::AsyncRead for SharedStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.lock().as_mut().poll_read(cx, buf)
+    }
+}
+
+#[cfg(feature = "async-stream")]
+impl nyquest_interface::r#async::futures_io::AsyncSeek for SharedStream {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: std::io::SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        self.lock().as_mut().poll_seek(cx, pos)
+    }
+}
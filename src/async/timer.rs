@@ -0,0 +1,164 @@
+//! A timer that does not depend on any async runtime.
+//!
+//! All delays are waited for on a single background thread, started on first use, which wakes
+//! them in the order of their deadlines. Dropping a delay removes it from the timer.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+/// The key of a delay in the timer, unique even for equal deadlines.
+type Key = (Instant, u64);
+
+#[derive(Default)]
+struct Timer {
+    state: Mutex<TimerState>,
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct TimerState {
+    entries: BTreeMap<Key, Waker>,
+    next_id: u64,
+}
+
+impl Timer {
+    fn get() -> &'static Self {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        TIMER.get_or_init(|| {
+            std::thread::Builder::new()
+                .name("nyquest-timer".into())
+                .spawn(|| Self::get().run())
+                .expect("failed to spawn nyquest timer");
+            Self::default()
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TimerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            let now = Instant::now();
+            let mut elapsed = vec![];
+            while let Some(entry) = state.entries.first_entry() {
+                if entry.key().0 > now {
+                    break;
+                }
+                elapsed.push(entry.remove());
+            }
+            if !elapsed.is_empty() {
+                // Wake outside the lock, as waking may poll the delays right away
+                drop(state);
+                elapsed.into_iter().for_each(Waker::wake);
+                state = self.lock();
+                continue;
+            }
+            state = match state.entries.keys().next() {
+                Some(&(deadline, _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+                None => self.changed.wait(state).unwrap_or_else(|e| e.into_inner()),
+            };
+        }
+    }
+}
+
+/// A future completing after a duration.
+pub(super) struct Delay {
+    deadline: Instant,
+    key: Option<Key>,
+}
+
+impl Delay {
+    pub(super) fn new(duration: Duration) -> Self {
+        // Deadlines too far away to be represented are never reached
+        let deadline = Instant::now()
+            .checked_add(duration)
+            .unwrap_or_else(|| Instant::now() + Duration::from_secs(60 * 60 * 24 * 365 * 30));
+        Self {
+            deadline,
+            key: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let timer = Timer::get();
+        let mut state = timer.lock();
+        if let Some(waker) = self.key.and_then(|key| state.entries.get_mut(&key)) {
+            waker.clone_from(cx.waker());
+            return Poll::Pending;
+        }
+        let key = (self.deadline, state.next_id);
+        state.next_id += 1;
+        state.entries.insert(key, cx.waker().clone());
+        let earliest = state.entries.keys().next() == Some(&key);
+        drop(state);
+        self.key = Some(key);
+        if earliest {
+            timer.changed.notify_one();
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Delay {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            Timer::get().lock().entries.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::task::Wake;
+    use std::thread::Thread;
+
+    use super::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    #[test]
+    fn test_delay() {
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let start = Instant::now();
+        let mut delay = Box::pin(Delay::new(Duration::from_millis(50)));
+        // A later delay dropped before the earlier one must not hold it up
+        let mut dropped = Box::pin(Delay::new(Duration::from_secs(60)));
+        assert!(dropped.as_mut().poll(&mut cx).is_pending());
+        let key = dropped.key.unwrap();
+        drop(dropped);
+        assert!(!Timer::get().lock().entries.contains_key(&key));
+
+        while delay.as_mut().poll(&mut cx).is_pending() {
+            std::thread::park_timeout(Duration::from_secs(5));
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
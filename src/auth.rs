@@ -0,0 +1,352 @@
+//! HTTP authentication.
+//!
+//! Basic, Bearer and Digest credentials are set for all requests of a client with
+//! [`ClientBuilder::basic_auth`], [`ClientBuilder::bearer_token`] and
+//! [`ClientBuilder::digest_auth`], or for a single request with [`Request::with_basic_auth`] and
+//! [`Request::with_bearer_token`]. They are applied by nyquest itself, so every backend supports
+//! them.
+//!
+//! Redirects of requests with credentials are followed by nyquest instead of the backend, under
+//! the redirect policy of the client. The credentials are dropped when a redirect leads to another
+//! origin, and Digest challenges are answered by the origin the request was redirected to.
+//!
+//! Access tokens can also be obtained from an OAuth 2.0 authorization server with the
+//! [`OAuth2ClientCredentials`] middleware, and requests to AWS APIs signed with [`AwsSigV4`].
+//!
+//! [`ClientBuilder::basic_auth`]: crate::ClientBuilder::basic_auth
+//! [`ClientBuilder::bearer_token`]: crate::ClientBuilder::bearer_token
+//! [`ClientBuilder::digest_auth`]: crate::ClientBuilder::digest_auth
+//! [`Request::with_basic_auth`]: crate::Request::with_basic_auth
+//! [`Request::with_bearer_token`]: crate::Request::with_bearer_token
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+#[cfg(any(feature = "async", feature = "blocking"))]
+use md5::{Digest as _, Md5};
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+use crate::{Method, Request};
+
+#[cfg(feature = "json")]
+mod oauth2;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod sigv4;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub use oauth2::OAuth2ClientCredentials;
+#[cfg(any(feature = "async", feature = "blocking"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "async", feature = "blocking"))))]
+pub use sigv4::AwsSigV4;
+
+/// Credentials to authenticate requests with.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum Auth {
+    Basic { username: String, password: String },
+    Bearer { token: String },
+    Digest { username: String, password: String },
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the credentials
+        match self {
+            Self::Basic { .. } => f.write_str("Auth::Basic"),
+            Self::Bearer { .. } => f.write_str("Auth::Bearer"),
+            Self::Digest { .. } => f.write_str("Auth::Digest"),
+        }
+    }
+}
+
+/// Adds the `Authorization` header for the credentials of `req`, unless the request has one
+/// already.
+///
+/// Returns the credentials if they can only be sent in response to a challenge.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn prepare<S>(req: &mut Request<S>) -> Option<Auth> {
+    let auth = req.auth.take()?;
+    if req
+        .inner
+        .additional_headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+    {
+        return None;
+    }
+    let value = match &auth {
+        Auth::Basic { username, password } => {
+            let credentials = format!("{username}:{password}");
+            format!("Basic {}", BASE64_STANDARD.encode(credentials))
+        }
+        Auth::Bearer { token } => format!("Bearer {token}"),
+        Auth::Digest { .. } => return Some(auth),
+    };
+    req.inner
+        .additional_headers
+        .push(("authorization".into(), value.into()));
+    None
+}
+
+/// Builds the request answering the Digest challenge of a 401 response, if the challenge can be
+/// answered.
+///
+/// `retry` is a copy of the request that received the response at `url`, which must not have
+/// been redirected, so that the credentials are only sent to the origin that asked for them.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn answer_challenge<S>(
+    auth: &Auth,
+    mut retry: Request<S>,
+    url: Option<&str>,
+    challenges: &[String],
+) -> Option<Request<S>> {
+    let Auth::Digest { username, password } = auth else {
+        return None;
+    };
+    let uri = request_target(url?)?;
+    let challenge = challenges
+        .iter()
+        .find_map(|value| DigestChallenge::parse(value))?;
+    let method = Method::from(retry.inner.method.clone());
+    let value = challenge.answer(username, password, method.as_str(), uri, &new_cnonce());
+    retry
+        .inner
+        .additional_headers
+        .push(("authorization".into(), value.into()));
+    Some(retry)
+}
+
+/// Returns the path and query of an absolute URL, as used in the request line.
+fn request_target(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+    Some(match rest.find(['/', '?']) {
+        Some(start) if rest[start..].starts_with('/') => &rest[start..],
+        _ => "/",
+    })
+}
+
+/// A `WWW-Authenticate` challenge of the Digest scheme.
+#[derive(Debug, PartialEq, Eq)]
+struct DigestChallenge {
+    realm: String,
+    nonce: String,
+    opaque: Option<String>,
+    algorithm: Option<String>,
+    session: bool,
+    qop_auth: bool,
+}
+
+impl DigestChallenge {
+    /// Parses a challenge, returning `None` if it is not a Digest challenge or cannot be answered.
+    fn parse(value: &str) -> Option<Self> {
+        let (scheme, params) = value.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("digest") {
+            return None;
+        }
+        let mut challenge = Self {
+            realm: String::new(),
+            nonce: String::new(),
+            opaque: None,
+            algorithm: None,
+            session: false,
+            qop_auth: false,
+        };
+        let mut has_nonce = false;
+        for (name, value) in parse_params(params) {
+            match &*name {
+                "realm" => challenge.realm = value,
+                "nonce" => {
+                    challenge.nonce = value;
+                    has_nonce = true;
+                }
+                "opaque" => challenge.opaque = Some(value),
+                "algorithm" => {
+                    if value.eq_ignore_ascii_case("md5-sess") {
+                        challenge.session = true;
+                    } else if !value.eq_ignore_ascii_case("md5") {
+                        return None;
+                    }
+                    challenge.algorithm = Some(value);
+                }
+                "qop" => {
+                    // auth-int is not supported, as it requires hashing the body
+                    if !value.split(',').any(|qop| qop.trim() == "auth") {
+                        return None;
+                    }
+                    challenge.qop_auth = true;
+                }
+                _ => {}
+            }
+        }
+        has_nonce.then_some(challenge)
+    }
+
+    /// Builds the value of the `Authorization` header answering the challenge.
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    fn answer(
+        &self,
+        username: &str,
+        password: &str,
+        method: &str,
+        uri: &str,
+        cnonce: &str,
+    ) -> String {
+        const NC: &str = "00000001";
+        let nonce = &self.nonce;
+        let mut ha1 = md5_hex(format!("{username}:{}:{password}", self.realm).as_bytes());
+        if self.session {
+            ha1 = md5_hex(format!("{ha1}:{nonce}:{cnonce}").as_bytes());
+        }
+        let ha2 = md5_hex(format!("{method}:{uri}").as_bytes());
+        let response = if self.qop_auth {
+            md5_hex(format!("{ha1}:{nonce}:{NC}:{cnonce}:auth:{ha2}").as_bytes())
+        } else {
+            md5_hex(format!("{ha1}:{nonce}:{ha2}").as_bytes())
+        };
+
+        let mut value = format!(
+            "Digest username={}, realm={}, nonce={}, uri={}, response=\"{response}\"",
+            quote(username),
+            quote(&self.realm),
+            quote(nonce),
+            quote(uri),
+        );
+        if let Some(algorithm) = &self.algorithm {
+            value.push_str(&format!(", algorithm={algorithm}"));
+        }
+        if self.qop_auth {
+            value.push_str(&format!(", qop=auth, nc={NC}, cnonce={}", quote(cnonce)));
+        }
+        if let Some(opaque) = &self.opaque {
+            value.push_str(&format!(", opaque={}", quote(opaque)));
+        }
+        value
+    }
+}
+
+/// Parses comma separated `name=value` pairs, where values may be quoted strings.
+fn parse_params(mut rest: &str) -> Vec<(String, String)> {
+    let mut params = vec![];
+    loop {
+        rest = rest.trim_start_matches([',', ' ', '\t']);
+        let Some((name, value)) = rest.split_once('=') else {
+            return params;
+        };
+        let name = name.trim().to_ascii_lowercase();
+        let value = value.trim_start();
+        if let Some(quoted) = value.strip_prefix('"') {
+            let mut unquoted = String::new();
+            let mut end = quoted.len();
+            let mut chars = quoted.char_indices();
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '\\' => unquoted.extend(chars.next().map(|(_, c)| c)),
+                    '"' => {
+                        end = i + 1;
+                        break;
+                    }
+                    c => unquoted.push(c),
+                }
+            }
+            params.push((name, unquoted));
+            rest = &quoted[end..];
+        } else {
+            let end = value.find(',').unwrap_or(value.len());
+            params.push((name, value[..end].trim().to_owned()));
+            rest = &value[end..];
+        }
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn new_cnonce() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    hasher.write_u128(now.as_nanos());
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+fn md5_hex(input: &[u8]) -> String {
+    hex(&Md5::digest(input))
+}
+
+/// Encodes bytes as lowercase hexadecimal.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacted() {
+        let auth = Auth::Basic {
+            username: "user".into(),
+            password: "secret".into(),
+        };
+        let builder = crate::ClientBuilder::default().bearer_token("secret");
+        let request = crate::Request::<()>::get("/").with_basic_auth("user", "secret");
+        for debug in [
+            format!("{auth:?}"),
+            format!("{builder:?}"),
+            format!("{request:?}"),
+        ] {
+            assert!(!debug.contains("secret"), "{debug}");
+        }
+    }
+
+    #[test]
+    fn test_request_target() {
+        assert_eq!(request_target("http://a/b/c?d#e"), Some("/b/c?d"));
+        assert_eq!(request_target("http://a"), Some("/"));
+        assert_eq!(request_target("http://a?b"), Some("/"));
+        assert_eq!(request_target("/relative"), None);
+    }
+
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    #[test]
+    fn test_digest_answer() {
+        // The example in RFC 2617 section 3.5
+        let challenge = DigestChallenge::parse(
+            r#"Digest realm="testrealm@host.com", qop="auth,auth-int", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+        )
+        .unwrap();
+        let value = challenge.answer(
+            "Mufasa",
+            "Circle Of Life",
+            "GET",
+            "/dir/index.html",
+            "0a4f113b",
+        );
+        assert_eq!(
+            value,
+            r#"Digest username="Mufasa", realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", uri="/dir/index.html", response="6629fae49393a05397450978507c4ef1", qop=auth, nc=00000001, cnonce="0a4f113b", opaque="5ccc069c403ebaf9f0171e9517f40e41""#
+        );
+    }
+
+    #[test]
+    fn test_digest_challenge_unsupported() {
+        assert_eq!(DigestChallenge::parse(r#"Basic realm="x""#), None);
+        assert_eq!(
+            DigestChallenge::parse(r#"Digest realm="x", nonce="y", algorithm=SHA-256"#),
+            None
+        );
+        assert_eq!(
+            DigestChallenge::parse(r#"Digest realm="x", nonce="y", qop="auth-int""#),
+            None
+        );
+        assert_eq!(DigestChallenge::parse(r#"Digest realm="x""#), None);
+    }
+}
@@ -0,0 +1,412 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::task::Waker;
+use std::time::{Duration, Instant};
+
+use serde::de::Error as _;
+
+use crate::middleware::Middleware;
+use crate::{Body, Request};
+
+/// How long before its expiry a token is refreshed, to account for latency and clock skew.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// A [`Middleware`] authenticating requests with an access token obtained with the OAuth 2.0
+/// client credentials grant, as described in RFC 6749 section 4.4.
+///
+/// The token is requested from the token endpoint with the first request made with the client,
+/// and attached to every request as a bearer token. It is cached until shortly before it expires,
+/// and requested again afterwards. When a request is rejected with `401 Unauthorized`, e.g.
+/// because the token has been revoked, a new token is requested and the request is sent again
+/// once. Concurrent requests missing a token wait for a single token request instead of sending
+/// their own.
+///
+/// The token requests are sent with the client the middleware is registered on, through the
+/// middlewares registered after this one. The client credentials are sent with HTTP Basic
+/// authentication unless [`Self::with_credentials_in_body`] is set.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn main() -> nyquest::Result<()> {
+/// use nyquest::auth::OAuth2ClientCredentials;
+///
+/// let client = nyquest::ClientBuilder::default()
+///     .base_url("https://api.example.com/")
+///     .with_middleware(
+///         OAuth2ClientCredentials::new("https://auth.example.com/token", "my-client", "secret")
+///             .with_scope("read write"),
+///     )
+///     .build_blocking()?;
+/// let res = client.request(nyquest::Request::get("users"))?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "blocking"))]
+/// # fn main() {}
+/// ```
+pub struct OAuth2ClientCredentials {
+    token_url: Cow<'static, str>,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    credentials_in_body: bool,
+    token: Mutex<Option<AccessToken>>,
+    refresh: RefreshLock,
+}
+
+struct AccessToken {
+    value: String,
+    refresh_at: Option<Instant>,
+}
+
+impl OAuth2ClientCredentials {
+    /// Creates the middleware with the URL of the token endpoint and the client credentials.
+    ///
+    /// A relative URL is resolved against the [`crate::ClientBuilder::base_url`] option of the
+    /// client, like the URL of any other request.
+    pub fn new(
+        token_url: impl Into<Cow<'static, str>>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scope: None,
+            credentials_in_body: false,
+            token: Mutex::new(None),
+            refresh: RefreshLock::default(),
+        }
+    }
+
+    /// Requests tokens with the given scope, a space separated list of scope names.
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Sends the client credentials in the form body of token requests instead of the
+    /// `Authorization` header, for servers not supporting HTTP Basic authentication.
+    #[inline]
+    pub fn with_credentials_in_body(mut self) -> Self {
+        self.credentials_in_body = true;
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<AccessToken>> {
+        self.token.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the cached token, if it is not about to expire.
+    fn cached_token(&self) -> Option<String> {
+        let token = self.lock();
+        let token = token.as_ref()?;
+        match token.refresh_at {
+            Some(refresh_at) if refresh_at <= Instant::now() => None,
+            _ => Some(token.value.clone()),
+        }
+    }
+
+    /// Drops the cached token if it is still the rejected one, so that it is requested again.
+    fn invalidate(&self, rejected: &str) {
+        let mut token = self.lock();
+        if token.as_ref().is_some_and(|t| t.value == rejected) {
+            *token = None;
+        }
+    }
+
+    fn token_request<S>(&self) -> Request<S> {
+        let mut fields: Vec<(Cow<'static, str>, Cow<'static, str>)> =
+            vec![("grant_type".into(), "client_credentials".into())];
+        if let Some(scope) = &self.scope {
+            fields.push(("scope".into(), scope.clone().into()));
+        }
+        if self.credentials_in_body {
+            fields.push(("client_id".into(), self.client_id.clone().into()));
+            fields.push(("client_secret".into(), self.client_secret.clone().into()));
+        }
+        let req = Request::post(self.token_url.clone())
+            .with_header("accept", "application/json")
+            .with_body(Body::form(fields));
+        if self.credentials_in_body {
+            req
+        } else {
+            req.with_basic_auth(&*self.client_id, &*self.client_secret)
+        }
+    }
+
+    /// Caches the token in a successful token response, as requested at `requested_at`.
+    fn store_token(
+        &self,
+        response: serde_json::Value,
+        requested_at: Instant,
+    ) -> crate::Result<String> {
+        let (value, lifetime) = parse_token_response(&response)?;
+        // Short-lived tokens are refreshed halfway through instead. Lifetimes too long to be
+        // represented are treated as no expiry.
+        let refresh_at = lifetime.and_then(|lifetime| {
+            requested_at.checked_add(lifetime - EXPIRY_MARGIN.min(lifetime / 2))
+        });
+        *self.lock() = Some(AccessToken {
+            value: value.clone(),
+            refresh_at,
+        });
+        Ok(value)
+    }
+}
+
+/// Serializes the token requests, so that the requests missing a token wait for the token
+/// requested by the first of them instead of requesting their own. Usable from both blocking and
+/// async code, without depending on an async runtime.
+#[derive(Default)]
+struct RefreshLock {
+    state: Mutex<RefreshState>,
+    released: Condvar,
+}
+
+#[derive(Default)]
+struct RefreshState {
+    locked: bool,
+    /// The async tasks waiting for the lock, all woken on release.
+    waiters: Vec<Waker>,
+}
+
+/// Releases the [`RefreshLock`] when dropped, including when a token request is cancelled.
+struct RefreshGuard<'a>(&'a RefreshLock);
+
+impl RefreshLock {
+    fn state(&self) -> MutexGuard<'_, RefreshState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(feature = "blocking")]
+    fn lock_blocking(&self) -> RefreshGuard<'_> {
+        let mut state = self.state();
+        while state.locked {
+            state = self.released.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+        state.locked = true;
+        RefreshGuard(self)
+    }
+
+    #[cfg(feature = "async")]
+    async fn lock_async(&self) -> RefreshGuard<'_> {
+        std::future::poll_fn(|cx| {
+            let mut state = self.state();
+            if !state.locked {
+                state.locked = true;
+                return std::task::Poll::Ready(RefreshGuard(self));
+            }
+            if !state.waiters.iter().any(|w| w.will_wake(cx.waker())) {
+                state.waiters.push(cx.waker().clone());
+            }
+            std::task::Poll::Pending
+        })
+        .await
+    }
+}
+
+impl Drop for RefreshGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.0.state();
+        state.locked = false;
+        let waiters = std::mem::take(&mut state.waiters);
+        drop(state);
+        self.0.released.notify_all();
+        waiters.into_iter().for_each(Waker::wake);
+    }
+}
+
+/// Extracts the access token and its lifetime from a token response.
+fn parse_token_response(response: &serde_json::Value) -> crate::Result<(String, Option<Duration>)> {
+    let value = response
+        .get("access_token")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| serde_json::Error::custom("missing access_token in token response"))?;
+    // Some servers send the lifetime as a string
+    let lifetime = match response.get("expires_in") {
+        Some(serde_json::Value::Number(n)) => n.as_u64(),
+        Some(serde_json::Value::String(s)) => s.parse().ok(),
+        _ => None,
+    };
+    Ok((value.into(), lifetime.map(Duration::from_secs)))
+}
+
+impl Middleware for OAuth2ClientCredentials {
+    #[cfg(feature = "async")]
+    fn handle_async<'a>(
+        &'a self,
+        req: crate::r#async::Request,
+        next: crate::r#async::Next<'a>,
+    ) -> crate::middleware::BoxFuture<'a, crate::Result<crate::r#async::Response>> {
+        let get_token = move || async move {
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let _refresh = self.refresh.lock_async().await;
+            // Another request may have obtained a token while this one was waiting
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let requested_at = Instant::now();
+            let res = next.run(self.token_request()).await?;
+            let response = res.with_successful_status()?.json().await?;
+            self.store_token(response, requested_at)
+        };
+        Box::pin(async move {
+            let token = get_token().await?;
+            let retry = req.try_clone();
+            let res = next.run(req.with_bearer_token(&*token)).await?;
+            let Some(retry) = retry.filter(|_| res.status() == 401) else {
+                return Ok(res);
+            };
+            self.invalidate(&token);
+            let token = get_token().await?;
+            next.run(retry.with_bearer_token(token)).await
+        })
+    }
+
+    #[cfg(feature = "blocking")]
+    fn handle_blocking(
+        &self,
+        req: crate::blocking::Request,
+        next: crate::blocking::Next<'_>,
+    ) -> crate::Result<crate::blocking::Response> {
+        let get_token = || {
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let _refresh = self.refresh.lock_blocking();
+            // Another request may have obtained a token while this one was waiting
+            if let Some(token) = self.cached_token() {
+                return Ok(token);
+            }
+            let requested_at = Instant::now();
+            let res = next.run(self.token_request())?;
+            let response = res.with_successful_status()?.json()?;
+            self.store_token(response, requested_at)
+        };
+        let token = get_token()?;
+        let retry = req.try_clone();
+        let res = next.run(req.with_bearer_token(&*token))?;
+        let Some(retry) = retry.filter(|_| res.status() == 401) else {
+            return Ok(res);
+        };
+        self.invalidate(&token);
+        let token = get_token()?;
+        next.run(retry.with_bearer_token(token))
+    }
+}
+
+impl fmt::Debug for OAuth2ClientCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2ClientCredentials")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_response() {
+        let response = serde_json::json!({
+            "access_token": "abc",
+            "token_type": "Bearer",
+            "expires_in": 3600,
+        });
+        let (value, lifetime) = parse_token_response(&response).unwrap();
+        assert_eq!(value, "abc");
+        assert_eq!(lifetime, Some(Duration::from_secs(3600)));
+
+        let response = serde_json::json!({ "access_token": "abc", "expires_in": "60" });
+        let (_, lifetime) = parse_token_response(&response).unwrap();
+        assert_eq!(lifetime, Some(Duration::from_secs(60)));
+
+        let response = serde_json::json!({ "error": "invalid_client" });
+        assert!(parse_token_response(&response).is_err());
+    }
+
+    #[test]
+    fn test_token_refresh() {
+        let auth = OAuth2ClientCredentials::new("token", "id", "secret");
+        let now = Instant::now();
+        let response = serde_json::json!({ "access_token": "long", "expires_in": 3600 });
+        auth.store_token(response, now).unwrap();
+        assert_eq!(auth.cached_token().as_deref(), Some("long"));
+        assert_eq!(
+            auth.lock().as_ref().unwrap().refresh_at,
+            Some(now + Duration::from_secs(3600) - EXPIRY_MARGIN)
+        );
+
+        let response = serde_json::json!({ "access_token": "short", "expires_in": 10 });
+        auth.store_token(response, now).unwrap();
+        assert_eq!(
+            auth.lock().as_ref().unwrap().refresh_at,
+            Some(now + Duration::from_secs(5))
+        );
+
+        let response = serde_json::json!({ "access_token": "expired", "expires_in": 0 });
+        auth.store_token(response, now).unwrap();
+        assert_eq!(auth.cached_token(), None);
+
+        let response = serde_json::json!({ "access_token": "huge", "expires_in": u64::MAX });
+        auth.store_token(response, now).unwrap();
+        assert_eq!(auth.lock().as_ref().unwrap().refresh_at, None);
+        assert_eq!(auth.cached_token().as_deref(), Some("huge"));
+
+        let response = serde_json::json!({ "access_token": "forever" });
+        auth.store_token(response, now).unwrap();
+        auth.invalidate("other");
+        assert_eq!(auth.cached_token().as_deref(), Some("forever"));
+        auth.invalidate("forever");
+        assert_eq!(auth.cached_token(), None);
+    }
+
+    #[cfg(all(feature = "async", feature = "blocking"))]
+    #[test]
+    fn test_refresh_lock() {
+        use std::future::Future;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::task::{Context, Wake};
+
+        struct FlagWaker(AtomicBool);
+
+        impl Wake for FlagWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let lock = RefreshLock::default();
+        let guard = lock.lock_blocking();
+        let flag = Arc::new(FlagWaker(AtomicBool::new(false)));
+        let waker = Waker::from(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut waiting = Box::pin(lock.lock_async());
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+        std::thread::scope(|s| {
+            let blocked = s.spawn(|| drop(lock.lock_blocking()));
+            std::thread::sleep(Duration::from_millis(50));
+            assert!(!blocked.is_finished());
+            drop(guard);
+            blocked.join().unwrap();
+        });
+        assert!(flag.0.load(Ordering::SeqCst));
+        assert!(waiting.as_mut().poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn test_debug_redacted() {
+        let auth = OAuth2ClientCredentials::new("token", "id", "secret");
+        assert!(!format!("{auth:?}").contains("secret"));
+    }
+}
@@ -8,10 +8,14 @@ use std::borrow::Cow;
 #[cfg(feature = "blocking-stream")]
 use nyquest_interface::blocking::{BoxedStream, SizedBodyStream, UnsizedBodyStream};
 
+mod auth;
 pub(crate) mod client;
+mod next;
 #[cfg(feature = "blocking-stream")]
 mod read_stream;
+mod redirect;
 mod response;
+mod retry;
 
 #[cfg(not(feature = "blocking-stream"))]
 type BoxedStream = std::convert::Infallible;
@@ -26,6 +30,7 @@ pub type Part = crate::body::Part<BoxedStream>;
 /// The multipart form part body type for blocking requests.
 #[cfg(feature = "multipart")]
 pub type PartBody = crate::body::PartBody<BoxedStream>;
+pub use next::Next;
 #[cfg(feature = "blocking-stream")]
 pub use read_stream::ReadStream;
 pub use response::Response;
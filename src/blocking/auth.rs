@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use nyquest_interface::blocking::AnyBlockingClient;
+
+use super::redirect::send_once;
+use super::{Request, Response};
+
+/// Sends a request with its credentials, answering a Digest challenge if needed.
+pub(super) fn send(
+    client: &Arc<dyn AnyBlockingClient>,
+    mut req: Request,
+) -> crate::Result<Response> {
+    let Some(auth) = crate::auth::prepare(&mut req) else {
+        return send_once(client, req);
+    };
+    let retry = req.try_clone();
+    let res = send_once(client, req)?;
+    let Some(retry) = retry.filter(|_| res.status() == 401 && res.redirect_history().is_empty())
+    else {
+        return Ok(res);
+    };
+    let challenges = res.get_header("www-authenticate")?;
+    match crate::auth::answer_challenge(&auth, retry, res.url().as_deref(), &challenges) {
+        Some(retry) => send_once(client, retry),
+        None => Ok(res),
+    }
+}
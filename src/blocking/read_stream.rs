@@ -3,6 +3,9 @@ use std::io;
 use nyquest_interface::blocking::AnyBlockingResponse;
 
 /// An [`std::io::Read`] stream backed by a blocking response.
+///
+/// If the request was sent with a [`crate::CancellationToken`], reads fail with an
+/// [`io::Error`] wrapping [`crate::Error::Cancelled`] once the token is cancelled.
 pub struct ReadStream {
     inner: Box<dyn AnyBlockingResponse>,
 }
@@ -15,7 +18,13 @@ impl ReadStream {
 
 impl io::Read for ReadStream {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        self.inner.read(buf)
+        self.inner.read(buf).map_err(|e| {
+            if crate::error::is_cancelled_io(&e) {
+                io::Error::other(crate::Error::Cancelled)
+            } else {
+                e
+            }
+        })
     }
 }
 
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use nyquest_interface::blocking::AnyBlockingClient;
+
+use super::{Request, Response};
+use crate::redirect::RedirectConfig;
+
+/// Sends a request, following redirects by itself if the backend must not follow them for the
+/// request.
+pub(super) fn send(
+    client: &Arc<dyn AnyBlockingClient>,
+    mut req: Request,
+    redirects: &RedirectConfig,
+) -> crate::Result<Response> {
+    let Some(policy) = redirects.policy_for(&req) else {
+        return super::auth::send(client, req);
+    };
+    // Redirects are followed here, so the backend must not follow them
+    req.inner.options.follow_redirects = Some(false);
+    let mut history = vec![];
+    loop {
+        let head = req.clone_without_body();
+        let full = req.try_clone();
+        let res = super::auth::send(client, req)?;
+        let location = res.get_header("location")?.into_iter().next();
+        let next = crate::redirect::next_request(
+            policy,
+            res.status().code(),
+            res.url(),
+            location,
+            head,
+            full,
+            history.len() as u32,
+        )?;
+        let Some((hop, next)) = next else {
+            return Ok(res.with_redirect_history(history));
+        };
+        history.push(hop);
+        req = next;
+    }
+}
+
+pub(super) fn send_once(
+    client: &Arc<dyn AnyBlockingClient>,
+    req: Request,
+) -> crate::Result<Response> {
+    if req
+        .inner
+        .cancellation
+        .as_ref()
+        .is_some_and(|t| t.is_cancelled())
+    {
+        return Err(crate::Error::Cancelled);
+    }
+    let res = match req.progress {
+        Some(progress) => client.request_with_progress(req.inner, progress)?,
+        None => client.request(req.inner)?,
+    };
+    Ok(res.into())
+}
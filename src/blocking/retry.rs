@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use nyquest_interface::blocking::AnyBlockingClient;
+#[cfg(feature = "blocking-stream")]
+use nyquest_interface::{blocking::SizedBodyStream, Body as BodyImpl};
+
+use super::redirect::send;
+use super::{Request, Response};
+use crate::client::{RetryOutcome, RetryPolicy};
+use crate::redirect::RedirectConfig;
+
+pub(super) fn send_with_retry(
+    client: &Arc<dyn AnyBlockingClient>,
+    mut req: Request,
+    default_policy: Option<&RetryPolicy>,
+    redirects: &RedirectConfig,
+) -> crate::Result<Response> {
+    let policy = req.retry_policy.take().or_else(|| default_policy.cloned());
+    let Some(policy) =
+        policy.filter(|p| p.max_attempts() > 1 && p.allows_method(&req.inner.method))
+    else {
+        return send(client, req, redirects);
+    };
+    let replay = match Replay::new(req) {
+        Ok(replay) => replay,
+        Err(req) => return send(client, *req, redirects),
+    };
+
+    let mut attempt = 1;
+    loop {
+        if attempt > 1 {
+            replay.rewind()?;
+        }
+        let res = send(client, replay.next_request(), redirects);
+        if attempt >= policy.max_attempts() {
+            return res;
+        }
+        let (retry, retry_after) = match &res {
+            Ok(res) => (
+                policy.should_retry(RetryOutcome::Status(res.status())),
+                res.get_header("retry-after")
+                    .ok()
+                    .and_then(|v| v.into_iter().next()),
+            ),
+            Err(e) => (policy.should_retry(RetryOutcome::Error(e)), None),
+        };
+        if !retry {
+            return res;
+        }
+        let Some(delay) = policy.delay(attempt, retry_after.as_deref()) else {
+            return res;
+        };
+        drop(res);
+        std::thread::sleep(delay);
+        attempt += 1;
+    }
+}
+
+/// A request that can be sent multiple times.
+enum Replay {
+    Clone(Request),
+    #[cfg(feature = "blocking-stream")]
+    Stream {
+        template: Request,
+        stream: ReplayStream,
+    },
+}
+
+impl Replay {
+    fn new(req: Request) -> Result<Self, Box<Request>> {
+        if let Some(template) = req.try_clone() {
+            return Ok(Self::Clone(template));
+        }
+        #[cfg(feature = "blocking-stream")]
+        {
+            ReplayStream::extract(req).map(|(template, stream)| Self::Stream { template, stream })
+        }
+        #[cfg(not(feature = "blocking-stream"))]
+        Err(Box::new(req))
+    }
+
+    fn rewind(&self) -> std::io::Result<()> {
+        match self {
+            Self::Clone(_) => Ok(()),
+            #[cfg(feature = "blocking-stream")]
+            Self::Stream { stream, .. } => stream.rewind(),
+        }
+    }
+
+    fn next_request(&self) -> Request {
+        match self {
+            Self::Clone(template) => template
+                .try_clone()
+                .expect("replay template must be cloneable"),
+            #[cfg(feature = "blocking-stream")]
+            Self::Stream { template, stream } => {
+                let mut req = template
+                    .try_clone()
+                    .expect("replay template must be cloneable");
+                req.inner.body = Some(stream.to_body());
+                req
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking-stream")]
+struct ReplayStream {
+    stream: SharedStream,
+    start: u64,
+    content_length: u64,
+    content_type: std::borrow::Cow<'static, str>,
+}
+
+#[cfg(feature = "blocking-stream")]
+impl ReplayStream {
+    fn extract(mut req: Request) -> Result<(Request, Self), Box<Request>> {
+        use std::io::Seek;
+
+        use nyquest_interface::blocking::BoxedStream;
+
+        let Some(BodyImpl::Stream {
+            stream:
+                BoxedStream::Sized {
+                    stream,
+                    content_length,
+                },
+            content_type,
+        }) = req.inner.body.take()
+        else {
+            return Err(Box::new(req));
+        };
+        let mut stream = stream;
+        let Ok(start) = stream.stream_position() else {
+            req.inner.body = Some(BodyImpl::Stream {
+                stream: BoxedStream::Sized {
+                    stream,
+                    content_length,
+                },
+                content_type,
+            });
+            return Err(Box::new(req));
+        };
+        let template = req
+            .try_clone()
+            .expect("request without body must be cloneable");
+        Ok((
+            template,
+            Self {
+                stream: SharedStream(Arc::new(std::sync::Mutex::new(stream))),
+                start,
+                content_length,
+                content_type,
+            },
+        ))
+    }
+
+    fn rewind(&self) -> std::io::Result<()> {
+        use std::io::Seek;
+
+        self.stream
+            .lock()
+            .seek(std::io::SeekFrom::Start(self.start))?;
+        Ok(())
+    }
+
+    fn to_body(&self) -> BodyImpl<nyquest_interface::blocking::BoxedStream> {
+        BodyImpl::Stream {
+            stream: nyquest_interface::blocking::BoxedStream::Sized {
+                stream: Box::new(self.stream.clone()),
+                content_length: self.content_length,
+            },
+            content_type: self.content_type.clone(),
+        }
+    }
+}
+
+/// A stream shared between attempts so that it can be rewound after being handed to the backend.
+#[cfg(feature = "blocking-stream")]
+#[derive(Clone)]
+struct SharedStream(Arc<std::sync::Mutex<Box<dyn SizedBodyStream>>>);
+
+#[cfg(feature = "blocking-stream")]
+impl SharedStream {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Box<dyn SizedBodyStream>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(feature = "blocking-stream")]
+impl std::io::Read for SharedStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.lock().read(buf)
+    }
+}
+
+#[cfg(feature = "blocking-stream")]
+impl std::io::Seek for SharedStream {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.lock().seek(pos)
+    }
+}
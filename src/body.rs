@@ -218,6 +218,44 @@ impl<S> Part<S> {
     }
 }
 
+/// Duplicates a body unless it contains a stream.
+pub(crate) fn try_clone_body<S>(body: &BodyImpl<S>) -> Option<BodyImpl<S>> {
+    Some(match body {
+        BodyImpl::Bytes {
+            content,
+            content_type,
+        } => BodyImpl::Bytes {
+            content: content.clone(),
+            content_type: content_type.clone(),
+        },
+        BodyImpl::Form { fields } => BodyImpl::Form {
+            fields: fields.clone(),
+        },
+        #[cfg(feature = "multipart")]
+        BodyImpl::Multipart { parts } => BodyImpl::Multipart {
+            parts: parts
+                .iter()
+                .map(|part| {
+                    Some(PartImpl {
+                        headers: part.headers.clone(),
+                        name: part.name.clone(),
+                        filename: part.filename.clone(),
+                        content_type: part.content_type.clone(),
+                        body: match &part.body {
+                            PartBodyImpl::Bytes { content } => PartBodyImpl::Bytes {
+                                content: content.clone(),
+                            },
+                            PartBodyImpl::Stream(_) => return None,
+                        },
+                    })
+                })
+                .collect::<Option<_>>()?,
+        },
+        // Streams, or multipart bodies when the facade is built without the `multipart` feature
+        _ => return None,
+    })
+}
+
 pub(crate) mod private {
     pub trait IntoSizedStream<B> {
         fn into_stream(self, size: u64) -> B;
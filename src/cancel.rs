@@ -0,0 +1,31 @@
+use nyquest_interface::CancellationToken as CancellationTokenImpl;
+
+/// A handle to cancel requests from any thread, attached with
+/// [`crate::Request::with_cancellation`].
+///
+/// Clones of a token share the same state, so a single token can cancel multiple requests. Once
+/// cancelled, a token stays cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    pub(crate) inner: CancellationTokenImpl,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all requests this token is attached to.
+    ///
+    /// In-flight calls return [`crate::Error::Cancelled`] promptly, and subsequent calls fail
+    /// immediately.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
@@ -2,7 +2,16 @@
 //!
 
 mod builder;
+mod identity;
 mod proxy;
+mod resolve;
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+mod retry;
+mod version;
 
 pub use builder::ClientBuilder;
+pub use identity::Identity;
 pub use proxy::CustomProxy;
+pub use resolve::Resolve;
+pub use retry::{RetryOutcome, RetryPolicy};
+pub use version::{HttpVersionPreference, TlsVersion};
@@ -1,9 +1,17 @@
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
-use nyquest_interface::client::{CachingBehavior, ClientOptions, ProxyOptions};
+use nyquest_interface::client::{CachingBehavior, ClientOptions, ProxyOptions, ResolveOverride};
 
+use crate::auth::Auth;
 #[cfg(doc)]
 use crate::client::CustomProxy;
+use crate::client::{HttpVersionPreference, Identity, Resolve, RetryPolicy, TlsVersion};
+use crate::middleware::{Middleware, MiddlewareStack};
+use crate::redirect::{RedirectConfig, RedirectPolicy};
+use crate::ErrorContext;
 
 /// A builder for creating an async or blocking client with custom options.
 ///
@@ -11,6 +19,11 @@ use crate::client::CustomProxy;
 #[derive(Debug, Clone, Default)]
 pub struct ClientBuilder {
     pub(crate) options: ClientOptions,
+    pub(crate) middlewares: MiddlewareStack,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) error_context: ErrorContext,
+    pub(crate) max_download_size: Option<u64>,
+    pub(crate) auth: Option<Auth>,
 }
 
 impl ClientBuilder {
@@ -64,6 +77,57 @@ impl ClientBuilder {
         self
     }
 
+    /// Sends all requests over the Unix domain socket at `path`, e.g. to talk to a local daemon.
+    ///
+    /// The URL of a request still supplies the `Host` header and the path, such as
+    /// `http://localhost/v1.43/containers/json`.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend is not able to connect to Unix domain sockets.
+    /// Proxy settings are ignored by some backends when a socket is set.
+    #[inline]
+    pub fn unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.options.unix_socket = Some(path.into());
+        self
+    }
+
+    /// Resolves `host` to `addrs` when connecting to `port`, bypassing DNS, e.g. to route
+    /// requests to a specific server without editing the hosts file.
+    ///
+    /// Can be called multiple times to override more hosts. The overrides take precedence over
+    /// [`Self::dns_resolver`].
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend does not support DNS overrides. Some backends
+    /// apply the override to all ports of the host, connecting to `port` instead.
+    pub fn resolve(
+        mut self,
+        host: impl Into<String>,
+        port: u16,
+        addrs: impl IntoIterator<Item = IpAddr>,
+    ) -> Self {
+        self.options.dns.overrides.push(ResolveOverride {
+            host: host.into(),
+            port,
+            addrs: addrs.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Sets a custom [`Resolve`] implementation to resolve host names instead of the system
+    /// resolver.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend does not support custom resolvers. Redirects are
+    /// followed by nyquest, so that the resolver is consulted for the hosts redirected to.
+    pub fn dns_resolver(mut self, resolver: impl Resolve) -> Self {
+        self.options.dns.resolver = Some(Arc::new(move |host: &str| resolver.resolve(host)));
+        self
+    }
+
     /// Instructs the backend to not keep cookies between requests.
     #[inline]
     pub fn no_cookies(mut self) -> Self {
@@ -71,13 +135,41 @@ impl ClientBuilder {
         self
     }
 
+    /// Persists cookies in a file in the Netscape cookie file format, e.g. to stay logged in
+    /// across runs of a program.
+    ///
+    /// Cookies are loaded from the file, if it exists, when the client is built, and saved to it,
+    /// including session cookies, when the client is dropped. Use `flush_cookies()` on the client
+    /// to save them earlier and observe errors.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend is not able to persist cookies. The file is not
+    /// used when cookies are disabled with [`Self::no_cookies`].
+    #[inline]
+    pub fn cookie_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.options.cookie_file = Some(path.into());
+        self
+    }
+
     /// Instructs the backend to not follow 3xx redirects.
+    ///
+    /// This overrides the [`Self::redirect_policy`] setting.
     #[inline]
     pub fn no_redirects(mut self) -> Self {
         self.options.follow_redirects = false;
         self
     }
 
+    /// Sets the [`RedirectPolicy`] for following 3xx redirects.
+    ///
+    /// Without a policy, redirects are followed with the backend default limits.
+    #[inline]
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.options.redirect_policy = policy.inner;
+        self
+    }
+
     /// Sets the maximum number of bytes to buffer for a response.
     ///
     /// # Note
@@ -90,6 +182,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the maximum number of bytes to write to disk for a download.
+    ///
+    /// # Note
+    ///
+    /// The limit only applies to `client.download_to()`. Downloads exceeding the limit fail with
+    /// [`crate::Error::ResponseTooLarge`].
+    #[inline]
+    pub fn max_download_size(mut self, size: u64) -> Self {
+        self.max_download_size = Some(size);
+        self
+    }
+
     /// Sets the timeout for a whole request to complete.
     ///
     /// # Note
@@ -101,6 +205,34 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the timeout for establishing a connection, including the TLS handshake if any.
+    ///
+    /// # Note
+    ///
+    /// The precision of the timeout is implementation defined.
+    #[inline]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for a request to receive no data, e.g. when a server stalls in the middle
+    /// of a response.
+    ///
+    /// Unlike [`Self::request_timeout`], long transfers do not time out as long as data keeps
+    /// coming in.
+    ///
+    /// # Note
+    ///
+    /// The precision of the timeout is implementation defined. Some backends only support whole
+    /// seconds. The curl backend times out when less than 1 byte per second is received on
+    /// average over the timeout, so a trickle of data spread over the timeout keeps it alive.
+    #[inline]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.options.read_timeout = Some(timeout);
+        self
+    }
+
     /// Allows the client to ignore certificate errors.
     ///
     /// # Warning
@@ -113,4 +245,189 @@ impl ClientBuilder {
         self.options.ignore_certificate_errors = true;
         self
     }
+
+    /// Trusts the root certificates in a PEM encoded bundle, in addition to the system store.
+    ///
+    /// Can be called multiple times to add more certificates.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend does not support custom root certificates.
+    #[inline]
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.options.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Trusts only the root certificates added with [`Self::add_root_certificate`], ignoring the
+    /// system store.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend does not support custom root certificates.
+    #[inline]
+    pub fn only_custom_roots(mut self) -> Self {
+        self.options.only_custom_roots = true;
+        self
+    }
+
+    /// Pins the public key of the server, given as the base64 encoded SHA-256 hash of its DER
+    /// encoded SubjectPublicKeyInfo.
+    ///
+    /// Can be called multiple times to allow more keys. Requests fail with a certificate error
+    /// (see [`crate::Error::is_certificate`]) when the server certificate matches none of the pins.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend does not support public key pinning.
+    #[inline]
+    pub fn pin_public_key(mut self, sha256_base64: impl Into<String>) -> Self {
+        self.options.pinned_public_keys.push(sha256_base64.into());
+        self
+    }
+
+    /// Sets the minimum TLS version accepted when connecting to a server.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend is not able to enforce the minimum version.
+    #[inline]
+    pub fn min_tls_version(mut self, version: TlsVersion) -> Self {
+        self.options.min_tls_version = Some(version.into());
+        self
+    }
+
+    /// Sets the HTTP protocol versions the client is allowed to use.
+    ///
+    /// Without a preference, the backend default is used.
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend is not able to honor the preference.
+    #[inline]
+    pub fn http_version(mut self, preference: HttpVersionPreference) -> Self {
+        self.options.http_version = preference.into();
+        self
+    }
+
+    /// Presents a client certificate for TLS client authentication (mutual TLS).
+    ///
+    /// # Note
+    ///
+    /// Building the client fails if the backend is not able to present a client certificate, or
+    /// does not support the format of the [`Identity`].
+    #[inline]
+    pub fn client_identity(mut self, identity: Identity) -> Self {
+        self.options.client_identity = Some(identity.inner);
+        self
+    }
+
+    /// Authenticates all requests made with this client with HTTP Basic authentication.
+    ///
+    /// The credentials can be overridden for a single request with
+    /// [`crate::Request::with_basic_auth`] or [`crate::Request::with_bearer_token`].
+    ///
+    /// # Note
+    ///
+    /// The credentials are not sent if a request has an `Authorization` header already, and are
+    /// dropped when following a redirect to another origin.
+    pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.auth = Some(Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Authenticates all requests made with this client with a Bearer token.
+    ///
+    /// # Note
+    ///
+    /// See [`Self::basic_auth`] for when the token is sent.
+    pub fn bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Some(Auth::Bearer {
+            token: token.into(),
+        });
+        self
+    }
+
+    /// Authenticates all requests made with this client with HTTP Digest authentication.
+    ///
+    /// Requests are first sent without credentials. When the server responds with
+    /// `401 Unauthorized` and a Digest challenge, the request is sent again with the answer to the
+    /// challenge, which costs a round trip per request.
+    ///
+    /// # Note
+    ///
+    /// Only the `MD5` and `MD5-sess` algorithms with the `auth` quality of protection are
+    /// supported; other challenges are left unanswered and the 401 response is returned. Requests
+    /// with a streaming body cannot be sent again and are not answered either.
+    pub fn digest_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.auth = Some(Auth::Digest {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Adds a [`Middleware`] to intercept all requests made with this client.
+    ///
+    /// Middlewares are invoked in the order they are added. See the
+    /// [`middleware`](crate::middleware) module for more details.
+    pub fn with_middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] for all requests made with this client.
+    ///
+    /// Requests are not retried by default. The policy can be overridden for a single request
+    /// with [`crate::Request::with_retry_policy`].
+    #[inline]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Sets whether errors carry the method and URL of the request that failed.
+    ///
+    /// Disabled by default. See [`ErrorContext`] for the available options.
+    #[inline]
+    pub fn error_context(mut self, context: ErrorContext) -> Self {
+        self.error_context = context;
+        self
+    }
+
+    /// Takes the options to be passed to the backend, along with how nyquest follows redirects.
+    ///
+    /// If the redirect policy can only be enforced by nyquest, or a custom resolver has to be
+    /// consulted for the hosts redirected to, the backend is told not to follow redirects, and
+    /// the default headers not to be sent to other origins are taken out of the options to be
+    /// added to each request by nyquest.
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) fn backend_options(&mut self) -> (ClientOptions, RedirectConfig) {
+        let mut options = std::mem::take(&mut self.options);
+        let policy = &options.redirect_policy;
+        let enforced = options.follow_redirects
+            && (policy.same_origin_only
+                || policy.decider.is_some()
+                || options.dns.resolver.is_some());
+        let mut policy = policy.clone();
+        // Backends have their own limits, which nyquest cannot rely on
+        policy
+            .max_redirects
+            .get_or_insert(RedirectPolicy::DEFAULT_MAX_REDIRECTS);
+        let mut redirects = RedirectConfig {
+            policy,
+            follow: options.follow_redirects,
+            enforced,
+            sensitive_headers: vec![],
+        };
+        if enforced {
+            options.follow_redirects = false;
+            redirects.sensitive_headers =
+                crate::redirect::take_sensitive_headers(&mut options.default_headers);
+        }
+        (options, redirects)
+    }
 }
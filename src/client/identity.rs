@@ -0,0 +1,45 @@
+use nyquest_interface::client::ClientIdentity;
+
+/// A client certificate with its private key, used for TLS client authentication (mutual TLS).
+///
+/// # Examples
+///
+/// ```rust
+/// # use nyquest::client::Identity;
+/// # let (cert, key) = (vec![], vec![]);
+/// let identity = Identity::from_pem(cert, key);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub(super) inner: ClientIdentity,
+}
+
+impl Identity {
+    /// Creates an identity from a PEM encoded certificate chain and a PEM encoded PKCS#8 private
+    /// key.
+    ///
+    /// The certificate chain starts with the client certificate, optionally followed by
+    /// intermediate certificates.
+    pub fn from_pem(cert: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            inner: ClientIdentity::Pem {
+                cert: cert.into(),
+                key: key.into(),
+            },
+        }
+    }
+
+    /// Creates an identity from a DER encoded PKCS#12 archive protected by `password`.
+    ///
+    /// # Note
+    ///
+    /// Not all backends support PKCS#12 archives.
+    pub fn from_pkcs12_der(der: impl Into<Vec<u8>>, password: impl Into<String>) -> Self {
+        Self {
+            inner: ClientIdentity::Pkcs12 {
+                der: der.into(),
+                password: password.into(),
+            },
+        }
+    }
+}
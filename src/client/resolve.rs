@@ -0,0 +1,23 @@
+use std::io;
+use std::net::IpAddr;
+
+/// A custom resolver translating host names to addresses, set with
+/// [`ClientBuilder::dns_resolver`](crate::ClientBuilder::dns_resolver).
+///
+/// Resolution is blocking, like the `getaddrinfo` of the system. Backends may call the resolver on
+/// the thread sending the request.
+///
+/// Closures of the form `Fn(&str) -> io::Result<Vec<IpAddr>>` implement this trait.
+pub trait Resolve: Send + Sync + 'static {
+    /// Resolves `host` to its addresses, in order of preference.
+    fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>>;
+}
+
+impl<F> Resolve for F
+where
+    F: Fn(&str) -> io::Result<Vec<IpAddr>> + Send + Sync + 'static,
+{
+    fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        self(host)
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::hash_map::RandomState;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use nyquest_interface::Method as MethodImpl;
+
+use crate::{Error, ErrorKind, StatusCode};
+
+/// The outcome of a single attempt, as seen by [`RetryPolicy::retry_if`].
+#[derive(Debug, Clone, Copy)]
+pub enum RetryOutcome<'a> {
+    /// The attempt failed with an error.
+    Error(&'a Error),
+    /// The attempt received a response with the given status code.
+    Status(StatusCode),
+}
+
+type RetryPredicate = Arc<dyn Fn(RetryOutcome<'_>) -> bool + Send + Sync>;
+
+/// Controls whether and how failed requests are retried.
+///
+/// A policy can be set for all requests with [`crate::ClientBuilder::retry_policy`] and overridden
+/// for a single request with [`crate::Request::with_retry_policy`].
+///
+/// By default, a retry is attempted when
+///
+/// - the request fails with [`Error::Io`] or [`Error::RequestTimeout`], or fails to resolve the
+///   host, connect to the server or proxy, or transfer the request or response, or
+/// - the response status is `429 Too Many Requests`, `502 Bad Gateway`,
+///   `503 Service Unavailable` or `504 Gateway Timeout`.
+///
+/// Only idempotent methods (`GET`, `HEAD`, `PUT`, `DELETE`, `OPTIONS` and `TRACE`) are retried
+/// unless [`RetryPolicy::retry_non_idempotent`] is set. Requests whose body cannot be replayed,
+/// i.e. unsized streams or multipart forms containing streams, are never retried.
+///
+/// The delay between attempts grows exponentially from the initial backoff, with random jitter
+/// applied. When the response carries a `Retry-After` header, either in delta-seconds or
+/// HTTP-date form, the server-provided delay is used instead.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use nyquest::client::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(5).backoff(Duration::from_millis(200), Duration::from_secs(5));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_retry_after: Duration,
+    jitter: bool,
+    retry_non_idempotent: bool,
+    predicate: Option<RetryPredicate>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes at most `max_attempts` attempts in total, including the first
+    /// one.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            max_retry_after: Duration::from_secs(60),
+            jitter: true,
+            retry_non_idempotent: false,
+            predicate: None,
+        }
+    }
+
+    /// Creates a policy that never retries.
+    ///
+    /// This is useful to opt a single request out of the client-wide policy.
+    pub fn never() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay before the first retry and the upper bound of the delay between attempts.
+    ///
+    /// The delay doubles after each attempt.
+    #[inline]
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Sets the longest `Retry-After` delay to wait for.
+    ///
+    /// When the server asks for a longer delay, the response is returned as-is without retrying.
+    /// Defaults to 60 seconds.
+    #[inline]
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Disables random jitter of the backoff delay.
+    #[inline]
+    pub fn no_jitter(mut self) -> Self {
+        self.jitter = false;
+        self
+    }
+
+    /// Allows retrying requests with non-idempotent methods such as `POST` and `PATCH`.
+    #[inline]
+    pub fn retry_non_idempotent(mut self) -> Self {
+        self.retry_non_idempotent = true;
+        self
+    }
+
+    /// Replaces the default condition of whether an attempt should be retried.
+    pub fn retry_if(
+        mut self,
+        predicate: impl Fn(RetryOutcome<'_>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    pub(crate) fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub(crate) fn allows_method(&self, method: &MethodImpl) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    pub(crate) fn should_retry(&self, outcome: RetryOutcome<'_>) -> bool {
+        match &self.predicate {
+            Some(predicate) => predicate(outcome),
+            None => match outcome {
+                RetryOutcome::Error(e) => {
+                    matches!(e, Error::Io(_) | Error::RequestTimeout)
+                        || matches!(
+                            e.kind(),
+                            Some(
+                                ErrorKind::Dns
+                                    | ErrorKind::Connect
+                                    | ErrorKind::Proxy
+                                    | ErrorKind::BodyRead
+                                    | ErrorKind::BodyWrite
+                            )
+                        )
+                }
+                RetryOutcome::Status(status) => matches!(status.code(), 429 | 502 | 503 | 504),
+            },
+        }
+    }
+
+    /// Computes the delay before the next attempt, or `None` if the server asked for a delay
+    /// longer than allowed.
+    ///
+    /// `attempt` is the 1-based number of the attempt that has just failed.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<&str>) -> Option<Duration> {
+        if let Some(delay) = retry_after.and_then(|v| parse_retry_after(v, SystemTime::now())) {
+            return (delay <= self.max_retry_after).then_some(delay);
+        }
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if self.jitter {
+            // Equal jitter: keep half of the backoff and randomize the other half.
+            let half = backoff / 2;
+            Some(half + half.mul_f64(random_unit()))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Creates a policy with at most 3 attempts.
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("max_retry_after", &self.max_retry_after)
+            .field("jitter", &self.jitter)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("custom_predicate", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+fn is_idempotent(method: &MethodImpl) -> bool {
+    match method {
+        MethodImpl::Get | MethodImpl::Head | MethodImpl::Put | MethodImpl::Delete => true,
+        MethodImpl::Post | MethodImpl::Patch => false,
+        MethodImpl::Other(method) => {
+            method.eq_ignore_ascii_case("OPTIONS") || method.eq_ignore_ascii_case("TRACE")
+        }
+    }
+}
+
+/// Parses the value of a `Retry-After` header into a delay relative to `now`.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = crate::time::parse_http_date(value)?;
+    // A date in the past means the request can be retried immediately.
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = UNIX_EPOCH + Duration::from_secs(784111777);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:50:07 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn test_idempotent_methods() {
+        assert!(is_idempotent(&MethodImpl::Get));
+        assert!(is_idempotent(&MethodImpl::Delete));
+        assert!(is_idempotent(&MethodImpl::Other("options".into())));
+        assert!(!is_idempotent(&MethodImpl::Post));
+        assert!(!is_idempotent(&MethodImpl::Other("LOCK".into())));
+    }
+}
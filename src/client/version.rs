@@ -0,0 +1,49 @@
+use nyquest_interface::client::{
+    HttpVersionPreference as HttpVersionPreferenceImpl, TlsVersion as TlsVersionImpl,
+};
+
+/// A TLS protocol version, used to set the minimum version accepted by a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// TLS 1.0.
+    Tls1_0,
+    /// TLS 1.1.
+    Tls1_1,
+    /// TLS 1.2.
+    Tls1_2,
+    /// TLS 1.3.
+    Tls1_3,
+}
+
+impl From<TlsVersion> for TlsVersionImpl {
+    fn from(version: TlsVersion) -> Self {
+        match version {
+            TlsVersion::Tls1_0 => Self::Tls1_0,
+            TlsVersion::Tls1_1 => Self::Tls1_1,
+            TlsVersion::Tls1_2 => Self::Tls1_2,
+            TlsVersion::Tls1_3 => Self::Tls1_3,
+        }
+    }
+}
+
+/// The HTTP protocol versions a client is allowed to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpVersionPreference {
+    /// Use HTTP/1.1 only.
+    Http1Only,
+    /// Use HTTP/2 when the server supports it, and fall back to HTTP/1.1 otherwise.
+    PreferHttp2,
+    /// Use HTTP/2 without negotiation, assuming the server supports it. This is also the only way
+    /// to use HTTP/2 over plain-text connections.
+    Http2PriorKnowledge,
+}
+
+impl From<HttpVersionPreference> for HttpVersionPreferenceImpl {
+    fn from(preference: HttpVersionPreference) -> Self {
+        match preference {
+            HttpVersionPreference::Http1Only => Self::Http1Only,
+            HttpVersionPreference::PreferHttp2 => Self::PreferHttp2,
+            HttpVersionPreference::Http2PriorKnowledge => Self::Http2PriorKnowledge,
+        }
+    }
+}
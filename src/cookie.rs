@@ -0,0 +1,233 @@
+//! Cookie jar access for clients.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use nyquest_interface::{Cookie as CookieImpl, CookieJar as CookieJarImpl};
+
+/// A cookie in the [`CookieJar`] of a client.
+///
+/// # Examples
+///
+/// ```rust
+/// # use nyquest::Cookie;
+/// let cookie = Cookie::new("session", "abc", "example.com")
+///     .with_path("/api")
+///     .with_subdomains()
+///     .with_secure();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    inner: CookieImpl,
+}
+
+impl Cookie {
+    /// Creates a session cookie sent to the exact `domain` only, with the path `/`.
+    pub fn new(
+        name: impl Into<String>,
+        value: impl Into<String>,
+        domain: impl Into<String>,
+    ) -> Self {
+        let domain: String = domain.into();
+        Self {
+            inner: CookieImpl {
+                name: name.into(),
+                value: value.into(),
+                domain: domain.trim_start_matches('.').into(),
+                include_subdomains: false,
+                path: "/".into(),
+                secure: false,
+                http_only: false,
+                expires: None,
+            },
+        }
+    }
+
+    /// Sets the path of the cookie.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.inner.path = path.into();
+        self
+    }
+
+    /// Sends the cookie to subdomains of its domain as well, like the `Domain` attribute does.
+    #[inline]
+    pub fn with_subdomains(mut self) -> Self {
+        self.inner.include_subdomains = true;
+        self
+    }
+
+    /// Only sends the cookie over secure connections.
+    #[inline]
+    pub fn with_secure(mut self) -> Self {
+        self.inner.secure = true;
+        self
+    }
+
+    /// Marks the cookie as hidden from scripts.
+    #[inline]
+    pub fn with_http_only(mut self) -> Self {
+        self.inner.http_only = true;
+        self
+    }
+
+    /// Sets the time the cookie expires. Without it, the cookie is a session cookie.
+    #[inline]
+    pub fn with_expires(mut self, expires: SystemTime) -> Self {
+        self.inner.expires = Some(expires);
+        self
+    }
+
+    /// Returns the name of the cookie.
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Returns the value of the cookie.
+    pub fn value(&self) -> &str {
+        &self.inner.value
+    }
+
+    /// Returns the domain of the cookie, without a leading dot.
+    pub fn domain(&self) -> &str {
+        &self.inner.domain
+    }
+
+    /// Returns whether the cookie is also sent to subdomains of [`Self::domain`].
+    pub fn includes_subdomains(&self) -> bool {
+        self.inner.include_subdomains
+    }
+
+    /// Returns the path of the cookie.
+    pub fn path(&self) -> &str {
+        &self.inner.path
+    }
+
+    /// Returns whether the cookie is only sent over secure connections.
+    pub fn is_secure(&self) -> bool {
+        self.inner.secure
+    }
+
+    /// Returns whether the cookie is hidden from scripts.
+    pub fn is_http_only(&self) -> bool {
+        self.inner.http_only
+    }
+
+    /// Returns the time the cookie expires, or `None` for session cookies.
+    pub fn expires(&self) -> Option<SystemTime> {
+        self.inner.expires
+    }
+
+    /// Returns whether the cookie would be sent with a request to `host` and `path`.
+    fn matches(&self, host: &str, path: &str) -> bool {
+        domain_matches(&self.inner.domain, self.inner.include_subdomains, host)
+            && path_matches(&self.inner.path, path)
+    }
+}
+
+impl From<CookieImpl> for Cookie {
+    fn from(inner: CookieImpl) -> Self {
+        Self { inner }
+    }
+}
+
+/// A handle to the cookies kept by a client, returned by `cookie_jar()` on
+/// [`BlockingClient`](crate::BlockingClient) or [`AsyncClient`](crate::AsyncClient).
+///
+/// Changes are visible to requests made afterwards with the client. The handle is cheap to clone
+/// and can outlive the client.
+#[derive(Clone)]
+pub struct CookieJar {
+    inner: Arc<dyn CookieJarImpl>,
+}
+
+impl CookieJar {
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) fn new(inner: Arc<dyn CookieJarImpl>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns all cookies in the jar that have not expired.
+    pub fn cookies(&self) -> crate::Result<Vec<Cookie>> {
+        let cookies = self.inner.cookies()?;
+        Ok(cookies.into_iter().map(Cookie::from).collect())
+    }
+
+    /// Returns the cookies that would be sent with a request to `host` and `path`, e.g.
+    /// `cookies_for("api.example.com", "/v1/users")`.
+    ///
+    /// The `Secure` attribute is not taken into account.
+    pub fn cookies_for(&self, host: &str, path: &str) -> crate::Result<Vec<Cookie>> {
+        let mut cookies = self.cookies()?;
+        cookies.retain(|cookie| cookie.matches(host, path));
+        Ok(cookies)
+    }
+
+    /// Adds a cookie, replacing the one with the same name, domain and path if any.
+    pub fn add(&self, cookie: Cookie) -> crate::Result<()> {
+        Ok(self.inner.add(cookie.inner)?)
+    }
+
+    /// Removes the cookie with the given name, domain and path if any.
+    pub fn remove(&self, name: &str, domain: &str, path: &str) -> crate::Result<()> {
+        Ok(self
+            .inner
+            .remove(name, domain.trim_start_matches('.'), path)?)
+    }
+
+    /// Removes all cookies.
+    pub fn clear(&self) -> crate::Result<()> {
+        Ok(self.inner.clear()?)
+    }
+}
+
+impl fmt::Debug for CookieJar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CookieJar").finish_non_exhaustive()
+    }
+}
+
+/// Domain matching as defined in RFC 6265, section 5.1.3.
+fn domain_matches(domain: &str, include_subdomains: bool, host: &str) -> bool {
+    if host.eq_ignore_ascii_case(domain) {
+        return true;
+    }
+    include_subdomains
+        && host.len() > domain.len()
+        && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+        && host[host.len() - domain.len()..].eq_ignore_ascii_case(domain)
+}
+
+/// Path matching as defined in RFC 6265, section 5.1.4.
+fn path_matches(cookie_path: &str, path: &str) -> bool {
+    match path.strip_prefix(cookie_path) {
+        Some(rest) => rest.is_empty() || cookie_path.ends_with('/') || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_matches() {
+        assert!(domain_matches("example.com", false, "example.com"));
+        assert!(domain_matches("example.com", false, "EXAMPLE.com"));
+        assert!(!domain_matches("example.com", false, "www.example.com"));
+        assert!(domain_matches("example.com", true, "www.example.com"));
+        assert!(!domain_matches("example.com", true, "badexample.com"));
+        assert!(!domain_matches("www.example.com", true, "example.com"));
+    }
+
+    #[test]
+    fn test_path_matches() {
+        assert!(path_matches("/", "/"));
+        assert!(path_matches("/", "/foo"));
+        assert!(path_matches("/foo", "/foo"));
+        assert!(path_matches("/foo", "/foo/bar"));
+        assert!(path_matches("/foo/", "/foo/bar"));
+        assert!(!path_matches("/foo", "/foobar"));
+        assert!(!path_matches("/foo", "/"));
+    }
+}
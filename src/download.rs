@@ -0,0 +1,114 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::StatusCode;
+
+mod resume;
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) use resume::{validator, PartialDownload, Resume};
+
+/// Metadata of a response body downloaded to a file by `AsyncClient::download_to` or
+/// `BlockingClient::download_to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Download {
+    pub(crate) status: StatusCode,
+    pub(crate) size: u64,
+    pub(crate) content_type: Option<String>,
+    pub(crate) url: Option<String>,
+}
+
+impl Download {
+    /// Get the `StatusCode` of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the number of bytes written to the file.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the `content-type` of the response, if any.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Get the effective URL of the response after following redirects, if known by the
+    /// backend.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+}
+
+/// A temporary file in the same directory as the download target, which replaces the target
+/// once the download completes.
+///
+/// The temporary file is removed if dropped before being persisted.
+pub(crate) struct PartFile {
+    file: File,
+    path: TempPath,
+}
+
+struct TempPath {
+    path: PathBuf,
+    persisted: bool,
+}
+
+impl PartFile {
+    pub(crate) fn create(target: &Path) -> io::Result<Self> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let name = target.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "download path has no file name",
+            )
+        })?;
+        let mut temp_name = OsString::from(".");
+        temp_name.push(name);
+        temp_name.push(format!(
+            ".{}-{}.part",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path = target.with_file_name(temp_name);
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self {
+            file,
+            path: TempPath {
+                path,
+                persisted: false,
+            },
+        })
+    }
+
+    pub(crate) fn file(&mut self) -> &mut File {
+        &mut self.file
+    }
+
+    /// Flushes the file to disk and renames it to `target`, replacing any existing file.
+    pub(crate) fn persist(self, target: &Path) -> io::Result<()> {
+        let Self { file, mut path } = self;
+        file.sync_all()?;
+        // Some platforms do not allow renaming files that are still open
+        drop(file);
+        fs::rename(&path.path, target)?;
+        path.persisted = true;
+        Ok(())
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        if !self.persisted {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::ffi::{OsStr, OsString};
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::header::{IF_RANGE, RANGE};
+use crate::{Request, StatusCode};
+
+/// How the response to a possibly resumed request relates to the partial file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Resume {
+    /// The response continues the partial file.
+    Append,
+    /// The response contains the full body, replacing the partial file.
+    Restart,
+    /// The response cannot be used, so the full body needs to be requested again.
+    Retry,
+}
+
+/// A partially downloaded file kept next to the download target, along with the validator of the
+/// response it belongs to, so that an interrupted download can be resumed later.
+///
+/// The partial body is kept in `<name>.part`, and the validator in `<name>.part.meta`.
+pub(crate) struct PartialDownload {
+    path: PathBuf,
+    meta_path: PathBuf,
+    offset: u64,
+    validator: Option<String>,
+}
+
+impl PartialDownload {
+    pub(crate) fn open(target: &Path) -> io::Result<Self> {
+        let name = target.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "download path has no file name",
+            )
+        })?;
+        let path = target.with_file_name(with_suffix(name, ".part"));
+        let meta_path = target.with_file_name(with_suffix(name, ".part.meta"));
+        let validator = match fs::read_to_string(&meta_path) {
+            Ok(validator) => Some(validator.trim().to_owned()).filter(|v| !v.is_empty()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+        let offset = match fs::metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            path,
+            meta_path,
+            offset,
+            validator,
+        })
+    }
+
+    /// Get the size of the partial file.
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    fn is_resumable(&self) -> bool {
+        self.offset > 0 && self.validator.is_some()
+    }
+
+    /// Adds the `Range` and `If-Range` headers to request the rest of the body, if the partial
+    /// file can be resumed.
+    pub(crate) fn range_request<S>(&self, req: Request<S>) -> Request<S> {
+        match &self.validator {
+            Some(validator) if self.is_resumable() => req
+                .with_header(RANGE, format!("bytes={}-", self.offset))
+                .with_header(IF_RANGE, validator.clone()),
+            _ => req,
+        }
+    }
+
+    /// Checks whether a response continues the partial file.
+    pub(crate) fn check(&self, status: StatusCode, content_range: Option<&str>) -> Resume {
+        match u16::from(status) {
+            206 if self.is_resumable()
+                && content_range.and_then(range_start) == Some(self.offset) =>
+            {
+                Resume::Append
+            }
+            206 | 416 => Resume::Retry,
+            _ => Resume::Restart,
+        }
+    }
+
+    /// Truncates the partial file to receive a full body, remembering the validator of the new
+    /// response.
+    pub(crate) fn restart(&mut self, validator: Option<String>) -> io::Result<()> {
+        File::create(&self.path)?;
+        match &validator {
+            Some(validator) => fs::write(&self.meta_path, validator)?,
+            None => remove_if_exists(&self.meta_path)?,
+        }
+        self.offset = 0;
+        self.validator = validator;
+        Ok(())
+    }
+
+    /// Opens the partial file for appending.
+    pub(crate) fn file(&self) -> io::Result<File> {
+        OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&self.path)
+    }
+
+    /// Moves the completed file to `target`, returning its size.
+    pub(crate) fn finish(self, file: File, target: &Path) -> io::Result<u64> {
+        file.sync_all()?;
+        let size = file.metadata()?.len();
+        drop(file);
+        fs::rename(&self.path, target)?;
+        remove_if_exists(&self.meta_path)?;
+        Ok(size)
+    }
+}
+
+/// Picks the validator to send in `If-Range` for a response, given its `ETag` and
+/// `Last-Modified` headers.
+///
+/// Weak entity tags cannot be used in `If-Range`.
+pub(crate) fn validator(etag: Option<String>, last_modified: Option<String>) -> Option<String> {
+    etag.filter(|etag| !etag.starts_with("W/"))
+        .or(last_modified)
+}
+
+/// Parses the first byte position of a `Content-Range` header, e.g. `bytes 100-199/200`.
+fn range_start(content_range: &str) -> Option<u64> {
+    let range = content_range.trim().strip_prefix("bytes ")?;
+    let (start, _) = range.trim_start().split_once('-')?;
+    start.parse().ok()
+}
+
+fn with_suffix(name: &OsStr, suffix: &str) -> OsString {
+    let mut name = name.to_owned();
+    name.push(suffix);
+    name
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_start() {
+        assert_eq!(range_start("bytes 100-199/200"), Some(100));
+        assert_eq!(range_start("bytes 0-0/*"), Some(0));
+        assert_eq!(range_start("bytes */200"), None);
+        assert_eq!(range_start("items 1-2/3"), None);
+    }
+
+    #[test]
+    fn test_validator() {
+        let etag = || Some(r#""abc""#.to_owned());
+        let weak = || Some(r#"W/"abc""#.to_owned());
+        let date = || Some("Wed, 21 Oct 2015 07:28:00 GMT".to_owned());
+        assert_eq!(validator(etag(), date()), etag());
+        assert_eq!(validator(weak(), date()), date());
+        assert_eq!(validator(weak(), None), None);
+    }
+}
@@ -1,8 +1,46 @@
+use std::fmt;
+
 use thiserror::Error;
 
-use nyquest_interface::Error as ErrorImpl;
+use nyquest_interface::{Error as ErrorImpl, ErrorKind as ErrorKindImpl};
+
+use crate::{Method, StatusCode};
+
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+mod context;
 
-use crate::StatusCode;
+mod response;
+
+pub use context::ErrorContext;
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) use context::{attach_context, ContextConfig, RequestContext};
+pub use response::ErrorResponse;
+
+/// The category of a backend failure, returned by [`Error::kind`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The host name could not be resolved.
+    Dns,
+    /// The connection to the server could not be established.
+    Connect,
+    /// The TLS handshake or session failed.
+    Tls,
+    /// The server certificate is invalid or not trusted.
+    Certificate,
+    /// The proxy could not be resolved, connected to, or refused to tunnel the request.
+    Proxy,
+    /// Receiving the response failed, e.g. the connection was closed prematurely.
+    BodyRead,
+    /// Sending the request failed, e.g. the request body could not be read.
+    BodyWrite,
+    /// The response body could not be decoded according to its content encoding.
+    Decode,
+    /// The number of redirects exceeds the configured limit.
+    TooManyRedirects,
+    /// A failure not covered by the other categories.
+    Other,
+}
 
 /// The errors produced by the backend.
 #[non_exhaustive]
@@ -23,14 +61,211 @@ pub enum Error {
     /// [`crate::ClientBuilder::max_response_buffer_size`].
     #[error("Response body size exceeds max limit")]
     ResponseTooLarge,
-    /// The backend is not able to finish transferring the request within the timeout specified in
-    /// [`crate::ClientBuilder::request_timeout`].
+    /// The backend is not able to finish transferring the request within the timeouts specified in
+    /// [`crate::ClientBuilder::request_timeout`], [`crate::ClientBuilder::connect_timeout`] or
+    /// [`crate::ClientBuilder::read_timeout`], or their overrides on the [`crate::Request`].
     #[error("Request is not finished within timeout")]
     RequestTimeout,
+    /// The number of redirects exceeds the limit specified in
+    /// [`crate::redirect::RedirectPolicy::limited`].
+    #[error("Too many redirects")]
+    TooManyRedirects,
+    /// The request is cancelled with the [`crate::CancellationToken`] attached by
+    /// [`crate::Request::with_cancellation`].
+    #[error("Request is cancelled")]
+    Cancelled,
+    /// A backend failure of a known category.
+    ///
+    /// The original backend error is available from [`std::error::Error::source`].
+    #[error("{kind}")]
+    Backend {
+        /// The category of the failure.
+        kind: ErrorKind,
+        /// The original error reported by the backend.
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
     /// The response has a non-successful status code and being checked by `with_successful_status`
     /// method.
     #[error("Non-successful status code: {0}")]
     NonSuccessfulStatusCode(StatusCode),
+    /// The response has a non-successful status code and being checked by
+    /// `error_for_status_with_body` method, which keeps the headers and a prefix of the body.
+    #[error("Non-successful status code: {}", .0.status)]
+    UnsuccessfulResponse(Box<ErrorResponse>),
+    /// An error together with the request that caused it.
+    ///
+    /// Only produced when enabled with [`crate::ClientBuilder::error_context`]. Use
+    /// [`Error::without_context`] to get the underlying error.
+    #[error("{method} {url}")]
+    Request {
+        /// The method of the request.
+        method: Method,
+        /// The URL of the request, redacted as configured by [`ErrorContext`].
+        url: String,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns the category of the failure, if known.
+    pub fn kind(&self) -> Option<ErrorKind> {
+        match self.inner() {
+            Self::TooManyRedirects => Some(ErrorKind::TooManyRedirects),
+            Self::Backend { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    /// Returns the method of the request that failed, if attached.
+    pub fn method(&self) -> Option<&Method> {
+        match self {
+            Self::Request { method, .. } => Some(method),
+            _ => None,
+        }
+    }
+
+    /// Returns the redacted URL of the request that failed, if attached.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Request { url, .. } => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without the attached request context, if any.
+    pub fn without_context(self) -> Self {
+        match self {
+            Self::Request { source, .. } => *source,
+            e => e,
+        }
+    }
+
+    fn inner(&self) -> &Self {
+        match self {
+            Self::Request { source, .. } => source,
+            e => e,
+        }
+    }
+
+    /// Returns whether the request did not finish within the timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.inner(), Self::RequestTimeout)
+    }
+
+    /// Returns whether the request is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.inner(), Self::Cancelled)
+    }
+
+    /// Returns whether the host name could not be resolved.
+    pub fn is_dns(&self) -> bool {
+        self.kind() == Some(ErrorKind::Dns)
+    }
+
+    /// Returns whether the connection to the server could not be established, including DNS
+    /// failures.
+    pub fn is_connect(&self) -> bool {
+        matches!(self.kind(), Some(ErrorKind::Dns | ErrorKind::Connect))
+    }
+
+    /// Returns whether the TLS handshake failed, including certificate errors.
+    pub fn is_tls(&self) -> bool {
+        matches!(self.kind(), Some(ErrorKind::Tls | ErrorKind::Certificate))
+    }
+
+    /// Returns whether the server certificate is invalid or not trusted.
+    pub fn is_certificate(&self) -> bool {
+        self.kind() == Some(ErrorKind::Certificate)
+    }
+
+    /// Returns whether the failure is caused by the proxy.
+    pub fn is_proxy(&self) -> bool {
+        self.kind() == Some(ErrorKind::Proxy)
+    }
+
+    /// Returns whether sending the request or receiving the response failed.
+    pub fn is_body(&self) -> bool {
+        matches!(
+            self.kind(),
+            Some(ErrorKind::BodyRead | ErrorKind::BodyWrite)
+        )
+    }
+
+    /// Returns whether the response body could not be decoded.
+    pub fn is_decode(&self) -> bool {
+        self.kind() == Some(ErrorKind::Decode)
+    }
+
+    /// Returns whether the number of redirects exceeds the limit.
+    pub fn is_redirect(&self) -> bool {
+        self.kind() == Some(ErrorKind::TooManyRedirects)
+    }
+
+    /// Returns whether the response has a non-successful status code.
+    pub fn is_status(&self) -> bool {
+        self.status().is_some()
+    }
+
+    /// Returns the non-successful status code of the response, if this error is caused by one.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self.inner() {
+            Self::NonSuccessfulStatusCode(status) => Some(*status),
+            Self::UnsuccessfulResponse(res) => Some(res.status),
+            _ => None,
+        }
+    }
+
+    /// Returns the response kept by `error_for_status_with_body`, if any.
+    pub fn response(&self) -> Option<&ErrorResponse> {
+        match self.inner() {
+            Self::UnsuccessfulResponse(res) => Some(res),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ErrorKindImpl::from(*self).fmt(f)
+    }
+}
+
+impl From<ErrorKindImpl> for ErrorKind {
+    fn from(kind: ErrorKindImpl) -> Self {
+        match kind {
+            ErrorKindImpl::Dns => Self::Dns,
+            ErrorKindImpl::Connect => Self::Connect,
+            ErrorKindImpl::Tls => Self::Tls,
+            ErrorKindImpl::Certificate => Self::Certificate,
+            ErrorKindImpl::Proxy => Self::Proxy,
+            ErrorKindImpl::BodyRead => Self::BodyRead,
+            ErrorKindImpl::BodyWrite => Self::BodyWrite,
+            ErrorKindImpl::Decode => Self::Decode,
+            ErrorKindImpl::TooManyRedirects => Self::TooManyRedirects,
+            ErrorKindImpl::Other => Self::Other,
+            // Kinds added to the interface after this version of nyquest
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<ErrorKind> for ErrorKindImpl {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Dns => Self::Dns,
+            ErrorKind::Connect => Self::Connect,
+            ErrorKind::Tls => Self::Tls,
+            ErrorKind::Certificate => Self::Certificate,
+            ErrorKind::Proxy => Self::Proxy,
+            ErrorKind::BodyRead => Self::BodyRead,
+            ErrorKind::BodyWrite => Self::BodyWrite,
+            ErrorKind::Decode => Self::Decode,
+            ErrorKind::TooManyRedirects => Self::TooManyRedirects,
+            ErrorKind::Other => Self::Other,
+        }
+    }
 }
 
 /// A `Result` alias where the `Err` case is [`crate::Error`].
@@ -40,9 +275,58 @@ impl From<ErrorImpl> for Error {
     fn from(e: ErrorImpl) -> Self {
         match e {
             ErrorImpl::InvalidUrl => Self::InvalidUrl,
+            // Backends report cancellation and timeouts from `Read` streams as I/O errors
+            ErrorImpl::Io(e) if is_cancelled_io(&e) => Self::Cancelled,
+            ErrorImpl::Io(e) if e.kind() == std::io::ErrorKind::TimedOut => Self::RequestTimeout,
             ErrorImpl::Io(e) => Self::Io(e),
             ErrorImpl::ResponseTooLarge => Self::ResponseTooLarge,
             ErrorImpl::RequestTimeout => Self::RequestTimeout,
+            ErrorImpl::TooManyRedirects => Self::TooManyRedirects,
+            ErrorImpl::Cancelled => Self::Cancelled,
+            ErrorImpl::Backend {
+                kind: ErrorKindImpl::TooManyRedirects,
+                ..
+            } => Self::TooManyRedirects,
+            ErrorImpl::Backend { kind, source } => Self::Backend {
+                kind: kind.into(),
+                source,
+            },
         }
     }
 }
+
+pub(crate) fn is_cancelled_io(e: &std::io::Error) -> bool {
+    e.get_ref()
+        .and_then(|e| e.downcast_ref::<ErrorImpl>())
+        .is_some_and(|e| matches!(e, ErrorImpl::Cancelled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_round_trip() {
+        for kind in [
+            ErrorKind::Dns,
+            ErrorKind::TooManyRedirects,
+            ErrorKind::Other,
+        ] {
+            assert_eq!(ErrorKind::from(ErrorKindImpl::from(kind)), kind);
+        }
+        let e = Error::from(ErrorImpl::backend(ErrorKindImpl::Other, "unknown failure"));
+        assert_eq!(e.kind(), Some(ErrorKind::Other));
+    }
+
+    #[test]
+    fn test_backend_error_chain() {
+        let e = Error::from(ErrorImpl::backend(
+            ErrorKindImpl::Connect,
+            std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused by peer"),
+        ));
+        assert_eq!(e.to_string(), "Connection failed");
+        let source = std::error::Error::source(&e).unwrap();
+        assert_eq!(source.to_string(), "refused by peer");
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
+}
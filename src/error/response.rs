@@ -0,0 +1,48 @@
+use crate::header::HeaderMap;
+use crate::StatusCode;
+
+/// A response with a non-successful status code, kept by `Response::error_for_status_with_body`
+/// so that the error payload can be inspected.
+///
+/// Only a bounded prefix of the body is kept.
+#[derive(Debug, Clone)]
+pub struct ErrorResponse {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Vec<u8>,
+    pub(crate) truncated: bool,
+}
+
+impl ErrorResponse {
+    /// Get the `StatusCode` of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Get the kept prefix of the response body.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Get the kept prefix of the response body as text, replacing invalid UTF-8 sequences.
+    pub fn text(&self) -> std::borrow::Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// Returns whether the response body is longer than the kept prefix.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Deserialize the kept prefix of the response body into the given type.
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> crate::Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
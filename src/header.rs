@@ -1,7 +1,11 @@
-//! Predefined HTTP Header names
+//! Predefined HTTP Header names and the response header map
 //!
 //! Adopted from [http](https://github.com/hyperium/http) crate.
 
+mod map;
+
+pub use map::{HeaderMap, IntoIter, Iter};
+
 macro_rules! standard_headers {
     (
         $(
@@ -119,18 +119,31 @@
 #![forbid(missing_docs)]
 
 mod body;
+mod cancel;
+mod cookie;
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+mod download;
 mod error;
+mod progress;
 mod request;
 
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub mod r#async;
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+pub mod auth;
 #[cfg(feature = "blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
 pub mod blocking;
 pub mod client;
 pub mod header;
+pub mod middleware;
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+pub mod redirect;
 mod status;
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+mod time;
+mod version;
 
 #[cfg(feature = "blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
@@ -139,11 +152,16 @@ pub use body::Body;
 #[cfg(feature = "multipart")]
 #[cfg_attr(docsrs, doc(cfg(feature = "multipart")))]
 pub use body::{Part, PartBody};
+pub use cancel::CancellationToken;
 #[doc(inline)]
 pub use client::ClientBuilder;
-pub use error::{Error, Result};
+pub use cookie::{Cookie, CookieJar};
+pub use download::Download;
+pub use error::{Error, ErrorContext, ErrorKind, ErrorResponse, Result};
+pub use progress::Progress;
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub use r#async::client::AsyncClient;
 pub use request::{Method, Request};
 pub use status::StatusCode;
+pub use version::HttpVersion;
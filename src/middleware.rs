@@ -0,0 +1,115 @@
+//! Middleware support for intercepting requests and responses.
+//!
+//! A [`Middleware`] is registered on a [`ClientBuilder`] with
+//! [`ClientBuilder::with_middleware`] and applies to every request made by the clients built from
+//! it, regardless of whether they are async or blocking. Middlewares are invoked in the order they
+//! are registered, i.e. the first registered middleware sees the request first and the response
+//! last.
+//!
+//! Each middleware receives the request together with a `Next` handle ([`r#async::Next`] or
+//! [`blocking::Next`]) that represents the rest of the chain. A middleware may:
+//!
+//! - modify the request before passing it on to `next`,
+//! - inspect or replace the response returned by `next`,
+//! - short-circuit the chain by returning a response or an error without calling `next`,
+//! - re-issue the request by calling `next` multiple times, since `Next` is [`Copy`]. See
+//!   [`Request::try_clone`] for duplicating a request.
+//!
+//! [`ClientBuilder`]: crate::ClientBuilder
+//! [`ClientBuilder::with_middleware`]: crate::ClientBuilder::with_middleware
+//! [`r#async::Next`]: crate::r#async::Next
+//! [`blocking::Next`]: crate::blocking::Next
+//! [`Request::try_clone`]: crate::Request::try_clone
+
+use std::fmt::Debug;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// An owned dynamically typed [`Future`] returned by async middlewares.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An interceptor that sits between a client and the backend.
+///
+/// Both methods have default implementations that pass the request on to the rest of the chain
+/// unchanged, so a middleware only needs to implement the flavors it cares about. To behave
+/// identically on async and blocking clients, implement both.
+///
+/// # Example
+///
+/// ```
+/// use nyquest::middleware::Middleware;
+///
+/// struct RequestId;
+///
+/// impl Middleware for RequestId {
+///     #[cfg(feature = "async")]
+///     fn handle_async<'a>(
+///         &'a self,
+///         req: nyquest::r#async::Request,
+///         next: nyquest::r#async::Next<'a>,
+///     ) -> nyquest::middleware::BoxFuture<'a, nyquest::Result<nyquest::r#async::Response>> {
+///         next.run(req.with_header("x-request-id", "42"))
+///     }
+///
+///     #[cfg(feature = "blocking")]
+///     fn handle_blocking(
+///         &self,
+///         req: nyquest::blocking::Request,
+///         next: nyquest::blocking::Next<'_>,
+///     ) -> nyquest::Result<nyquest::blocking::Response> {
+///         next.run(req.with_header("x-request-id", "42"))
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync + 'static {
+    /// Handles a request made by an async client.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    fn handle_async<'a>(
+        &'a self,
+        req: crate::r#async::Request,
+        next: crate::r#async::Next<'a>,
+    ) -> BoxFuture<'a, crate::Result<crate::r#async::Response>> {
+        next.run(req)
+    }
+
+    /// Handles a request made by a blocking client.
+    #[cfg(feature = "blocking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    fn handle_blocking(
+        &self,
+        req: crate::blocking::Request,
+        next: crate::blocking::Next<'_>,
+    ) -> crate::Result<crate::blocking::Response> {
+        next.run(req)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack {
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl MiddlewareStack {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    pub(crate) fn into_shared(self) -> Arc<[Arc<dyn Middleware>]> {
+        self.middlewares.into()
+    }
+}
+
+impl Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MiddlewareStack")
+            .field("len", &self.middlewares.len())
+            .finish()
+    }
+}
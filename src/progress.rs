@@ -0,0 +1,36 @@
+use nyquest_interface::Progress as ProgressImpl;
+
+/// A snapshot of the transfer progress of a request, passed to the callback set by
+/// [`crate::Request::with_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    inner: ProgressImpl,
+}
+
+impl Progress {
+    /// Get the number of request body bytes sent so far.
+    pub fn uploaded(&self) -> u64 {
+        self.inner.uploaded
+    }
+
+    /// Get the total size of the request body, if known.
+    pub fn upload_total(&self) -> Option<u64> {
+        self.inner.upload_total
+    }
+
+    /// Get the number of response body bytes received so far.
+    pub fn downloaded(&self) -> u64 {
+        self.inner.downloaded
+    }
+
+    /// Get the total size of the response body, if known.
+    pub fn download_total(&self) -> Option<u64> {
+        self.inner.download_total
+    }
+}
+
+impl From<ProgressImpl> for Progress {
+    fn from(inner: ProgressImpl) -> Self {
+        Self { inner }
+    }
+}
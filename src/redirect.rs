@@ -0,0 +1,481 @@
+//! Types describing how redirects are followed.
+//!
+//! By default, redirects are followed by the backend with its own limits. A [`RedirectPolicy`]
+//! set with [`crate::ClientBuilder::redirect_policy`] can limit the number of hops, restrict
+//! redirects to the same origin, or veto individual redirects with a callback.
+//!
+//! When a redirect crosses origins, `Authorization`, `Proxy-Authorization` and `Cookie` headers of
+//! the request are not sent to the new origin. This includes such headers set with
+//! [`crate::ClientBuilder::with_header`].
+
+use std::fmt;
+use std::sync::Arc;
+
+use nyquest_interface::client::{
+    RedirectAttempt as RedirectAttemptImpl, RedirectPolicy as RedirectPolicyImpl,
+};
+use nyquest_interface::{Method as MethodImpl, RedirectHop as RedirectHopImpl};
+
+use crate::{Request, StatusCode};
+
+/// A redirect response that was followed before receiving the final response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectHop {
+    status: StatusCode,
+    location: Option<String>,
+}
+
+impl RedirectHop {
+    /// Get the status code of the redirect response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the value of the `Location` header of the redirect response, if present.
+    ///
+    /// Some backends, e.g. reqwest, report the absolute URL the header resolves to instead.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl From<RedirectHopImpl> for RedirectHop {
+    fn from(hop: RedirectHopImpl) -> Self {
+        Self {
+            status: hop.status.into(),
+            location: hop.location,
+        }
+    }
+}
+
+/// A redirect about to be followed, passed to the callback set by [`RedirectPolicy::decide`].
+#[derive(Debug, Clone, Copy)]
+pub struct RedirectAttempt<'a> {
+    inner: RedirectAttemptImpl<'a>,
+}
+
+impl<'a> RedirectAttempt<'a> {
+    /// Get the status code of the redirect response.
+    pub fn status(&self) -> StatusCode {
+        self.inner.status.into()
+    }
+
+    /// Get the URL that responded with the redirect.
+    pub fn url(&self) -> &'a str {
+        self.inner.url
+    }
+
+    /// Get the absolute URL the redirect points to.
+    pub fn location(&self) -> &'a str {
+        self.inner.location
+    }
+
+    /// Get the number of redirects already followed before this one.
+    pub fn previous_hops(&self) -> u32 {
+        self.inner.previous_hops
+    }
+}
+
+/// Controls how redirects are followed.
+///
+/// The default policy follows up to 10 redirects.
+#[derive(Clone)]
+pub struct RedirectPolicy {
+    pub(crate) inner: RedirectPolicyImpl,
+}
+
+impl RedirectPolicy {
+    pub(crate) const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+    /// Follows at most `max_redirects` redirects.
+    ///
+    /// Exceeding the limit results in [`crate::Error::TooManyRedirects`].
+    pub fn limited(max_redirects: u32) -> Self {
+        Self {
+            inner: RedirectPolicyImpl {
+                max_redirects: Some(max_redirects),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Only follows redirects to the same origin, i.e. the same scheme, host and port.
+    ///
+    /// A redirect to another origin is returned as the response.
+    pub fn same_origin_only(mut self) -> Self {
+        self.inner.same_origin_only = true;
+        self
+    }
+
+    /// Sets a callback deciding whether each redirect should be followed.
+    ///
+    /// When the callback returns `false`, the redirect is returned as the response.
+    pub fn decide(
+        mut self,
+        decider: impl Fn(&RedirectAttempt<'_>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.inner.decider = Some(Arc::new(move |attempt: &RedirectAttemptImpl<'_>| {
+            decider(&RedirectAttempt { inner: *attempt })
+        }));
+        self
+    }
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self::limited(Self::DEFAULT_MAX_REDIRECTS)
+    }
+}
+
+impl fmt::Debug for RedirectPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Builds the request for the next hop if the response is a redirect that should be followed.
+///
+/// `head` is the previous request without its body, and `full` is a copy including the body, if
+/// the body can be duplicated.
+pub(crate) fn next_request<S>(
+    policy: &RedirectPolicyImpl,
+    status: u16,
+    url: Option<String>,
+    location: Option<String>,
+    head: Request<S>,
+    full: Option<Request<S>>,
+    previous_hops: u32,
+) -> crate::Result<Option<(RedirectHopImpl, Request<S>)>> {
+    if !matches!(status, 301 | 302 | 303 | 307 | 308) {
+        return Ok(None);
+    }
+    let (Some(url), Some(location)) = (url, location) else {
+        return Ok(None);
+    };
+    let Some(target) = next_location(policy, status, &url, &location, previous_hops)? else {
+        return Ok(None);
+    };
+    let method = &head.inner.method;
+    let change_to_get = match status {
+        303 => *method != MethodImpl::Head,
+        301 | 302 => *method == MethodImpl::Post,
+        _ => false,
+    };
+    let mut req = if change_to_get {
+        let mut head = head;
+        head.inner.method = MethodImpl::Get;
+        head
+    } else {
+        match full {
+            Some(full) => full,
+            // The body cannot be sent again
+            None => return Ok(None),
+        }
+    };
+    if !is_same_origin(&url, &target) {
+        req.inner
+            .additional_headers
+            .retain(|(name, _)| !is_sensitive_header(name));
+        req.auth = None;
+    }
+    req.inner.relative_uri = target.into();
+    let hop = RedirectHopImpl {
+        status,
+        location: Some(location),
+    };
+    Ok(Some((hop, req)))
+}
+
+/// Decides whether to follow a redirect, returning the absolute target URL if so.
+///
+/// Errors with [`crate::Error::TooManyRedirects`] if the hop limit is exceeded.
+fn next_location(
+    policy: &RedirectPolicyImpl,
+    status: u16,
+    url: &str,
+    location: &str,
+    previous_hops: u32,
+) -> crate::Result<Option<String>> {
+    let Some(target) = resolve(url, location) else {
+        return Ok(None);
+    };
+    if policy.same_origin_only && !is_same_origin(url, &target) {
+        return Ok(None);
+    }
+    let attempt = RedirectAttemptImpl {
+        status,
+        url,
+        location: &target,
+        previous_hops,
+    };
+    if let Some(decider) = &policy.decider {
+        if !decider(&attempt) {
+            return Ok(None);
+        }
+    }
+    if policy.max_redirects.is_some_and(|max| previous_hops >= max) {
+        return Err(crate::Error::TooManyRedirects);
+    }
+    Ok(Some(target))
+}
+
+/// Headers that must not be forwarded to another origin.
+fn is_sensitive_header(name: &str) -> bool {
+    ["authorization", "proxy-authorization", "cookie"]
+        .iter()
+        .any(|h| name.eq_ignore_ascii_case(h))
+}
+
+/// Moves the headers that must not be forwarded to another origin out of the default headers of
+/// a client, so that they can be added to each request instead of being sent by the backend.
+pub(crate) fn take_sensitive_headers(headers: &mut Vec<(String, String)>) -> Vec<(String, String)> {
+    let (sensitive, rest) = std::mem::take(headers)
+        .into_iter()
+        .partition(|(name, _)| is_sensitive_header(name));
+    *headers = rest;
+    sensitive
+}
+
+/// How the redirects of a client are followed.
+#[derive(Clone, Default)]
+pub(crate) struct RedirectConfig {
+    /// The redirect policy of the client, with a limit on the number of redirects.
+    pub(crate) policy: RedirectPolicyImpl,
+    /// Whether redirects are followed unless a request overrides it.
+    pub(crate) follow: bool,
+    /// Whether the backend is told not to follow redirects, as it cannot enforce the policy or
+    /// consult the custom resolver for the hosts redirected to.
+    pub(crate) enforced: bool,
+    /// The default headers taken by [`take_sensitive_headers`] when the policy is enforced.
+    pub(crate) sensitive_headers: Vec<(String, String)>,
+}
+
+impl RedirectConfig {
+    /// Returns the policy to follow the redirects of `req` with, or `None` if they are left to
+    /// the backend.
+    ///
+    /// Redirects of requests with credentials are always followed by nyquest, so that the
+    /// credentials are not sent to other origins and Digest challenges are answered after
+    /// redirects.
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) fn policy_for<S>(&self, req: &Request<S>) -> Option<&RedirectPolicyImpl> {
+        let follow = req.inner.options.follow_redirects.unwrap_or(self.follow);
+        (follow && (self.enforced || req.auth.is_some())).then_some(&self.policy)
+    }
+
+    /// Adds the default headers taken by [`take_sensitive_headers`] to a request not setting them
+    /// itself, where they are dropped on cross-origin redirects like the headers of the request.
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) fn add_sensitive_headers<S>(&self, req: &mut Request<S>) {
+        let additional_headers = &mut req.inner.additional_headers;
+        for (name, value) in &self.sensitive_headers {
+            if !additional_headers
+                .iter()
+                .any(|(n, _)| n.eq_ignore_ascii_case(name))
+            {
+                additional_headers.push((name.clone().into(), value.clone().into()));
+            }
+        }
+    }
+}
+
+fn is_same_origin(a: &str, b: &str) -> bool {
+    match (origin(a), origin(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn origin(url: &str) -> Option<(String, String, Option<u16>)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let scheme = scheme.to_ascii_lowercase();
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) if !port.contains(']') => (host, Some(port.parse().ok()?)),
+        _ => (host_port, None),
+    };
+    let default_port = match &*scheme {
+        "http" => Some(80),
+        "https" => Some(443),
+        _ => None,
+    };
+    Some((scheme, host.to_ascii_lowercase(), port.or(default_port)))
+}
+
+/// Resolves a `Location` header value against the URL of the redirect response, following
+/// RFC 3986 section 5.2.
+pub(crate) fn resolve(base: &str, location: &str) -> Option<String> {
+    let location = location.trim();
+    if has_scheme(location) {
+        return Some(location.into());
+    }
+    let (scheme, rest) = base.split_once("://")?;
+    if let Some(location) = location.strip_prefix("//") {
+        return Some(format!("{scheme}://{location}"));
+    }
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+    let authority_end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (authority, path_query) = rest.split_at(authority_end);
+    let (base_path, base_query) = path_query
+        .split_once('?')
+        .map_or((path_query, None), |(path, query)| (path, Some(query)));
+    let prefix = format!("{scheme}://{authority}");
+
+    if location.is_empty() || location.starts_with('#') {
+        let query = base_query.map(|q| format!("?{q}")).unwrap_or_default();
+        return Some(format!("{prefix}{base_path}{query}{location}"));
+    }
+    if location.starts_with('?') {
+        return Some(format!("{prefix}{base_path}{location}"));
+    }
+    let split = location.find(['?', '#']).unwrap_or(location.len());
+    let (path, suffix) = location.split_at(split);
+    let merged = if path.starts_with('/') {
+        path.to_owned()
+    } else {
+        let dir = base_path.rfind('/').map_or("/", |i| &base_path[..=i]);
+        format!("{dir}{path}")
+    };
+    Some(format!("{prefix}{}{suffix}", remove_dot_segments(&merged)))
+}
+
+fn has_scheme(s: &str) -> bool {
+    let Some((scheme, _)) = s.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+fn remove_dot_segments(path: &str) -> String {
+    let mut output: Vec<&str> = vec![];
+    let segments: Vec<_> = path.split('/').skip(1).collect();
+    for (i, segment) in segments.iter().enumerate() {
+        let is_last = i + 1 == segments.len();
+        match *segment {
+            "." => {
+                if is_last {
+                    output.push("");
+                }
+            }
+            ".." => {
+                output.pop();
+                if is_last {
+                    output.push("");
+                }
+            }
+            segment => output.push(segment),
+        }
+    }
+    format!("/{}", output.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        const BASE: &str = "http://a/b/c/d;p?q";
+        let cases = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("g/../h", "http://a/b/c/h"),
+        ];
+        for (location, expected) in cases {
+            assert_eq!(
+                resolve(BASE, location).as_deref(),
+                Some(expected),
+                "{location}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_same_origin() {
+        assert!(is_same_origin("http://a.com/x", "HTTP://A.com:80/y?z"));
+        assert!(is_same_origin(
+            "https://u:p@a.com:8443/",
+            "https://a.com:8443"
+        ));
+        assert!(!is_same_origin("http://a.com/", "https://a.com/"));
+        assert!(!is_same_origin("http://a.com/", "http://a.com:8080/"));
+        assert!(!is_same_origin("http://a.com/", "http://b.com/"));
+        assert!(is_same_origin("http://[::1]:80/", "http://[::1]/"));
+    }
+
+    #[test]
+    fn test_next_location_limits() {
+        let policy = RedirectPolicy::limited(1)
+            .decide(|attempt| !attempt.location().ends_with("/blocked"))
+            .inner;
+        let next = |location, hops| next_location(&policy, 302, "http://a/b", location, hops);
+        assert_eq!(next("/c", 0).unwrap().as_deref(), Some("http://a/c"));
+        assert_eq!(next("/blocked", 0).unwrap(), None);
+        assert!(matches!(next("/c", 1), Err(crate::Error::TooManyRedirects)));
+    }
+
+    #[test]
+    fn test_sensitive_default_headers() {
+        let mut defaults = vec![
+            ("Authorization".to_string(), "Bearer a".to_string()),
+            ("x-custom".to_string(), "1".to_string()),
+            ("cookie".to_string(), "a=1".to_string()),
+        ];
+        let redirects = RedirectConfig {
+            sensitive_headers: take_sensitive_headers(&mut defaults),
+            ..Default::default()
+        };
+        assert_eq!(defaults, [("x-custom".into(), "1".into())]);
+
+        let mut req = Request::<()>::get("http://a/").with_header("authorization", "Bearer b");
+        redirects.add_sensitive_headers(&mut req);
+        assert_eq!(
+            req.inner.additional_headers,
+            [
+                ("authorization".into(), "Bearer b".into()),
+                ("cookie".into(), "a=1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_policy_for_authenticated_requests() {
+        let redirects = RedirectConfig {
+            follow: true,
+            ..Default::default()
+        };
+        let req = Request::<()>::get("http://a/");
+        assert!(redirects.policy_for(&req).is_none());
+        let req = req.with_basic_auth("user", "password");
+        assert!(redirects.policy_for(&req).is_some());
+        let req = req.with_redirects(false);
+        assert!(redirects.policy_for(&req).is_none());
+
+        let enforced = RedirectConfig {
+            follow: true,
+            enforced: true,
+            ..Default::default()
+        };
+        assert!(enforced
+            .policy_for(&Request::<()>::get("http://a/"))
+            .is_some());
+    }
+}
@@ -1,8 +1,15 @@
-use std::{borrow::Cow, fmt::Debug};
+use std::{borrow::Cow, fmt, fmt::Debug};
 
-use nyquest_interface::{Method as MethodImpl, Request as RequestImpl};
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::body::Body;
+use nyquest_interface::client::CachingBehavior;
+use nyquest_interface::{Method as MethodImpl, ProgressCallback, Request as RequestImpl};
+
+use crate::auth::Auth;
+use crate::body::{try_clone_body, Body};
+use crate::client::RetryPolicy;
+use crate::{CancellationToken, Progress};
 
 /// The Request Method (VERB)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -13,6 +20,9 @@ pub struct Method {
 /// A request generic over async or blocking stream.
 pub struct Request<S> {
     pub(crate) inner: RequestImpl<S>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) progress: Option<ProgressCallback>,
+    pub(crate) auth: Option<Auth>,
 }
 
 impl Method {
@@ -66,6 +76,33 @@ impl Method {
     }
 }
 
+impl Method {
+    /// Returns the method name, e.g. `GET`.
+    pub fn as_str(&self) -> &str {
+        match &self.inner {
+            MethodImpl::Get => "GET",
+            MethodImpl::Post => "POST",
+            MethodImpl::Put => "PUT",
+            MethodImpl::Delete => "DELETE",
+            MethodImpl::Patch => "PATCH",
+            MethodImpl::Head => "HEAD",
+            MethodImpl::Other(method) => method,
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<MethodImpl> for Method {
+    fn from(inner: MethodImpl) -> Self {
+        Self { inner }
+    }
+}
+
 impl<S> Request<S> {
     /// Constructs a request with the given method and a relative or absolute URI.
     ///
@@ -78,7 +115,12 @@ impl<S> Request<S> {
                 relative_uri: relative_uri.into(),
                 additional_headers: vec![],
                 body: None,
+                cancellation: None,
+                options: Default::default(),
             },
+            retry_policy: None,
+            progress: None,
+            auth: None,
         }
     }
 
@@ -143,6 +185,143 @@ impl<S> Request<S> {
         self.inner.body = Some(body.inner);
         self
     }
+
+    /// Attempts to duplicate the request, e.g. to send it again.
+    ///
+    /// Returns `None` if the request body contains a stream, which cannot be duplicated.
+    pub fn try_clone(&self) -> Option<Self> {
+        let body = match &self.inner.body {
+            Some(body) => Some(try_clone_body(body)?),
+            None => None,
+        };
+        let mut req = self.clone_without_body();
+        req.inner.body = body;
+        Some(req)
+    }
+
+    pub(crate) fn clone_without_body(&self) -> Self {
+        Self {
+            inner: RequestImpl {
+                method: self.inner.method.clone(),
+                relative_uri: self.inner.relative_uri.clone(),
+                additional_headers: self.inner.additional_headers.clone(),
+                body: None,
+                cancellation: self.inner.cancellation.clone(),
+                options: self.inner.options.clone(),
+            },
+            retry_policy: self.retry_policy.clone(),
+            progress: self.progress.clone(),
+            auth: self.auth.clone(),
+        }
+    }
+
+    /// Sets a callback receiving the upload and download progress of this request.
+    ///
+    /// The callback keeps being invoked while the response body is read. It may be invoked from a
+    /// background thread, and should return quickly. Some backends are not able to report
+    /// progress, in which case the callback is never invoked.
+    pub fn with_progress(mut self, progress: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(move |p| progress(p.into())));
+        self
+    }
+
+    /// Attaches a [`CancellationToken`] to cancel this request from another thread.
+    ///
+    /// Cancelling the token interrupts sending the request and reading the response body,
+    /// resulting in [`crate::Error::Cancelled`]. Async requests can also be cancelled by dropping
+    /// the future, and some backends only honor the token for blocking requests.
+    pub fn with_cancellation(mut self, token: &CancellationToken) -> Self {
+        self.inner.cancellation = Some(token.inner.clone());
+        self
+    }
+
+    /// Overrides [`crate::ClientBuilder::request_timeout`] for this request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner.options.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides [`crate::ClientBuilder::connect_timeout`] for this request.
+    ///
+    /// Some backends are not able to override the connect timeout per request, in which case the
+    /// client setting applies, or sending the request fails for the reqwest backend.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.inner.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides [`crate::ClientBuilder::read_timeout`] for this request.
+    ///
+    /// Some backends are not able to override the read timeout per request, in which case the
+    /// client setting applies.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.inner.options.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides whether 3xx redirects are followed for this request.
+    ///
+    /// When `false`, the redirect response is returned as is. When `true` on a client built with
+    /// [`crate::ClientBuilder::no_redirects`], redirects are followed with the backend default
+    /// limits.
+    pub fn with_redirects(mut self, follow: bool) -> Self {
+        self.inner.options.follow_redirects = Some(follow);
+        self
+    }
+
+    /// Overrides [`crate::ClientBuilder::no_caching`] for this request.
+    ///
+    /// Passing `false` instructs the backend to bypass cache for this request.
+    pub fn with_caching(mut self, enabled: bool) -> Self {
+        self.inner.options.caching_behavior = Some(if enabled {
+            CachingBehavior::BestEffort
+        } else {
+            CachingBehavior::Disabled
+        });
+        self
+    }
+
+    /// Overrides [`crate::ClientBuilder::max_response_buffer_size`] for this request.
+    pub fn with_max_response_buffer_size(mut self, size: u64) -> Self {
+        self.inner.options.max_response_buffer_size = Some(size);
+        self
+    }
+
+    /// Overrides the [`RetryPolicy`] of the client for this request.
+    ///
+    /// Use [`RetryPolicy::never`] to disable retrying for this request.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Authenticates this request with HTTP Basic authentication, overriding the credentials of
+    /// the client if any.
+    ///
+    /// The credentials are sent along with the request, and dropped when following a redirect to
+    /// another origin. They are not sent if the request has an `Authorization` header already.
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.auth = Some(Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Authenticates this request with a Bearer token, overriding the credentials of the client
+    /// if any.
+    ///
+    /// See [`Self::with_basic_auth`] for when the token is sent.
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Some(Auth::Bearer {
+            token: token.into(),
+        });
+        self
+    }
 }
 
 impl<S> Debug for Request<S>
@@ -161,6 +340,9 @@ where
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            retry_policy: self.retry_policy.clone(),
+            progress: self.progress.clone(),
+            auth: self.auth.clone(),
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Minimal date and time helpers for HTTP headers.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Parses an HTTP-date as defined in RFC 9110 section 5.6.7.
+///
+/// All three formats are accepted:
+/// - IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+/// - obsolete RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+/// - ANSI C `asctime()`: `Sun Nov  6 08:49:37 1994`
+pub(crate) fn parse_http_date(s: &str) -> Option<SystemTime> {
+    let s = s.trim();
+    let (year, month, day, time) = if let Some((_, rest)) = s.split_once(", ") {
+        let rest = rest.strip_suffix(" GMT")?;
+        if rest.contains('-') {
+            // RFC 850: 06-Nov-94 08:49:37
+            let (date, time) = rest.split_once(' ')?;
+            let mut date = date.split('-');
+            let day = date.next()?;
+            let month = date.next()?;
+            let year: u64 = parse_digits(date.next()?)?;
+            // Two-digit years are interpreted as the closest year in the past or near future.
+            let year = if year < 70 { 2000 + year } else { 1900 + year };
+            (year, month, day, time)
+        } else {
+            // IMF-fixdate: 06 Nov 1994 08:49:37
+            let mut parts = rest.split(' ');
+            let day = parts.next()?;
+            let month = parts.next()?;
+            let year = parse_digits(parts.next()?)?;
+            (year, month, day, parts.next()?)
+        }
+    } else {
+        // asctime: Sun Nov  6 08:49:37 1994
+        let mut parts = s.split_ascii_whitespace().skip(1);
+        let month = parts.next()?;
+        let day = parts.next()?;
+        let time = parts.next()?;
+        let year = parse_digits(parts.next()?)?;
+        (year, month, day, time)
+    };
+
+    let month = MONTHS.iter().position(|m| *m == month)? as u64 + 1;
+    let day: u64 = parse_digits(day)?;
+    let mut time = time.split(':');
+    let hour: u64 = parse_digits(time.next()?)?;
+    let minute: u64 = parse_digits(time.next()?)?;
+    let second: u64 = parse_digits(time.next()?)?;
+    if !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 || year < 1970 {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let secs = days * 86400 + hour * 3600 + minute * 60 + second;
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
+
+/// Formats a time in the ISO 8601 basic format in UTC, e.g. `20150830T123600Z`.
+pub(crate) fn format_iso8601_basic(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let secs = secs % 86400;
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+fn parse_digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Number of days since 1970-01-01 for the given date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// The date in the proleptic Gregorian calendar for the number of days since 1970-01-01.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719468;
+    let era = days / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_date() {
+        let expected = UNIX_EPOCH + Duration::from_secs(784111777);
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"),
+            Some(expected)
+        );
+        assert_eq!(parse_http_date("Sun Nov  6 08:49:37 1994"), Some(expected));
+        assert_eq!(
+            parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"),
+            Some(UNIX_EPOCH)
+        );
+        assert_eq!(
+            parse_http_date("Tue, 29 Feb 2000 12:00:00 GMT"),
+            Some(UNIX_EPOCH + Duration::from_secs(951825600))
+        );
+    }
+
+    #[test]
+    fn test_format_iso8601_basic() {
+        assert_eq!(format_iso8601_basic(UNIX_EPOCH), "19700101T000000Z");
+        assert_eq!(
+            format_iso8601_basic(UNIX_EPOCH + Duration::from_secs(1440938160)),
+            "20150830T123600Z"
+        );
+        assert_eq!(
+            format_iso8601_basic(UNIX_EPOCH + Duration::from_secs(951825600)),
+            "20000229T120000Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_http_date() {
+        assert_eq!(parse_http_date(""), None);
+        assert_eq!(parse_http_date("120"), None);
+        assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 25:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+    }
+}
@@ -0,0 +1,29 @@
+use nyquest_interface::HttpVersion as HttpVersionImpl;
+
+/// The HTTP protocol version a response was received with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum HttpVersion {
+    /// HTTP/0.9.
+    Http09,
+    /// HTTP/1.0.
+    Http10,
+    /// HTTP/1.1.
+    Http11,
+    /// HTTP/2.
+    Http2,
+    /// HTTP/3.
+    Http3,
+}
+
+impl From<HttpVersionImpl> for HttpVersion {
+    fn from(version: HttpVersionImpl) -> Self {
+        match version {
+            HttpVersionImpl::Http09 => Self::Http09,
+            HttpVersionImpl::Http10 => Self::Http10,
+            HttpVersionImpl::Http11 => Self::Http11,
+            HttpVersionImpl::Http2 => Self::Http2,
+            HttpVersionImpl::Http3 => Self::Http3,
+        }
+    }
+}